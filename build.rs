@@ -0,0 +1,89 @@
+use std::env;
+use std::fmt::Write;
+use std::fs;
+use std::path::Path;
+
+/// Vector types and the component name sets they accept, in component order
+const TYPES: [(&str, &[&str]); 3] = [
+    ("Vec2", &["xy"]),
+    ("Vec3", &["xyz", "rgb"]),
+    ("Vec4", &["xyzw", "rgba"]),
+];
+
+/// Swizzle result type for each length
+const TARGETS: [(usize, &str); 3] = [(2, "Vec2"), (3, "Vec3"), (4, "Vec4")];
+
+/// Every sequence of `len` indices into `size` components
+fn combinations(size: usize, len: usize) -> Vec<Vec<usize>> {
+    let mut result = vec![vec![]];
+    for _ in 0..len {
+        result = result
+            .into_iter()
+            .flat_map(|prefix| {
+                (0..size).map(move |i| {
+                    let mut next = prefix.clone();
+                    next.push(i);
+                    next
+                })
+            })
+            .collect();
+    }
+    result
+}
+
+fn main() {
+    let mut code = String::new();
+
+    for (ty, sets) in TYPES {
+        writeln!(code, "impl<T: Scalar> {}<T> {{", ty).unwrap();
+        for names in sets {
+            let names: Vec<char> = names.chars().collect();
+            for (len, target) in TARGETS {
+                for indices in combinations(names.len(), len) {
+                    let name: String = indices.iter().map(|&i| names[i]).collect();
+                    let fields: Vec<String> =
+                        indices.iter().map(|i| format!("self.{}", i)).collect();
+                    let fields = fields.join(", ");
+                    writeln!(
+                        code,
+                        "    /// Returns the `{name}` components as a {target}
+    #[inline]
+    pub fn {name}(&self) -> {target}<T> {{
+        {target}({fields})
+    }}
+"
+                    )
+                    .unwrap();
+
+                    // Write masks can't name a component twice
+                    let distinct = indices
+                        .iter()
+                        .enumerate()
+                        .all(|(i, idx)| !indices[..i].contains(idx));
+                    if !distinct {
+                        continue;
+                    }
+                    let assignments: String = indices
+                        .iter()
+                        .enumerate()
+                        .map(|(from, to)| format!("        self.{} = value.{};\n", to, from))
+                        .collect();
+                    writeln!(
+                        code,
+                        "    /// Writes `value` into the `{name}` components
+    #[inline]
+    pub fn set_{name}(&mut self, value: {target}<T>) {{
+{assignments}    }}
+"
+                    )
+                    .unwrap();
+                }
+            }
+        }
+        writeln!(code, "}}\n").unwrap();
+    }
+
+    let out_dir = env::var("OUT_DIR").unwrap();
+    fs::write(Path::new(&out_dir).join("swizzle.rs"), code).unwrap();
+    println!("cargo:rerun-if-changed=build.rs");
+}
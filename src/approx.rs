@@ -0,0 +1,409 @@
+use crate::{Mat2, Mat3, Mat4, Matrix, Quat, Scalar, Vec2, Vec3, Vec4, VecN};
+
+/// Absolute tolerance used by `approx_eq`
+pub const APPROX_EPSILON: f64 = 1e-9;
+
+/// Relative tolerance used by `approx_eq`
+pub const APPROX_MAX_RELATIVE: f64 = 1e-9;
+
+/// Floating point comparisons that tolerate rounding errors
+///
+/// Every comparison first succeeds if the absolute difference is within `epsilon`,
+/// which is needed for values close to 0.0 where relative and ULP distances blow up.
+/// Vectors and matrices compare component by component.
+pub trait ApproxEq {
+    /// `|a - b| <= epsilon`
+    fn abs_diff_eq(&self, other: &Self, epsilon: f64) -> bool;
+
+    /// `|a - b| <= max(|a|, |b|) * max_relative`
+    fn relative_eq(&self, other: &Self, epsilon: f64, max_relative: f64) -> bool;
+
+    /// `a` and `b` have the same sign and are at most `max_ulps` representable floats apart
+    fn ulps_eq(&self, other: &Self, epsilon: f64, max_ulps: u64) -> bool;
+
+    /// Relative comparison with `APPROX_EPSILON` and `APPROX_MAX_RELATIVE`
+    fn approx_eq(&self, other: &Self) -> bool {
+        self.relative_eq(other, APPROX_EPSILON, APPROX_MAX_RELATIVE)
+    }
+}
+
+impl ApproxEq for f64 {
+    fn abs_diff_eq(&self, other: &Self, epsilon: f64) -> bool {
+        self == other || (self - other).abs() <= epsilon
+    }
+
+    fn relative_eq(&self, other: &Self, epsilon: f64, max_relative: f64) -> bool {
+        if self.abs_diff_eq(other, epsilon) {
+            return true;
+        }
+        if self.is_infinite() || other.is_infinite() {
+            return false;
+        }
+
+        let largest = self.abs().max(other.abs());
+        (self - other).abs() <= largest * max_relative
+    }
+
+    fn ulps_eq(&self, other: &Self, epsilon: f64, max_ulps: u64) -> bool {
+        if self.abs_diff_eq(other, epsilon) {
+            return true;
+        }
+        if self.is_nan() || other.is_nan() || self.is_sign_negative() != other.is_sign_negative() {
+            return false;
+        }
+
+        let a = self.to_bits() as i64;
+        let b = other.to_bits() as i64;
+        (a - b).unsigned_abs() <= max_ulps
+    }
+}
+
+impl<T: ApproxEq + ?Sized> ApproxEq for &T {
+    fn abs_diff_eq(&self, other: &Self, epsilon: f64) -> bool {
+        T::abs_diff_eq(self, other, epsilon)
+    }
+
+    fn relative_eq(&self, other: &Self, epsilon: f64, max_relative: f64) -> bool {
+        T::relative_eq(self, other, epsilon, max_relative)
+    }
+
+    fn ulps_eq(&self, other: &Self, epsilon: f64, max_ulps: u64) -> bool {
+        T::ulps_eq(self, other, epsilon, max_ulps)
+    }
+}
+
+impl ApproxEq for [f64] {
+    fn abs_diff_eq(&self, other: &Self, epsilon: f64) -> bool {
+        self.len() == other.len()
+            && self
+                .iter()
+                .zip(other)
+                .all(|(a, b)| a.abs_diff_eq(b, epsilon))
+    }
+
+    fn relative_eq(&self, other: &Self, epsilon: f64, max_relative: f64) -> bool {
+        self.len() == other.len()
+            && self
+                .iter()
+                .zip(other)
+                .all(|(a, b)| a.relative_eq(b, epsilon, max_relative))
+    }
+
+    fn ulps_eq(&self, other: &Self, epsilon: f64, max_ulps: u64) -> bool {
+        self.len() == other.len()
+            && self
+                .iter()
+                .zip(other)
+                .all(|(a, b)| a.ulps_eq(b, epsilon, max_ulps))
+    }
+}
+
+impl<T: Scalar> ApproxEq for Vec2<T> {
+    fn abs_diff_eq(&self, other: &Self, epsilon: f64) -> bool {
+        [self.0.to_f64(), self.1.to_f64()]
+            .abs_diff_eq(&[other.0.to_f64(), other.1.to_f64()], epsilon)
+    }
+
+    fn relative_eq(&self, other: &Self, epsilon: f64, max_relative: f64) -> bool {
+        [self.0.to_f64(), self.1.to_f64()].relative_eq(
+            &[other.0.to_f64(), other.1.to_f64()],
+            epsilon,
+            max_relative,
+        )
+    }
+
+    fn ulps_eq(&self, other: &Self, epsilon: f64, max_ulps: u64) -> bool {
+        [self.0.to_f64(), self.1.to_f64()].ulps_eq(
+            &[other.0.to_f64(), other.1.to_f64()],
+            epsilon,
+            max_ulps,
+        )
+    }
+}
+
+impl<T: Scalar> ApproxEq for Vec3<T> {
+    fn abs_diff_eq(&self, other: &Self, epsilon: f64) -> bool {
+        [self.0.to_f64(), self.1.to_f64(), self.2.to_f64()].abs_diff_eq(
+            &[other.0.to_f64(), other.1.to_f64(), other.2.to_f64()],
+            epsilon,
+        )
+    }
+
+    fn relative_eq(&self, other: &Self, epsilon: f64, max_relative: f64) -> bool {
+        [self.0.to_f64(), self.1.to_f64(), self.2.to_f64()].relative_eq(
+            &[other.0.to_f64(), other.1.to_f64(), other.2.to_f64()],
+            epsilon,
+            max_relative,
+        )
+    }
+
+    fn ulps_eq(&self, other: &Self, epsilon: f64, max_ulps: u64) -> bool {
+        [self.0.to_f64(), self.1.to_f64(), self.2.to_f64()].ulps_eq(
+            &[other.0.to_f64(), other.1.to_f64(), other.2.to_f64()],
+            epsilon,
+            max_ulps,
+        )
+    }
+}
+
+impl<T: Scalar> ApproxEq for Vec4<T> {
+    fn abs_diff_eq(&self, other: &Self, epsilon: f64) -> bool {
+        [
+            self.0.to_f64(),
+            self.1.to_f64(),
+            self.2.to_f64(),
+            self.3.to_f64(),
+        ]
+        .abs_diff_eq(
+            &[
+                other.0.to_f64(),
+                other.1.to_f64(),
+                other.2.to_f64(),
+                other.3.to_f64(),
+            ],
+            epsilon,
+        )
+    }
+
+    fn relative_eq(&self, other: &Self, epsilon: f64, max_relative: f64) -> bool {
+        [
+            self.0.to_f64(),
+            self.1.to_f64(),
+            self.2.to_f64(),
+            self.3.to_f64(),
+        ]
+        .relative_eq(
+            &[
+                other.0.to_f64(),
+                other.1.to_f64(),
+                other.2.to_f64(),
+                other.3.to_f64(),
+            ],
+            epsilon,
+            max_relative,
+        )
+    }
+
+    fn ulps_eq(&self, other: &Self, epsilon: f64, max_ulps: u64) -> bool {
+        [
+            self.0.to_f64(),
+            self.1.to_f64(),
+            self.2.to_f64(),
+            self.3.to_f64(),
+        ]
+        .ulps_eq(
+            &[
+                other.0.to_f64(),
+                other.1.to_f64(),
+                other.2.to_f64(),
+                other.3.to_f64(),
+            ],
+            epsilon,
+            max_ulps,
+        )
+    }
+}
+
+impl<const N: usize, T: Scalar> ApproxEq for VecN<N, T> {
+    fn abs_diff_eq(&self, other: &Self, epsilon: f64) -> bool {
+        self.0
+            .map(T::to_f64)
+            .abs_diff_eq(&other.0.map(T::to_f64), epsilon)
+    }
+
+    fn relative_eq(&self, other: &Self, epsilon: f64, max_relative: f64) -> bool {
+        self.0
+            .map(T::to_f64)
+            .relative_eq(&other.0.map(T::to_f64), epsilon, max_relative)
+    }
+
+    fn ulps_eq(&self, other: &Self, epsilon: f64, max_ulps: u64) -> bool {
+        self.0
+            .map(T::to_f64)
+            .ulps_eq(&other.0.map(T::to_f64), epsilon, max_ulps)
+    }
+}
+
+impl ApproxEq for Quat {
+    fn abs_diff_eq(&self, other: &Self, epsilon: f64) -> bool {
+        Vec4::from(*self).abs_diff_eq(&Vec4::from(*other), epsilon)
+    }
+
+    fn relative_eq(&self, other: &Self, epsilon: f64, max_relative: f64) -> bool {
+        Vec4::from(*self).relative_eq(&Vec4::from(*other), epsilon, max_relative)
+    }
+
+    fn ulps_eq(&self, other: &Self, epsilon: f64, max_ulps: u64) -> bool {
+        Vec4::from(*self).ulps_eq(&Vec4::from(*other), epsilon, max_ulps)
+    }
+}
+
+impl ApproxEq for Mat2 {
+    fn abs_diff_eq(&self, other: &Self, epsilon: f64) -> bool {
+        self.0.abs_diff_eq(&other.0, epsilon) && self.1.abs_diff_eq(&other.1, epsilon)
+    }
+
+    fn relative_eq(&self, other: &Self, epsilon: f64, max_relative: f64) -> bool {
+        self.0.relative_eq(&other.0, epsilon, max_relative)
+            && self.1.relative_eq(&other.1, epsilon, max_relative)
+    }
+
+    fn ulps_eq(&self, other: &Self, epsilon: f64, max_ulps: u64) -> bool {
+        self.0.ulps_eq(&other.0, epsilon, max_ulps) && self.1.ulps_eq(&other.1, epsilon, max_ulps)
+    }
+}
+
+impl ApproxEq for Mat3 {
+    fn abs_diff_eq(&self, other: &Self, epsilon: f64) -> bool {
+        self.0.abs_diff_eq(&other.0, epsilon)
+            && self.1.abs_diff_eq(&other.1, epsilon)
+            && self.2.abs_diff_eq(&other.2, epsilon)
+    }
+
+    fn relative_eq(&self, other: &Self, epsilon: f64, max_relative: f64) -> bool {
+        self.0.relative_eq(&other.0, epsilon, max_relative)
+            && self.1.relative_eq(&other.1, epsilon, max_relative)
+            && self.2.relative_eq(&other.2, epsilon, max_relative)
+    }
+
+    fn ulps_eq(&self, other: &Self, epsilon: f64, max_ulps: u64) -> bool {
+        self.0.ulps_eq(&other.0, epsilon, max_ulps)
+            && self.1.ulps_eq(&other.1, epsilon, max_ulps)
+            && self.2.ulps_eq(&other.2, epsilon, max_ulps)
+    }
+}
+
+impl ApproxEq for Mat4 {
+    fn abs_diff_eq(&self, other: &Self, epsilon: f64) -> bool {
+        self.0.abs_diff_eq(&other.0, epsilon)
+            && self.1.abs_diff_eq(&other.1, epsilon)
+            && self.2.abs_diff_eq(&other.2, epsilon)
+            && self.3.abs_diff_eq(&other.3, epsilon)
+    }
+
+    fn relative_eq(&self, other: &Self, epsilon: f64, max_relative: f64) -> bool {
+        self.0.relative_eq(&other.0, epsilon, max_relative)
+            && self.1.relative_eq(&other.1, epsilon, max_relative)
+            && self.2.relative_eq(&other.2, epsilon, max_relative)
+            && self.3.relative_eq(&other.3, epsilon, max_relative)
+    }
+
+    fn ulps_eq(&self, other: &Self, epsilon: f64, max_ulps: u64) -> bool {
+        self.0.ulps_eq(&other.0, epsilon, max_ulps)
+            && self.1.ulps_eq(&other.1, epsilon, max_ulps)
+            && self.2.ulps_eq(&other.2, epsilon, max_ulps)
+            && self.3.ulps_eq(&other.3, epsilon, max_ulps)
+    }
+}
+
+impl ApproxEq for Matrix {
+    fn abs_diff_eq(&self, other: &Self, epsilon: f64) -> bool {
+        same_dimensions(self, other)
+            && (1..=self.rows())
+                .all(|row| self.get_row(row).abs_diff_eq(&other.get_row(row), epsilon))
+    }
+
+    fn relative_eq(&self, other: &Self, epsilon: f64, max_relative: f64) -> bool {
+        same_dimensions(self, other)
+            && (1..=self.rows()).all(|row| {
+                self.get_row(row)
+                    .relative_eq(&other.get_row(row), epsilon, max_relative)
+            })
+    }
+
+    fn ulps_eq(&self, other: &Self, epsilon: f64, max_ulps: u64) -> bool {
+        same_dimensions(self, other)
+            && (1..=self.rows()).all(|row| {
+                self.get_row(row)
+                    .ulps_eq(&other.get_row(row), epsilon, max_ulps)
+            })
+    }
+}
+
+fn same_dimensions(a: &Matrix, b: &Matrix) -> bool {
+    a.rows() == b.rows() && a.cols() == b.cols()
+}
+
+/// Asserts that two values are equal according to `ApproxEq`
+///
+/// `assert_approx_eq!(a, b)` uses `approx_eq`, `assert_approx_eq!(a, b, epsilon)` uses `abs_diff_eq`
+/// # Examples
+/// ```
+/// use vecx::{assert_approx_eq, Vec2};
+///
+/// assert_approx_eq!(Vec2(0.1 + 0.2, 1.0), Vec2(0.3, 1.0));
+/// assert_approx_eq!(Vec2(1.0, 1.0), Vec2(1.05, 1.0), 0.1);
+/// ```
+#[macro_export]
+macro_rules! assert_approx_eq {
+    ($left:expr, $right:expr $(,)?) => {
+        match (&$left, &$right) {
+            (left, right) => {
+                if !$crate::ApproxEq::approx_eq(left, right) {
+                    panic!(
+                        "assertion failed: `left ≈ right`\n  left: `{:?}`\n right: `{:?}`",
+                        left, right
+                    );
+                }
+            }
+        }
+    };
+    ($left:expr, $right:expr, $epsilon:expr $(,)?) => {
+        match (&$left, &$right) {
+            (left, right) => {
+                if !$crate::ApproxEq::abs_diff_eq(left, right, $epsilon) {
+                    panic!(
+                        "assertion failed: `left ≈ right` (epsilon: {:?})\n  left: `{:?}`\n right: `{:?}`",
+                        $epsilon, left, right
+                    );
+                }
+            }
+        }
+    };
+}
+
+/// Asserts that two values are equal according to `ApproxEq::relative_eq`
+/// # Examples
+/// ```
+/// use vecx::{assert_relative_eq, Vec3};
+///
+/// assert_relative_eq!(Vec3(1000.0, 0.0, 1.0), Vec3(1000.1, 0.0, 1.0), 1e-9, 1e-3);
+/// ```
+#[macro_export]
+macro_rules! assert_relative_eq {
+    ($left:expr, $right:expr, $epsilon:expr, $max_relative:expr $(,)?) => {
+        match (&$left, &$right) {
+            (left, right) => {
+                if !$crate::ApproxEq::relative_eq(left, right, $epsilon, $max_relative) {
+                    panic!(
+                        "assertion failed: `left ≈ right` (epsilon: {:?}, max_relative: {:?})\n  left: `{:?}`\n right: `{:?}`",
+                        $epsilon, $max_relative, left, right
+                    );
+                }
+            }
+        }
+    };
+}
+
+/// Asserts that two values are equal according to `ApproxEq::ulps_eq`
+/// # Examples
+/// ```
+/// use vecx::assert_ulps_eq;
+///
+/// assert_ulps_eq!(0.1 + 0.2, 0.3, 0.0, 1);
+/// ```
+#[macro_export]
+macro_rules! assert_ulps_eq {
+    ($left:expr, $right:expr, $epsilon:expr, $max_ulps:expr $(,)?) => {
+        match (&$left, &$right) {
+            (left, right) => {
+                if !$crate::ApproxEq::ulps_eq(left, right, $epsilon, $max_ulps) {
+                    panic!(
+                        "assertion failed: `left ≈ right` (epsilon: {:?}, max_ulps: {:?})\n  left: `{:?}`\n right: `{:?}`",
+                        $epsilon, $max_ulps, left, right
+                    );
+                }
+            }
+        }
+    };
+}
@@ -0,0 +1,467 @@
+//! Bezier curves and B-splines over any `f64` vector
+//!
+//! Both implement `Curve`, which adds arc length, arc-length parameterization and bounding
+//! boxes on top of point and derivative evaluation, so a `Vec2` path and a `Vec3` rail share
+//! the same API.
+use crate::{VecX, VecxError};
+
+/// Subintervals of every polynomial span integrated with Gauss-Legendre quadrature
+const LENGTH_STEPS: usize = 8;
+
+/// Samples of every polynomial span searched for sign changes of the derivative
+const BOUNDS_SAMPLES: usize = 64;
+
+/// Control points a Bezier curve evaluates on the stack, higher degrees allocate a copy
+const STACK_POINTS: usize = 8;
+
+/// Nodes and weights of the 5 point Gauss-Legendre quadrature over -1..=1
+const GAUSS_LEGENDRE: [(f64, f64); 5] = [
+    (0.0, 0.568_888_888_888_888_9),
+    (-0.538_469_310_105_683_1, 0.478_628_670_499_366_5),
+    (0.538_469_310_105_683_1, 0.478_628_670_499_366_5),
+    (-0.906_179_845_938_664, 0.236_926_885_056_189_1),
+    (0.906_179_845_938_664, 0.236_926_885_056_189_1),
+];
+
+/// Parametric curve made of polynomial spans
+pub trait Curve<V: VecX> {
+    /// Curve of the first derivative, a Bezier curve or B-spline of one degree less
+    type Derivative: Curve<V>;
+
+    /// Parameter intervals on which the curve is a single polynomial, in increasing order
+    fn spans(&self) -> Vec<(f64, f64)>;
+
+    /// Point at parameter `t`
+    fn at(&self, t: f64) -> V;
+
+    /// Curve whose points are the first derivative of this curve over the same domain
+    ///
+    /// Build it once to evaluate the derivative at many parameters, `derivative_at` builds it
+    /// on every call.
+    fn derivative(&self) -> Self::Derivative;
+
+    /// First derivative with respect to `t`, the velocity of a point moving along the curve
+    fn derivative_at(&self, t: f64) -> V {
+        self.derivative().at(t)
+    }
+
+    /// First and last parameter of the curve
+    fn domain(&self) -> (f64, f64) {
+        let spans = self.spans();
+        (spans[0].0, spans[spans.len() - 1].1)
+    }
+
+    /// Arc length of the whole curve, see `length_between`
+    fn length(&self) -> f64 {
+        let (start, end) = self.domain();
+        self.length_between(start, end)
+    }
+
+    /// Arc length from parameter `from` to parameter `to`, 0.0 if `to <= from`
+    fn length_between(&self, from: f64, to: f64) -> f64 {
+        arc_length(&self.spans(), &self.derivative(), from, to)
+    }
+
+    /// Parameter at which the arc length from the start of the curve reaches `distance`,
+    /// `distance` is clamped between 0.0 and `length()`
+    ///
+    /// Moving `distance` by equal steps moves along the curve at constant speed.
+    fn t_at_length(&self, distance: f64) -> f64 {
+        let (spans, derivative) = (self.spans(), self.derivative());
+        let (start, end) = (spans[0].0, spans[spans.len() - 1].1);
+        let total = arc_length(&spans, &derivative, start, end);
+        if distance <= 0.0 || total == 0.0 {
+            return start;
+        }
+        if distance >= total {
+            return end;
+        }
+
+        // Newton's method on length_between(start, t) - distance, falling back to bisection
+        let (mut low, mut high) = (start, end);
+        let mut t = start + (end - start) * distance / total;
+        for _ in 0..64 {
+            let error = arc_length(&spans, &derivative, start, t) - distance;
+            if error.abs() <= f64::EPSILON * total {
+                break;
+            }
+            if error > 0.0 {
+                high = t;
+            } else {
+                low = t;
+            }
+            let speed = derivative.at(t).magnitude();
+            let next = t - error / speed;
+            t = if speed > 0.0 && next > low && next < high {
+                next
+            } else {
+                (low + high) * 0.5
+            };
+            if high - low <= f64::EPSILON * (end - start) {
+                break;
+            }
+        }
+        t
+    }
+
+    /// Point at `distance` along the curve, see `t_at_length`
+    fn at_length(&self, distance: f64) -> V {
+        self.at(self.t_at_length(distance))
+    }
+
+    /// Component-wise `(min, max)` corners of the smallest box containing the curve
+    ///
+    /// Extremes are found by bisecting every sign change of the derivative, two extremes closer
+    /// than 1 / 64 of a span may be missed but the curve barely moves between them.
+    fn bounding_box(&self) -> (V, V) {
+        let (spans, derivative) = (self.spans(), self.derivative());
+        let (mut min, mut max) = (self.at(spans[0].0), self.at(spans[0].0));
+        for (a, b) in spans {
+            let step = (b - a) / BOUNDS_SAMPLES as f64;
+            let mut previous = (a, derivative.at(a));
+            for i in 1..=BOUNDS_SAMPLES {
+                let t = if i == BOUNDS_SAMPLES {
+                    b
+                } else {
+                    a + step * i as f64
+                };
+                let velocity = derivative.at(t);
+                for axis in 0..V::size() {
+                    let extreme = if velocity.at(axis) == 0.0 {
+                        t
+                    } else if previous.1.at(axis) * velocity.at(axis) < 0.0 {
+                        derivative_root(&derivative, axis, previous.0, t)
+                    } else {
+                        continue;
+                    };
+                    let extreme = self.at(extreme);
+                    min = min.min(&extreme);
+                    max = max.max(&extreme);
+                }
+                previous = (t, velocity);
+            }
+            let end = self.at(b);
+            min = min.min(&end);
+            max = max.max(&end);
+        }
+        (min, max)
+    }
+}
+
+/// Arc length from `from` to `to` over `spans`, integrating the magnitude of `derivative`
+fn arc_length<V: VecX, C: Curve<V>>(
+    spans: &[(f64, f64)],
+    derivative: &C,
+    from: f64,
+    to: f64,
+) -> f64 {
+    let mut length = 0.0;
+    for &(a, b) in spans {
+        let (a, b) = (a.max(from), b.min(to));
+        if b <= a {
+            continue;
+        }
+        let step = (b - a) / LENGTH_STEPS as f64;
+        for i in 0..LENGTH_STEPS {
+            let center = a + step * (i as f64 + 0.5);
+            length += GAUSS_LEGENDRE
+                .iter()
+                .map(|(x, w)| w * derivative.at(center + x * step * 0.5).magnitude())
+                .sum::<f64>()
+                * step
+                * 0.5;
+        }
+    }
+    length
+}
+
+/// Bisects the sign change of the `axis` component of `derivative` between `low` and `high`
+fn derivative_root<V: VecX, C: Curve<V>>(
+    derivative: &C,
+    axis: usize,
+    mut low: f64,
+    mut high: f64,
+) -> f64 {
+    let low_sign = derivative.at(low).at(axis).signum();
+    while high - low > f64::EPSILON * high.abs().max(1.0) {
+        let middle = (low + high) * 0.5;
+        if middle <= low || middle >= high {
+            break;
+        }
+        if derivative.at(middle).at(axis).signum() == low_sign {
+            low = middle;
+        } else {
+            high = middle;
+        }
+    }
+    (low + high) * 0.5
+}
+
+/*
+    Bezier
+*/
+/// Bezier curve of any degree, going from the first control point at `t = 0.0` to the last one
+/// at `t = 1.0`
+#[derive(Debug, Clone, PartialEq)]
+pub struct Bezier<V> {
+    points: Vec<V>,
+}
+
+impl<V: VecX> Bezier<V> {
+    /// Curve of degree `points.len() - 1`, panics without any point, see `try_new`
+    pub fn new(points: Vec<V>) -> Self {
+        Self::try_new(points).unwrap_or_else(|e| panic!("{}", e))
+    }
+
+    /// Curve of degree `points.len() - 1`, or `VecxError::NotEnoughPoints` without any point
+    pub fn try_new(points: Vec<V>) -> Result<Self, VecxError> {
+        if points.is_empty() {
+            return Err(VecxError::NotEnoughPoints {
+                required: 1,
+                len: 0,
+            });
+        }
+        Ok(Bezier { points })
+    }
+
+    pub fn quadratic(p0: V, p1: V, p2: V) -> Self {
+        Bezier {
+            points: vec![p0, p1, p2],
+        }
+    }
+
+    pub fn cubic(p0: V, p1: V, p2: V, p3: V) -> Self {
+        Bezier {
+            points: vec![p0, p1, p2, p3],
+        }
+    }
+
+    pub fn points(&self) -> &[V] {
+        &self.points
+    }
+
+    pub fn degree(&self) -> usize {
+        self.points.len() - 1
+    }
+
+    /// Splits the curve at `t` into two curves of the same degree, going from 0.0 to `t` and
+    /// from `t` to 1.0
+    pub fn split(&self, t: f64) -> (Bezier<V>, Bezier<V>) {
+        // the first and last points of every row of the de Casteljau triangle
+        let mut row = self.points.clone();
+        let mut left = Vec::with_capacity(row.len());
+        let mut right = Vec::with_capacity(row.len());
+        for len in (1..=row.len()).rev() {
+            left.push(row[0]);
+            right.push(row[len - 1]);
+            interpolate(&mut row[..len], t);
+        }
+        right.reverse();
+        (Bezier { points: left }, Bezier { points: right })
+    }
+}
+
+impl<V: VecX> Curve<V> for Bezier<V> {
+    type Derivative = Bezier<V>;
+
+    fn spans(&self) -> Vec<(f64, f64)> {
+        vec![(0.0, 1.0)]
+    }
+
+    /// De Casteljau's algorithm, `t` outside of 0.0..=1.0 extrapolates the curve
+    fn at(&self, t: f64) -> V {
+        let len = self.points.len();
+        if len > STACK_POINTS {
+            return de_casteljau(&mut self.points.clone(), t);
+        }
+        let mut row = [V::from(0.0); STACK_POINTS];
+        row[..len].copy_from_slice(&self.points);
+        de_casteljau(&mut row[..len], t)
+    }
+
+    /// Bezier curve of one degree less whose points are the derivative of this curve,
+    /// a single zero point for a constant curve
+    fn derivative(&self) -> Bezier<V> {
+        if self.points.len() == 1 {
+            return Bezier {
+                points: vec![V::from(0.0)],
+            };
+        }
+        let degree = self.degree() as f64;
+        Bezier {
+            points: self
+                .points
+                .windows(2)
+                .map(|pair| (pair[1] - pair[0]) * degree)
+                .collect(),
+        }
+    }
+}
+
+/// Point at `t` of the Bezier curve of `points`, overwriting them with the de Casteljau triangle
+fn de_casteljau<V: VecX>(points: &mut [V], t: f64) -> V {
+    for len in (2..=points.len()).rev() {
+        interpolate(&mut points[..len], t);
+    }
+    points[0]
+}
+
+/// One step of de Casteljau's algorithm in place, every point but the last becomes the point at
+/// `t` between itself and the next one
+fn interpolate<V: VecX>(points: &mut [V], t: f64) {
+    for i in 1..points.len() {
+        points[i - 1] = points[i - 1] * (1.0 - t) + points[i] * t;
+    }
+}
+
+/*
+    BSpline
+*/
+/// B-spline of any degree with control `points` and a non-decreasing knot vector
+///
+/// Its domain goes from `knots[degree]` to `knots[points.len()]`, every span between two
+/// distinct knots is a polynomial of `degree` shaped by `degree + 1` control points.
+#[derive(Debug, Clone, PartialEq)]
+pub struct BSpline<V> {
+    degree: usize,
+    points: Vec<V>,
+    knots: Vec<f64>,
+}
+
+impl<V: VecX> BSpline<V> {
+    /// Panics if the knots or the number of points are invalid, see `try_new`
+    pub fn new(degree: usize, points: Vec<V>, knots: Vec<f64>) -> Self {
+        Self::try_new(degree, points, knots).unwrap_or_else(|e| panic!("{}", e))
+    }
+
+    /// Returns the B-spline or an error with fewer than `degree + 1` points, without
+    /// `points.len() + degree + 1` knots, or if the knots are decreasing or give an empty domain
+    pub fn try_new(degree: usize, points: Vec<V>, knots: Vec<f64>) -> Result<Self, VecxError> {
+        if points.len() <= degree {
+            return Err(VecxError::NotEnoughPoints {
+                required: degree + 1,
+                len: points.len(),
+            });
+        }
+        if knots.len() != points.len() + degree + 1 {
+            return Err(VecxError::InvalidKnotCount {
+                expected: points.len() + degree + 1,
+                len: knots.len(),
+            });
+        }
+        let increasing = knots.windows(2).all(|pair| pair[0] <= pair[1]);
+        if !increasing || knots[degree] >= knots[points.len()] {
+            return Err(VecxError::InvalidKnots);
+        }
+        Ok(BSpline {
+            degree,
+            points,
+            knots,
+        })
+    }
+
+    /// Uniform B-spline with knots 0.0, 1.0, 2.0..., which doesn't go through its first and
+    /// last points
+    ///
+    /// Panics with fewer than `degree + 1` points
+    pub fn uniform(degree: usize, points: Vec<V>) -> Self {
+        let knots = (0..points.len() + degree + 1).map(|i| i as f64).collect();
+        Self::new(degree, points, knots)
+    }
+
+    /// Uniform B-spline over 0.0..=1.0 with its end knots repeated `degree + 1` times,
+    /// so it starts at the first point and ends at the last one
+    ///
+    /// Panics with fewer than `degree + 1` points
+    pub fn clamped(degree: usize, points: Vec<V>) -> Self {
+        let spans = points.len().saturating_sub(degree).max(1);
+        let knots = (0..points.len() + degree + 1)
+            .map(|i| (i.saturating_sub(degree) as f64 / spans as f64).min(1.0))
+            .collect();
+        Self::new(degree, points, knots)
+    }
+
+    pub fn degree(&self) -> usize {
+        self.degree
+    }
+
+    pub fn points(&self) -> &[V] {
+        &self.points
+    }
+
+    pub fn knots(&self) -> &[f64] {
+        &self.knots
+    }
+
+    /// Index `k` of the non-empty span `knots[k]..knots[k + 1]` containing `t`, clamped to the domain
+    fn span(&self, t: f64) -> usize {
+        let last = self.points.len() - 1;
+        let mut k = self
+            .knots
+            .partition_point(|&knot| knot <= t)
+            .saturating_sub(1)
+            .clamp(self.degree, last);
+        while self.knots[k] == self.knots[k + 1] {
+            k -= 1;
+        }
+        k
+    }
+}
+
+impl<V: VecX> Curve<V> for BSpline<V> {
+    type Derivative = BSpline<V>;
+
+    fn spans(&self) -> Vec<(f64, f64)> {
+        (self.degree..self.points.len())
+            .map(|k| (self.knots[k], self.knots[k + 1]))
+            .filter(|(a, b)| a < b)
+            .collect()
+    }
+
+    /// De Boor's algorithm, `t` is clamped to the domain
+    fn at(&self, t: f64) -> V {
+        let (start, end) = (self.knots[self.degree], self.knots[self.points.len()]);
+        let t = t.clamp(start, end);
+        let (p, k) = (self.degree, self.span(t));
+        let mut d: Vec<V> = self.points[k - p..=k].to_vec();
+        for r in 1..=p {
+            for j in (r..=p).rev() {
+                let i = j + k - p;
+                let alpha = (t - self.knots[i]) / (self.knots[i + p + 1 - r] - self.knots[i]);
+                d[j] = d[j - 1] * (1.0 - alpha) + d[j] * alpha;
+            }
+        }
+        d[p]
+    }
+
+    /// B-spline of one degree less over the same domain whose points are the derivative of
+    /// this curve, zero everywhere for a degree 0 spline
+    fn derivative(&self) -> BSpline<V> {
+        if self.degree == 0 {
+            return BSpline {
+                degree: 0,
+                points: vec![V::from(0.0); self.points.len()],
+                knots: self.knots.clone(),
+            };
+        }
+        let degree = self.degree;
+        let points = self
+            .points
+            .windows(2)
+            .enumerate()
+            .map(|(i, pair)| {
+                let width = self.knots[i + degree + 1] - self.knots[i + 1];
+                if width > 0.0 {
+                    (pair[1] - pair[0]) * (degree as f64 / width)
+                } else {
+                    V::from(0.0)
+                }
+            })
+            .collect();
+        BSpline {
+            degree: degree - 1,
+            points,
+            knots: self.knots[1..self.knots.len() - 1].to_vec(),
+        }
+    }
+}
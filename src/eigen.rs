@@ -0,0 +1,101 @@
+use crate::mat::SINGULAR_EPSILON;
+use crate::{Matrix, VecxError};
+
+/// Sweeps after which the Jacobi iterations give up, convergence usually takes fewer than 10
+const MAX_SWEEPS: usize = 100;
+
+/// Eigenvalues in decreasing order and the matching unit eigenvectors as columns
+///
+/// Uses the cyclic Jacobi method, which rotates away the off-diagonal values one pair at a time.
+/// Fails with `NotConverged` if they're still too large after `MAX_SWEEPS` sweeps.
+pub(crate) fn symmetric_eigen(m: &Matrix) -> Result<(Vec<f64>, Matrix), VecxError> {
+    if !m.is_square() {
+        return Err(VecxError::NotSquare {
+            rows: m.rows(),
+            cols: m.cols(),
+        });
+    }
+
+    let n = m.rows();
+    let mut a: Vec<Vec<f64>> = (1..=n).map(|row| m.get_row(row)).collect();
+    let largest = a.iter().flatten().fold(0.0, |max: f64, v| max.max(v.abs()));
+    let symmetric = (0..n).all(|row| {
+        ((row + 1)..n).all(|col| (a[row][col] - a[col][row]).abs() <= SINGULAR_EPSILON * largest)
+    });
+    if !symmetric {
+        return Err(VecxError::NotSymmetric);
+    }
+
+    let identity = Matrix::identity(n);
+    let mut v: Vec<Vec<f64>> = (1..=n).map(|row| identity.get_row(row)).collect();
+    let frobenius: f64 = a.iter().flatten().map(|x| x * x).sum();
+    let mut sweeps = 0;
+    loop {
+        // NaN never passes, so non-finite values end up not converging
+        if off_diagonal(&a) <= f64::EPSILON * f64::EPSILON * frobenius {
+            break;
+        }
+        if sweeps == MAX_SWEEPS {
+            return Err(VecxError::NotConverged { sweeps });
+        }
+        sweeps += 1;
+
+        for p in 0..n {
+            for q in (p + 1)..n {
+                if a[p][q] == 0.0 {
+                    continue;
+                }
+                // rotation by the smaller angle that zeroes a[p][q]
+                let theta = (a[q][q] - a[p][p]) / (2.0 * a[p][q]);
+                let t = theta.signum() / (theta.abs() + f64::sqrt(theta * theta + 1.0));
+                let c = 1.0 / f64::sqrt(t * t + 1.0);
+                let s = t * c;
+
+                for row in a.iter_mut() {
+                    (row[p], row[q]) = rotate(row[p], row[q], c, s);
+                }
+                rotate_vectors(&mut a, p, q, c, s);
+                for row in v.iter_mut() {
+                    (row[p], row[q]) = rotate(row[p], row[q], c, s);
+                }
+            }
+        }
+    }
+
+    let mut order: Vec<usize> = (0..n).collect();
+    order.sort_by(|&i, &j| a[j][j].total_cmp(&a[i][i]));
+    let values = order.iter().map(|&i| a[i][i]).collect();
+    let vectors = Matrix::from(
+        v.iter()
+            .map(|row| order.iter().map(|&i| row[i]).collect())
+            .collect::<Vec<Vec<f64>>>(),
+    );
+    Ok((values, vectors))
+}
+
+/// Sum of the squared values outside the diagonal
+fn off_diagonal(a: &[Vec<f64>]) -> f64 {
+    a.iter()
+        .enumerate()
+        .flat_map(|(row, values)| {
+            values
+                .iter()
+                .enumerate()
+                .filter(move |&(col, _)| col != row)
+        })
+        .map(|(_, x)| x * x)
+        .sum()
+}
+
+/// Applies the Givens rotation `[c, -s; s, c]` to the pair `(p, q)`
+fn rotate(p: f64, q: f64, c: f64, s: f64) -> (f64, f64) {
+    (c * p - s * q, s * p + c * q)
+}
+
+/// Rotates every pair of values of `vectors[p]` and `vectors[q]`, `p < q`
+pub(crate) fn rotate_vectors(vectors: &mut [Vec<f64>], p: usize, q: usize, c: f64, s: f64) {
+    let (left, right) = vectors.split_at_mut(q);
+    for (x, y) in left[p].iter_mut().zip(right[0].iter_mut()) {
+        (*x, *y) = rotate(*x, *y, c, s);
+    }
+}
@@ -0,0 +1,175 @@
+use std::error::Error;
+use std::fmt;
+
+/// Errors returned by the `try_` and `checked_` counterparts of panicking operations
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum VecxError {
+    /// Swizzle length doesn't match the size of the requested vector
+    InvalidSwizzleLength { swizzle: String, expected: usize },
+    /// Component name isn't part of the vector
+    InvalidComponent(char),
+    /// Component written more than once by a swizzle assignment
+    DuplicateComponent(char),
+    /// Vector of magnitude 0 can't be normalized
+    ZeroMagnitude,
+    /// A component of 0.0 divided by 0.0
+    ZeroByZeroDivision,
+    /// Integer component divided by 0
+    IntegerDivisionByZero,
+    /// Remainder with a divisor of 0.0
+    ZeroDivisor,
+    /// 1-based row index out of range
+    InvalidRowIndex {
+        row: usize,
+        rows: usize,
+        cols: usize,
+    },
+    /// 1-based column index out of range
+    InvalidColIndex {
+        col: usize,
+        rows: usize,
+        cols: usize,
+    },
+    /// Columns of the left matrix don't match rows of the right matrix
+    InvalidMultiplication {
+        left: (usize, usize),
+        right: (usize, usize),
+    },
+    /// Element-wise operation on matrices of different dimensions
+    DimensionMismatch {
+        left: (usize, usize),
+        right: (usize, usize),
+    },
+    /// Content dimensions don't match the matrix dimensions
+    InvalidContent {
+        content: (usize, usize),
+        matrix: (usize, usize),
+    },
+    /// Flat data length doesn't match the matrix dimensions
+    InvalidDataLength { len: usize, matrix: (usize, usize) },
+    /// Operation only defined for square matrices
+    NotSquare { rows: usize, cols: usize },
+    /// Operation only defined for 4x4 matrices
+    Not4x4 { rows: usize, cols: usize },
+    /// Right-hand side rows don't match the rows of the system matrix
+    InvalidRhs {
+        matrix: (usize, usize),
+        rhs: (usize, usize),
+    },
+    /// System matrix has no inverse
+    SingularMatrix,
+    /// System matrix is so close to singular that the solution can't be trusted
+    IllConditioned,
+    /// Fewer equations than unknowns, the least-squares solution isn't unique
+    Underdetermined { rows: usize, cols: usize },
+    /// Columns of the system matrix are linearly dependent
+    RankDeficient,
+    /// Operation only defined for symmetric matrices
+    NotSymmetric,
+    /// Curve has fewer control points than its degree requires
+    NotEnoughPoints { required: usize, len: usize },
+    /// Knot vector length doesn't match the degree and control points of a B-spline
+    InvalidKnotCount { expected: usize, len: usize },
+    /// Knot vector is decreasing somewhere or leaves the B-spline with an empty domain
+    InvalidKnots,
+    /// Iterative algorithm stopped before reaching its tolerance
+    NotConverged { sweeps: usize },
+    /// Transformed point has a w of 0, so it lies at infinity and can't be divided by w
+    PointAtInfinity,
+}
+
+impl fmt::Display for VecxError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            VecxError::InvalidSwizzleLength { swizzle, expected } => write!(
+                f,
+                "Invalid swizzle size: expected length {} vs swizzle {} length {}",
+                expected,
+                swizzle,
+                swizzle.len()
+            ),
+            VecxError::InvalidComponent(component) => {
+                write!(f, "Attempt to access invalid component '{}'", component)
+            }
+            VecxError::DuplicateComponent(component) => {
+                write!(f, "Attempt to assign component '{}' more than once", component)
+            }
+            VecxError::ZeroMagnitude => {
+                write!(f, "Can't be normalized because its magnitude is 0")
+            }
+            VecxError::ZeroByZeroDivision => write!(f, "Division of 0.0 by 0.0"),
+            VecxError::IntegerDivisionByZero => write!(f, "Attempt to divide an integer by 0"),
+            VecxError::ZeroDivisor => {
+                write!(f, "Attempt to find remainder with a divisor of 0")
+            }
+            VecxError::InvalidRowIndex { row, rows, cols } => {
+                write!(f, "Invalid row index {} for {}x{} matrix", row, rows, cols)
+            }
+            VecxError::InvalidColIndex { col, rows, cols } => {
+                write!(f, "Invalid column index {} for {}x{} matrix", col, rows, cols)
+            }
+            VecxError::InvalidMultiplication { left, right } => write!(
+                f,
+                "Invalid Matrix multiplication: {}x{} by {}x{}, columns of left need to equal rows of right",
+                left.0, left.1, right.0, right.1
+            ),
+            VecxError::DimensionMismatch { left, right } => write!(
+                f,
+                "Invalid element-wise Matrix operation: {}x{} and {}x{}, dimensions need to be equal",
+                left.0, left.1, right.0, right.1
+            ),
+            VecxError::InvalidContent { content, matrix } => write!(
+                f,
+                "Attempt to set {}x{} content into a {}x{} matrix",
+                content.0, content.1, matrix.0, matrix.1
+            ),
+            VecxError::InvalidDataLength { len, matrix } => write!(
+                f,
+                "Attempt to build a {}x{} matrix from {} values",
+                matrix.0, matrix.1, len
+            ),
+            VecxError::NotSquare { rows, cols } => {
+                write!(f, "Invalid operation on {}x{} matrix, it is not square", rows, cols)
+            }
+            VecxError::Not4x4 { rows, cols } => {
+                write!(f, "Invalid operation on {}x{} matrix, it is not 4x4", rows, cols)
+            }
+            VecxError::InvalidRhs { matrix, rhs } => write!(
+                f,
+                "Invalid right-hand side: {}x{} for a {}x{} system, rows need to match",
+                rhs.0, rhs.1, matrix.0, matrix.1
+            ),
+            VecxError::SingularMatrix => write!(f, "Matrix is singular"),
+            VecxError::IllConditioned => {
+                write!(f, "Matrix is too ill-conditioned to solve reliably")
+            }
+            VecxError::Underdetermined { rows, cols } => write!(
+                f,
+                "Underdetermined system: {}x{} matrix has fewer rows than columns",
+                rows, cols
+            ),
+            VecxError::RankDeficient => write!(f, "Matrix columns are linearly dependent"),
+            VecxError::NotSymmetric => write!(f, "Matrix is not symmetric"),
+            VecxError::NotEnoughPoints { required, len } => write!(
+                f,
+                "Curve needs at least {} control points, got {}",
+                required, len
+            ),
+            VecxError::InvalidKnotCount { expected, len } => {
+                write!(f, "Invalid knot vector: expected {} knots, got {}", expected, len)
+            }
+            VecxError::InvalidKnots => write!(
+                f,
+                "Knot vector needs to be non-decreasing with distinct knots around the domain"
+            ),
+            VecxError::NotConverged { sweeps } => {
+                write!(f, "Iterations didn't converge after {} sweeps", sweeps)
+            }
+            VecxError::PointAtInfinity => {
+                write!(f, "Transformed point has a w of 0, it is at infinity")
+            }
+        }
+    }
+}
+
+impl Error for VecxError {}
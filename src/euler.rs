@@ -0,0 +1,184 @@
+use crate::{Mat3, Mat4, Matrix, Quat, Vec3};
+use std::fmt;
+
+/*
+    Definition
+*/
+/// Order in which the rotations around each axis are applied, `XYZ` rotates around x first
+///
+/// Rotations are around the fixed world axes, so `XYZ` is the matrix `Rz * Ry * Rx`
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum EulerOrder {
+    XYZ,
+    XZY,
+    YXZ,
+    YZX,
+    ZXY,
+    ZYX,
+}
+
+impl EulerOrder {
+    pub const ALL: [EulerOrder; 6] = [
+        EulerOrder::XYZ,
+        EulerOrder::XZY,
+        EulerOrder::YXZ,
+        EulerOrder::YZX,
+        EulerOrder::ZXY,
+        EulerOrder::ZYX,
+    ];
+
+    /// Axis indices (0 = x, 1 = y, 2 = z) in application order
+    pub fn axes(&self) -> [usize; 3] {
+        match self {
+            EulerOrder::XYZ => [0, 1, 2],
+            EulerOrder::XZY => [0, 2, 1],
+            EulerOrder::YXZ => [1, 0, 2],
+            EulerOrder::YZX => [1, 2, 0],
+            EulerOrder::ZXY => [2, 0, 1],
+            EulerOrder::ZYX => [2, 1, 0],
+        }
+    }
+
+    /// 1.0 for the cyclic orders XYZ, YZX and ZXY, -1.0 for the others
+    fn parity(&self) -> f64 {
+        match self {
+            EulerOrder::XYZ | EulerOrder::YZX | EulerOrder::ZXY => 1.0,
+            _ => -1.0,
+        }
+    }
+}
+
+/// Direction of positive rotations
+///
+/// `Right` follows the right-hand rule like `Quat`, `Matrix::m4_rotate_x` and `Matrix::m4_rotate_y`.
+/// `Left` rotates the other way, like `Vec3::rot_x/y/z` and `Matrix::m4_rotate_z`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Handedness {
+    Left,
+    Right,
+}
+
+impl Handedness {
+    /// Sign turning an angle of this handedness into a right-hand rule angle
+    fn sign(&self) -> f64 {
+        match self {
+            Handedness::Left => -1.0,
+            Handedness::Right => 1.0,
+        }
+    }
+}
+
+/// Rotation described by one angle per axis, in radians, applied in `order`
+///
+/// `angles.x()` is always the angle around x whatever the order
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct EulerAngles {
+    pub angles: Vec3,
+    pub order: EulerOrder,
+    pub handedness: Handedness,
+}
+
+impl EulerAngles {
+    pub fn new(angles: Vec3, order: EulerOrder, handedness: Handedness) -> Self {
+        EulerAngles {
+            angles,
+            order,
+            handedness,
+        }
+    }
+
+    pub fn to_quat(&self) -> Quat {
+        let axes = [
+            Vec3(1.0, 0.0, 0.0),
+            Vec3(0.0, 1.0, 0.0),
+            Vec3(0.0, 0.0, 1.0),
+        ];
+        let sign = self.handedness.sign();
+        self.order.axes().iter().fold(Quat::IDENTITY, |q, &axis| {
+            Quat::from_axis_angle(axes[axis], sign * self.angles[axis]) * q
+        })
+    }
+
+    pub fn to_mat3(&self) -> Mat3 {
+        self.to_quat().to_mat3()
+    }
+
+    pub fn to_mat4(&self) -> Mat4 {
+        self.to_quat().to_mat4()
+    }
+
+    pub fn to_matrix(&self) -> Matrix {
+        Matrix::from(self.to_mat4())
+    }
+
+    /// Extracts the angles of the rotation matrix `m`
+    ///
+    /// In gimbal lock, when the middle rotation is ±90°, the last angle is set to 0
+    pub fn from_mat3(m: Mat3, order: EulerOrder, handedness: Handedness) -> Self {
+        let [i, j, k] = order.axes();
+        let parity = order.parity();
+        let at = |row: usize, col: usize| m[row][col];
+
+        let mut angles = Vec3(0.0, 0.0, 0.0);
+        // atan2 instead of asin keeps precision near ±90°
+        let cos_j = f64::hypot(at(i, i), at(j, i));
+        angles[j] = f64::atan2(-parity * at(k, i), cos_j);
+        if cos_j > 1e-12 {
+            angles[i] = f64::atan2(parity * at(k, j), at(k, k));
+            angles[k] = f64::atan2(parity * at(j, i), at(i, i));
+        } else {
+            angles[i] = f64::atan2(-parity * at(j, k), at(j, j));
+        }
+
+        EulerAngles::new(angles * handedness.sign(), order, handedness)
+    }
+
+    pub fn from_quat(q: Quat, order: EulerOrder, handedness: Handedness) -> Self {
+        EulerAngles::from_mat3(q.normalized().to_mat3(), order, handedness)
+    }
+
+    /// Same rotation expressed with another order and handedness
+    pub fn to_order(&self, order: EulerOrder, handedness: Handedness) -> Self {
+        EulerAngles::from_quat(self.to_quat(), order, handedness)
+    }
+}
+
+/*
+    Display
+*/
+impl fmt::Display for EulerAngles {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{:?} {:?}-handed {}",
+            self.order, self.handedness, self.angles
+        )
+    }
+}
+
+/*
+    From
+*/
+impl From<EulerAngles> for Quat {
+    fn from(e: EulerAngles) -> Self {
+        e.to_quat()
+    }
+}
+
+impl From<EulerAngles> for Mat3 {
+    fn from(e: EulerAngles) -> Self {
+        e.to_mat3()
+    }
+}
+
+impl From<EulerAngles> for Mat4 {
+    fn from(e: EulerAngles) -> Self {
+        e.to_mat4()
+    }
+}
+
+impl From<EulerAngles> for Matrix {
+    fn from(e: EulerAngles) -> Self {
+        e.to_matrix()
+    }
+}
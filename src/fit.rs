@@ -0,0 +1,164 @@
+use crate::mat::SINGULAR_EPSILON;
+use crate::{Matrix, Vec3, VecX};
+
+/*
+    Line
+*/
+/// Total least-squares line through 2D or 3D samples, `direction` is unit length
+#[derive(Debug, Clone, PartialEq)]
+pub struct LineFit<V> {
+    /// Centroid of the samples
+    pub point: V,
+    /// Points from the first sample toward the last one
+    pub direction: V,
+    /// Orthogonal distance of every sample to the line, in input order
+    pub residuals: Vec<f64>,
+}
+
+impl<V: VecX> LineFit<V> {
+    /// Fits a line minimizing the squared orthogonal distances, so noise on any coordinate is
+    /// handled alike and vertical lines are found
+    ///
+    /// The direction is the largest right singular vector of the centered samples.
+    /// Returns `None` with fewer than 2 distinct points.
+    pub fn fit(points: &[V]) -> Option<LineFit<V>> {
+        let (point, directions) = principal_directions(points)?;
+        let direction = directions[0].1;
+        if directions[0].0 <= spread_tolerance(points) {
+            return None;
+        }
+        let direction = if (points[points.len() - 1] - points[0]).dot_product(&direction) < 0.0 {
+            direction * -1.0
+        } else {
+            direction
+        };
+
+        let mut line = LineFit {
+            point,
+            direction,
+            residuals: vec![],
+        };
+        line.residuals = points.iter().map(|&p| line.distance(p)).collect();
+        Some(line)
+    }
+
+    /// Point at `t` along the line from the centroid
+    pub fn at(&self, t: f64) -> V {
+        self.point + self.direction * t
+    }
+
+    /// Closest point of the line to `p`
+    pub fn project(&self, p: V) -> V {
+        self.at((p - self.point).dot_product(&self.direction))
+    }
+
+    /// Orthogonal distance of `p` to the line
+    pub fn distance(&self, p: V) -> f64 {
+        (p - self.project(p)).magnitude()
+    }
+
+    /// Root mean square of the residuals
+    pub fn rms(&self) -> f64 {
+        rms(&self.residuals)
+    }
+}
+
+/*
+    Plane
+*/
+/// Total least-squares plane through 3D samples, `normal` is unit length
+#[derive(Debug, Clone, PartialEq)]
+pub struct PlaneFit {
+    /// Centroid of the samples
+    pub point: Vec3,
+    /// Has a positive z, or a positive y if z is 0, or a positive x if both are 0
+    pub normal: Vec3,
+    /// Signed distance of every sample to the plane along `normal`, in input order
+    pub residuals: Vec<f64>,
+}
+
+impl PlaneFit {
+    /// Fits a plane minimizing the squared orthogonal distances, so noise on any coordinate is
+    /// handled alike and vertical planes are found
+    ///
+    /// The normal is the smallest right singular vector of the centered samples.
+    /// Returns `None` with fewer than 3 points or when the points are collinear.
+    pub fn fit(points: &[Vec3]) -> Option<PlaneFit> {
+        if points.len() < 3 {
+            return None;
+        }
+        let (point, directions) = principal_directions(points)?;
+        if directions[1].0 <= spread_tolerance(points) {
+            return None;
+        }
+        let normal = directions[2].1;
+        let upward = [normal.z(), normal.y(), normal.x()]
+            .into_iter()
+            .find(|c| c.abs() > SINGULAR_EPSILON)
+            .unwrap_or(1.0);
+        let normal = if upward < 0.0 { -normal } else { normal };
+
+        let mut plane = PlaneFit {
+            point,
+            normal,
+            residuals: vec![],
+        };
+        plane.residuals = points.iter().map(|&p| plane.signed_distance(p)).collect();
+        Some(plane)
+    }
+
+    /// Distance of `p` to the plane, positive on the side `normal` points to
+    pub fn signed_distance(&self, p: Vec3) -> f64 {
+        (p - self.point).dot_product(&self.normal)
+    }
+
+    /// Closest point of the plane to `p`
+    pub fn project(&self, p: Vec3) -> Vec3 {
+        p - self.normal * self.signed_distance(p)
+    }
+
+    /// Root mean square of the residuals
+    pub fn rms(&self) -> f64 {
+        rms(&self.residuals)
+    }
+}
+
+/// Centroid of `points` and the right singular vectors of the centered points with their
+/// singular values, in decreasing order, `None` without any point
+fn principal_directions<V: VecX>(points: &[V]) -> Option<(V, Vec<(f64, V)>)> {
+    if points.is_empty() {
+        return None;
+    }
+    let centroid = points.iter().fold(V::from(0.0), |sum, &p| sum + p) / points.len() as f64;
+    let centered: Vec<Vec<f64>> = points
+        .iter()
+        .map(|&p| {
+            let offset = p - centroid;
+            (0..V::size()).map(|i| offset.at(i)).collect()
+        })
+        .collect();
+
+    // with fewer samples than dimensions the missing directions have a singular value of 0
+    let (_, sigma, vt) = Matrix::from(centered).svd();
+    let directions = (1..=V::size())
+        .map(|i| {
+            if i <= vt.rows() {
+                (sigma.get((i, i)), V::from(vt.get_row(i)))
+            } else {
+                (0.0, V::from(0.0))
+            }
+        })
+        .collect();
+    Some((centroid, directions))
+}
+
+/// Singular values below this are rounding noise of the centering
+fn spread_tolerance<V: VecX>(points: &[V]) -> f64 {
+    let norm: f64 = points.iter().map(|p| p.dot_product(p)).sum();
+    SINGULAR_EPSILON * norm.sqrt()
+}
+
+fn rms(residuals: &[f64]) -> f64 {
+    let sum: f64 = residuals.iter().map(|r| r * r).sum();
+    f64::sqrt(sum / residuals.len() as f64)
+}
@@ -0,0 +1,366 @@
+//! Geometric primitives built on `Vec3` and ray intersection tests
+//!
+//! Every primitive implements `Intersect`, so `ray.cast(&shape)` returns the closest `Hit`
+//! in front of the ray origin. Returned normals are unit length and point outward; planes and
+//! triangles have no outside, so their normal faces the ray origin instead.
+use crate::mat::SINGULAR_EPSILON;
+use crate::{Vec3, VecX};
+use std::fmt;
+
+/*
+    Hit
+*/
+/// Intersection of a ray with a surface
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Hit {
+    /// Distance from the ray origin along its unit direction
+    pub distance: f64,
+    pub point: Vec3,
+    pub normal: Vec3,
+}
+
+/// Surfaces a `Ray` can hit
+pub trait Intersect {
+    /// Returns the closest intersection at a distance >= 0, or `None` if the ray misses
+    fn intersect(&self, ray: &Ray) -> Option<Hit>;
+}
+
+/*
+    Ray
+*/
+/// Half-line starting at `origin`, `direction` is kept unit length
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Ray {
+    pub origin: Vec3,
+    pub direction: Vec3,
+}
+
+impl Ray {
+    /// Panics if `direction` is zero
+    pub fn new(origin: Vec3, direction: Vec3) -> Self {
+        Ray {
+            origin,
+            direction: direction.normalized(),
+        }
+    }
+
+    /// Point at `distance` along the ray
+    pub fn at(&self, distance: f64) -> Vec3 {
+        self.origin + self.direction * distance
+    }
+
+    pub fn cast<S: Intersect>(&self, shape: &S) -> Option<Hit> {
+        shape.intersect(self)
+    }
+
+    fn hit(&self, distance: f64, normal: Vec3) -> Hit {
+        Hit {
+            distance,
+            point: self.at(distance),
+            normal,
+        }
+    }
+}
+
+/*
+    Plane
+*/
+/// Infinite plane of points `p` with `normal . p = distance`, `normal` is kept unit length
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Plane {
+    pub normal: Vec3,
+    pub distance: f64,
+}
+
+impl Plane {
+    /// Panics if `normal` is zero
+    pub fn new(normal: Vec3, distance: f64) -> Self {
+        let magnitude = normal.magnitude();
+        Plane {
+            normal: normal.normalized(),
+            distance: distance / magnitude,
+        }
+    }
+
+    /// Panics if `normal` is zero
+    pub fn from_point_normal(point: Vec3, normal: Vec3) -> Self {
+        let normal = normal.normalized();
+        Plane {
+            normal,
+            distance: normal.dot_product(&point),
+        }
+    }
+
+    /// Plane through three points, normal following the right-hand rule from `a` to `b` to `c`
+    ///
+    /// Returns `None` if the points are collinear
+    pub fn from_points(a: Vec3, b: Vec3, c: Vec3) -> Option<Self> {
+        let normal = (b - a).cross(&(c - a)).try_normalized().ok()?;
+        Some(Plane::from_point_normal(a, normal))
+    }
+
+    /// Distance of `p` to the plane, positive on the side `normal` points to
+    pub fn signed_distance(&self, p: Vec3) -> f64 {
+        self.normal.dot_product(&p) - self.distance
+    }
+
+    /// Closest point of the plane to `p`
+    pub fn project(&self, p: Vec3) -> Vec3 {
+        p - self.normal * self.signed_distance(p)
+    }
+}
+
+impl Intersect for Plane {
+    fn intersect(&self, ray: &Ray) -> Option<Hit> {
+        let denominator = self.normal.dot_product(&ray.direction);
+        if denominator.abs() < SINGULAR_EPSILON {
+            return None;
+        }
+        let distance = -self.signed_distance(ray.origin) / denominator;
+        if distance < 0.0 {
+            return None;
+        }
+        let normal = if denominator > 0.0 {
+            -self.normal
+        } else {
+            self.normal
+        };
+        Some(ray.hit(distance, normal))
+    }
+}
+
+/*
+    Sphere
+*/
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Sphere {
+    pub center: Vec3,
+    pub radius: f64,
+}
+
+impl Sphere {
+    pub fn new(center: Vec3, radius: f64) -> Self {
+        Sphere { center, radius }
+    }
+
+    pub fn contains(&self, p: Vec3) -> bool {
+        let offset = p - self.center;
+        offset.dot_product(&offset) <= self.radius * self.radius
+    }
+}
+
+impl Intersect for Sphere {
+    /// From inside the sphere the exit point is returned
+    fn intersect(&self, ray: &Ray) -> Option<Hit> {
+        // |o + t * d - c|² = r² with |d| = 1 gives t² + 2bt + c = 0
+        let offset = ray.origin - self.center;
+        let b = offset.dot_product(&ray.direction);
+        let c = offset.dot_product(&offset) - self.radius * self.radius;
+        let discriminant = b * b - c;
+        if discriminant < 0.0 {
+            return None;
+        }
+
+        let root = discriminant.sqrt();
+        let distance = if -b - root >= 0.0 {
+            -b - root
+        } else {
+            -b + root
+        };
+        if distance < 0.0 {
+            return None;
+        }
+        let point = ray.at(distance);
+        Some(Hit {
+            distance,
+            point,
+            normal: (point - self.center) / self.radius,
+        })
+    }
+}
+
+/*
+    Aabb
+*/
+/// Axis-aligned bounding box, every component of `min` is at most the one of `max`
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Aabb {
+    pub min: Vec3,
+    pub max: Vec3,
+}
+
+impl Aabb {
+    /// Box with `a` and `b` as opposite corners, in any order
+    pub fn new(a: Vec3, b: Vec3) -> Self {
+        Aabb {
+            min: Vec3(a.0.min(b.0), a.1.min(b.1), a.2.min(b.2)),
+            max: Vec3(a.0.max(b.0), a.1.max(b.1), a.2.max(b.2)),
+        }
+    }
+
+    /// Smallest box containing every point, `None` if `points` is empty
+    pub fn from_points(points: &[Vec3]) -> Option<Self> {
+        let first = *points.first()?;
+        Some(
+            points
+                .iter()
+                .fold(Aabb::new(first, first), |aabb, &p| aabb.expanded(p)),
+        )
+    }
+
+    /// Smallest box containing `self` and `p`
+    pub fn expanded(&self, p: Vec3) -> Self {
+        Aabb::new(
+            Vec3(
+                self.min.0.min(p.0),
+                self.min.1.min(p.1),
+                self.min.2.min(p.2),
+            ),
+            Vec3(
+                self.max.0.max(p.0),
+                self.max.1.max(p.1),
+                self.max.2.max(p.2),
+            ),
+        )
+    }
+
+    pub fn center(&self) -> Vec3 {
+        (self.min + self.max) * 0.5
+    }
+
+    pub fn size(&self) -> Vec3 {
+        self.max - self.min
+    }
+
+    pub fn contains(&self, p: Vec3) -> bool {
+        (0..3).all(|axis| self.min[axis] <= p[axis] && p[axis] <= self.max[axis])
+    }
+}
+
+impl Intersect for Aabb {
+    /// Slab test, the ray is clipped between the two planes of every axis in turn.
+    /// From inside the box the exit point is returned.
+    fn intersect(&self, ray: &Ray) -> Option<Hit> {
+        let mut enter = (f64::NEG_INFINITY, 0, 0.0);
+        let mut exit = (f64::INFINITY, 0, 0.0);
+        for axis in 0..3 {
+            let origin = ray.origin[axis];
+            let direction = ray.direction[axis];
+            if direction == 0.0 {
+                if origin < self.min[axis] || origin > self.max[axis] {
+                    return None;
+                }
+                continue;
+            }
+
+            // the face crossed first faces against the ray
+            let (near, far, sign) = if direction > 0.0 {
+                (self.min[axis], self.max[axis], -1.0)
+            } else {
+                (self.max[axis], self.min[axis], 1.0)
+            };
+            let t_near = (near - origin) / direction;
+            let t_far = (far - origin) / direction;
+            if t_near > enter.0 {
+                enter = (t_near, axis, sign);
+            }
+            if t_far < exit.0 {
+                exit = (t_far, axis, -sign);
+            }
+        }
+
+        if enter.0 > exit.0 || exit.0 < 0.0 {
+            return None;
+        }
+        let (distance, axis, sign) = if enter.0 >= 0.0 { enter } else { exit };
+        let mut normal = Vec3(0.0, 0.0, 0.0);
+        normal[axis] = sign;
+        Some(ray.hit(distance, normal))
+    }
+}
+
+/*
+    Triangle
+*/
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Triangle(pub Vec3, pub Vec3, pub Vec3);
+
+impl Triangle {
+    /// Unit normal following the right-hand rule from the first to the third vertex,
+    /// panics if the triangle is degenerate
+    pub fn normal(&self) -> Vec3 {
+        let (e1, e2) = self.edges();
+        e1.cross(&e2).normalized()
+    }
+
+    pub fn area(&self) -> f64 {
+        let (e1, e2) = self.edges();
+        e1.cross(&e2).magnitude() * 0.5
+    }
+
+    pub fn centroid(&self) -> Vec3 {
+        (self.0 + self.1 + self.2) / 3.0
+    }
+
+    fn edges(&self) -> (Vec3, Vec3) {
+        (self.1 - self.0, self.2 - self.0)
+    }
+}
+
+impl Intersect for Triangle {
+    /// Möller–Trumbore, solves `o + t * d = a + u * e1 + v * e2` with Cramer's rule
+    fn intersect(&self, ray: &Ray) -> Option<Hit> {
+        let (e1, e2) = self.edges();
+        let p = ray.direction.cross(&e2);
+        let determinant = e1.dot_product(&p);
+        // ray parallel to the triangle, or degenerate triangle, relative to the edge lengths so
+        // tiny triangles are still hit
+        if determinant.abs() <= SINGULAR_EPSILON * e1.magnitude() * e2.magnitude() {
+            return None;
+        }
+
+        let inverse = 1.0 / determinant;
+        let s = ray.origin - self.0;
+        let u = s.dot_product(&p) * inverse;
+        if !(0.0..=1.0).contains(&u) {
+            return None;
+        }
+        let q = s.cross(&e1);
+        let v = ray.direction.dot_product(&q) * inverse;
+        if v < 0.0 || u + v > 1.0 {
+            return None;
+        }
+
+        let distance = e2.dot_product(&q) * inverse;
+        if distance < 0.0 {
+            return None;
+        }
+        // the determinant is -d . (e1 x e2), negative when the ray comes from the back
+        let normal = e1.cross(&e2).normalized();
+        let normal = if determinant < 0.0 { -normal } else { normal };
+        Some(ray.hit(distance, normal))
+    }
+}
+
+/*
+    Display
+*/
+impl fmt::Display for Ray {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "[origin: {}, direction: {}]",
+            self.origin, self.direction
+        )
+    }
+}
+
+impl fmt::Display for Hit {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "[distance: {}, point: {}, normal: {}]",
+            self.distance, self.point, self.normal
+        )
+    }
+}
@@ -0,0 +1,166 @@
+//! Easing functions and smooth interpolation through keyframes
+//!
+//! Curves are generic over `f64` vectors, so the same `Track` animates a `Vec2`, `Vec3` or `Vec4`.
+//! Unit directions are better interpolated with `Vec3::slerp`, these curves don't keep the length.
+use crate::VecX;
+
+/*
+    Easing
+*/
+/// Hermite smoothing of `x` from 0.0 at `edge0` to 1.0 at `edge1`, clamped outside the edges
+///
+/// Same as GLSL `smoothstep`, its slope is 0 at both edges
+pub fn smoothstep(edge0: f64, edge1: f64, x: f64) -> f64 {
+    let t = ((x - edge0) / (edge1 - edge0)).clamp(0.0, 1.0);
+    t * t * (3.0 - 2.0 * t)
+}
+
+/// Like `smoothstep` with a second derivative of 0 at both edges as well
+pub fn smootherstep(edge0: f64, edge1: f64, x: f64) -> f64 {
+    let t = ((x - edge0) / (edge1 - edge0)).clamp(0.0, 1.0);
+    t * t * t * (t * (6.0 * t - 15.0) + 10.0)
+}
+
+/*
+    Splines
+*/
+/// Cubic Hermite curve from `p0` with tangent `m0` at `t = 0.0` to `p1` with tangent `m1`
+/// at `t = 1.0`
+pub fn hermite<V: VecX>(p0: V, m0: V, p1: V, m1: V, t: f64) -> V {
+    let t2 = t * t;
+    let t3 = t2 * t;
+    p0 * (2.0 * t3 - 3.0 * t2 + 1.0)
+        + m0 * (t3 - 2.0 * t2 + t)
+        + p1 * (-2.0 * t3 + 3.0 * t2)
+        + m1 * (t3 - t2)
+}
+
+/// Uniform Catmull-Rom curve from `p1` at `t = 0.0` to `p2` at `t = 1.0`, `p0` and `p3` only
+/// shape the tangents
+pub fn catmull_rom<V: VecX>(p0: V, p1: V, p2: V, p3: V, t: f64) -> V {
+    hermite(p1, (p2 - p0) * 0.5, p2, (p3 - p1) * 0.5, t)
+}
+
+/*
+    Keyframes
+*/
+/// Value of an animated vector at `time`, `tangent` is its rate of change per unit of time
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Keyframe<V> {
+    pub time: f64,
+    pub value: V,
+    /// Only used by `Track::hermite`
+    pub tangent: V,
+}
+
+impl<V: VecX> Keyframe<V> {
+    /// Keyframe with a zero tangent
+    pub fn new(time: f64, value: V) -> Self {
+        Keyframe {
+            time,
+            value,
+            tangent: V::from(0.0),
+        }
+    }
+
+    pub fn with_tangent(time: f64, value: V, tangent: V) -> Self {
+        Keyframe {
+            time,
+            value,
+            tangent,
+        }
+    }
+}
+
+/// Sequence of keyframes sorted by time
+///
+/// Sampling before the first or after the last keyframe returns its value
+#[derive(Debug, Clone, PartialEq)]
+pub struct Track<V> {
+    keys: Vec<Keyframe<V>>,
+}
+
+impl<V: VecX> Track<V> {
+    /// Sorts `keys` by time, keyframes at the same time keep their order
+    pub fn new(mut keys: Vec<Keyframe<V>>) -> Self {
+        keys.sort_by(|a, b| a.time.total_cmp(&b.time));
+        Track { keys }
+    }
+
+    pub fn keys(&self) -> &[Keyframe<V>] {
+        &self.keys
+    }
+
+    /// Straight lines between keyframes, `None` if the track is empty
+    pub fn linear(&self, time: f64) -> Option<V> {
+        self.sample(time, |i, t| {
+            self.keys[i].value.lerp(&self.keys[i + 1].value, t)
+        })
+    }
+
+    /// Hermite curve through every keyframe with its own `tangent`, `None` if the track is empty
+    pub fn hermite(&self, time: f64) -> Option<V> {
+        self.sample(time, |i, t| {
+            let (a, b) = (&self.keys[i], &self.keys[i + 1]);
+            let duration = b.time - a.time;
+            hermite(
+                a.value,
+                a.tangent * duration,
+                b.value,
+                b.tangent * duration,
+                t,
+            )
+        })
+    }
+
+    /// Catmull-Rom curve through every keyframe, `None` if the track is empty
+    ///
+    /// Tangents are the finite differences of the neighbouring keyframes over their time span,
+    /// so unevenly spaced keyframes don't overshoot, and `tangent` is ignored.
+    pub fn catmull_rom(&self, time: f64) -> Option<V> {
+        self.sample(time, |i, t| {
+            let duration = self.keys[i + 1].time - self.keys[i].time;
+            hermite(
+                self.keys[i].value,
+                self.finite_difference(i) * duration,
+                self.keys[i + 1].value,
+                self.finite_difference(i + 1) * duration,
+                t,
+            )
+        })
+    }
+
+    /// Slope between the keyframes around `i`, one-sided at both ends
+    fn finite_difference(&self, i: usize) -> V {
+        let before = &self.keys[i.saturating_sub(1)];
+        let after = &self.keys[(i + 1).min(self.keys.len() - 1)];
+        let duration = after.time - before.time;
+        if duration <= 0.0 {
+            return V::from(0.0);
+        }
+        (after.value - before.value) / duration
+    }
+
+    /// Keyframe `i` right before `time` and how far `time` is toward keyframe `i + 1`,
+    /// from 0.0 to 1.0, or `None` outside of the track
+    fn segment_at(&self, time: f64) -> Option<(usize, f64)> {
+        let last = self.keys.len().checked_sub(1)?;
+        if last == 0 || time <= self.keys[0].time || time >= self.keys[last].time {
+            return None;
+        }
+        // first keyframe strictly after `time`, which is between 1 and `last`
+        let next = self.keys.partition_point(|key| key.time <= time);
+        let (a, b) = (&self.keys[next - 1], &self.keys[next]);
+        Some((next - 1, (time - a.time) / (b.time - a.time)))
+    }
+
+    /// Evaluates `segment(i, t)` with the result of `segment_at`, or clamps to the end keyframes
+    fn sample(&self, time: f64, segment: impl Fn(usize, f64) -> V) -> Option<V> {
+        let (first, last) = (self.keys.first()?, self.keys.last()?);
+        Some(match self.segment_at(time) {
+            Some((i, t)) => segment(i, t),
+            None if time <= first.time => first.value,
+            None => last.value,
+        })
+    }
+}
@@ -1,14 +1,49 @@
 #[cfg(test)]
 mod tests;
 
+mod approx;
+mod curve;
+mod eigen;
+mod error;
+mod euler;
+mod fit;
+mod geometry;
+mod interpolation;
+mod lu;
 mod mat;
+mod mat2;
+mod mat3;
+mod mat4;
+mod qr;
+mod quat;
+mod scalar;
+mod svd;
+mod swizzle;
+mod transform;
 mod vec2;
 mod vec3;
 mod vec4;
+mod vecn;
 mod vecx;
 
-pub use crate::mat::Matrix;
+pub use crate::approx::{ApproxEq, APPROX_EPSILON, APPROX_MAX_RELATIVE};
+pub use crate::curve::{BSpline, Bezier, Curve};
+pub use crate::error::VecxError;
+pub use crate::euler::{EulerAngles, EulerOrder, Handedness};
+pub use crate::fit::{LineFit, PlaneFit};
+pub use crate::geometry::{Aabb, Hit, Intersect, Plane, Ray, Sphere, Triangle};
+pub use crate::interpolation::{catmull_rom, hermite, smootherstep, smoothstep, Keyframe, Track};
+pub use crate::lu::{Lu, RightHandSide};
+pub use crate::mat::{DepthRange, Layout, Matrix};
+pub use crate::mat2::Mat2;
+pub use crate::mat3::Mat3;
+pub use crate::mat4::Mat4;
+pub use crate::qr::Qr;
+pub use crate::quat::Quat;
+pub use crate::scalar::Scalar;
+pub use crate::transform::Transform;
 pub use crate::vec2::Vec2;
 pub use crate::vec3::Vec3;
 pub use crate::vec4::Vec4;
+pub use crate::vecn::VecN;
 pub use crate::vecx::VecX;
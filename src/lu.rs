@@ -0,0 +1,285 @@
+use crate::mat::SINGULAR_EPSILON;
+use crate::{Matrix, Vec2, Vec3, Vec4, VecN, VecX, VecxError};
+
+/// Reciprocal condition numbers below this make `try_solve` fail with `VecxError::IllConditioned`
+///
+/// About 10 of the 16 significant digits of the solution can be lost past this point
+pub(crate) const ILL_CONDITIONED_RCOND: f64 = 1e-10;
+
+/*
+    Definition
+*/
+/// LU decomposition with partial pivoting of a square matrix, `P * A = L * U`
+///
+/// Factoring once and calling `solve` for every right-hand side avoids repeating the elimination
+#[derive(Debug, Clone)]
+pub struct Lu {
+    /// L below the diagonal (its unit diagonal isn't stored) and U on and above it
+    factors: Vec<Vec<f64>>,
+    /// Row `i` of `P * A` is row `pivots[i]` of `A`
+    pivots: Vec<usize>,
+    /// Determinant of `P`, 1.0 or -1.0
+    sign: f64,
+    /// 1-norm of `A`, the largest absolute column sum
+    norm: f64,
+    rcond: f64,
+}
+
+impl Lu {
+    /// Factors the square matrix `m`, singular matrices are factored too and reported by `is_singular`
+    ///
+    /// Panics if `m` isn't square, see `try_new`.
+    pub fn new(m: &Matrix) -> Self {
+        Lu::try_new(m).unwrap_or_else(|e| panic!("{}", e))
+    }
+
+    /// Factors the square matrix `m`, or `VecxError::NotSquare`
+    pub fn try_new(m: &Matrix) -> Result<Self, VecxError> {
+        m.check_square()?;
+
+        let n = m.rows();
+        let mut factors: Vec<Vec<f64>> = (1..=n).map(|row| m.get_row(row)).collect();
+        let mut pivots: Vec<usize> = (0..n).collect();
+        let mut sign = 1.0;
+        let norm = m.norm_1();
+
+        for pivot in 0..n {
+            let max_row = Matrix::pivot_row(&factors, pivot);
+            if max_row != pivot {
+                factors.swap(max_row, pivot);
+                pivots.swap(max_row, pivot);
+                sign = -sign;
+            }
+            if factors[pivot][pivot] == 0.0 {
+                continue;
+            }
+
+            let (upper, lower) = factors.split_at_mut(pivot + 1);
+            let pivot_values = &upper[pivot];
+            for row_values in lower.iter_mut() {
+                let factor = row_values[pivot] / pivot_values[pivot];
+                row_values[pivot] = factor;
+                for (value, pivot_value) in row_values.iter_mut().zip(pivot_values).skip(pivot + 1)
+                {
+                    *value -= factor * pivot_value;
+                }
+            }
+        }
+
+        let mut lu = Lu {
+            factors,
+            pivots,
+            sign,
+            norm,
+            rcond: 0.0,
+        };
+        lu.rcond = lu.condition_reciprocal();
+        Ok(lu)
+    }
+
+    pub fn size(&self) -> usize {
+        self.factors.len()
+    }
+
+    /// Unit lower triangular factor
+    pub fn l(&self) -> Matrix {
+        let n = self.size();
+        let mut l = Matrix::identity(n);
+        for row in 1..n {
+            for col in 0..row {
+                l.set((row + 1, col + 1), self.factors[row][col]);
+            }
+        }
+        l
+    }
+
+    /// Upper triangular factor
+    pub fn u(&self) -> Matrix {
+        let n = self.size();
+        let mut u = Matrix::new(n, n);
+        for row in 0..n {
+            for col in row..n {
+                u.set((row + 1, col + 1), self.factors[row][col]);
+            }
+        }
+        u
+    }
+
+    /// Permutation matrix `P` of `P * A = L * U`
+    pub fn p(&self) -> Matrix {
+        let n = self.size();
+        let mut p = Matrix::new(n, n);
+        for (row, &pivot) in self.pivots.iter().enumerate() {
+            p.set((row + 1, pivot + 1), 1.0);
+        }
+        p
+    }
+
+    pub fn determinant(&self) -> f64 {
+        (0..self.size()).fold(self.sign, |det, i| det * self.factors[i][i])
+    }
+
+    /// True when a pivot is negligible compared to the 1-norm of the matrix
+    pub fn is_singular(&self) -> bool {
+        let threshold = SINGULAR_EPSILON * self.norm;
+        self.norm == 0.0 || (0..self.size()).any(|i| self.factors[i][i].abs() <= threshold)
+    }
+
+    /// Reciprocal of the 1-norm condition number `|A| * |A^-1|`, 0 for singular matrices
+    ///
+    /// Close to 1 for well-conditioned matrices, close to 0 when small changes in the input
+    /// cause large changes in the solution.
+    pub fn rcond(&self) -> f64 {
+        self.rcond
+    }
+
+    fn condition_reciprocal(&self) -> f64 {
+        if self.is_singular() {
+            return 0.0;
+        }
+
+        let n = self.size();
+        let inverse_norm = (0..n)
+            .map(|col| {
+                let mut e = vec![0.0; n];
+                e[col] = 1.0;
+                self.substitute(e).iter().map(|v| v.abs()).sum::<f64>()
+            })
+            .fold(0.0, f64::max);
+        1.0 / (self.norm * inverse_norm)
+    }
+
+    pub fn solve<B: RightHandSide>(&self, b: &B) -> B {
+        self.try_solve(b).unwrap_or_else(|e| panic!("{}", e))
+    }
+
+    /// Solves `A * x = b` for a vector or for every column of a matrix
+    ///
+    /// A matrix `b` without columns gives a solution without columns.
+    /// Fails if `b` doesn't have as many rows as `A`, if `A` is singular or if its `rcond`
+    /// is below 1e-10.
+    pub fn try_solve<B: RightHandSide>(&self, b: &B) -> Result<B, VecxError> {
+        let n = self.size();
+        let (rows, columns) = (b.rows(), b.columns());
+        if rows != n || columns.iter().any(|col| col.len() != rows) {
+            return Err(VecxError::InvalidRhs {
+                matrix: (n, n),
+                rhs: (rows, columns.len()),
+            });
+        }
+        if self.is_singular() {
+            return Err(VecxError::SingularMatrix);
+        }
+        if self.rcond < ILL_CONDITIONED_RCOND {
+            return Err(VecxError::IllConditioned);
+        }
+
+        Ok(B::from_columns(
+            n,
+            columns
+                .into_iter()
+                .map(|col| self.substitute(col))
+                .collect(),
+        ))
+    }
+
+    /// Forward substitution through `L` then back substitution through `U`
+    fn substitute(&self, b: Vec<f64>) -> Vec<f64> {
+        let n = self.size();
+        let mut x: Vec<f64> = self.pivots.iter().map(|&row| b[row]).collect();
+        for row in 0..n {
+            for col in 0..row {
+                x[row] -= self.factors[row][col] * x[col];
+            }
+        }
+        for row in (0..n).rev() {
+            for col in (row + 1)..n {
+                x[row] -= self.factors[row][col] * x[col];
+            }
+            x[row] /= self.factors[row][row];
+        }
+        x
+    }
+}
+
+/*
+    Right-hand sides
+*/
+/// Right-hand side of `A * x = b`, a vector or a matrix holding one system per column
+///
+/// `rows` is kept apart from `columns` so a matrix without columns still has a height.
+pub trait RightHandSide: Sized {
+    fn rows(&self) -> usize;
+
+    fn columns(&self) -> Vec<Vec<f64>>;
+
+    fn from_columns(rows: usize, columns: Vec<Vec<f64>>) -> Self;
+}
+
+impl RightHandSide for Vec<f64> {
+    fn rows(&self) -> usize {
+        self.len()
+    }
+
+    fn columns(&self) -> Vec<Vec<f64>> {
+        vec![self.clone()]
+    }
+
+    fn from_columns(_rows: usize, mut columns: Vec<Vec<f64>>) -> Self {
+        columns.swap_remove(0)
+    }
+}
+
+macro_rules! impl_vector_rhs {
+    ($($t:ty),*) => {$(
+        impl RightHandSide for $t {
+            fn rows(&self) -> usize {
+                <$t>::size()
+            }
+
+            fn columns(&self) -> Vec<Vec<f64>> {
+                vec![(0..<$t>::size()).map(|idx| self[idx]).collect()]
+            }
+
+            fn from_columns(_rows: usize, mut columns: Vec<Vec<f64>>) -> Self {
+                <$t>::from(columns.swap_remove(0))
+            }
+        }
+    )*};
+}
+
+impl_vector_rhs!(Vec2, Vec3, Vec4);
+
+impl<const N: usize> RightHandSide for VecN<N> {
+    fn rows(&self) -> usize {
+        N
+    }
+
+    fn columns(&self) -> Vec<Vec<f64>> {
+        vec![self.0.to_vec()]
+    }
+
+    fn from_columns(_rows: usize, mut columns: Vec<Vec<f64>>) -> Self {
+        VecN::from(columns.swap_remove(0))
+    }
+}
+
+impl RightHandSide for Matrix {
+    fn rows(&self) -> usize {
+        Matrix::rows(self)
+    }
+
+    fn columns(&self) -> Vec<Vec<f64>> {
+        (1..=self.cols()).map(|col| self.get_col(col)).collect()
+    }
+
+    fn from_columns(rows: usize, columns: Vec<Vec<f64>>) -> Self {
+        let mut matrix = Matrix::new(rows, columns.len());
+        for (col, values) in columns.iter().enumerate() {
+            for (row, &value) in values.iter().enumerate() {
+                matrix.set((row + 1, col + 1), value);
+            }
+        }
+        matrix
+    }
+}
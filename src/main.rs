@@ -1,5 +1,4 @@
 use std::f64::consts::PI;
-use std::f64::INFINITY;
 
 use vecx::Vec2;
 use vecx::Vec3;
@@ -11,7 +10,7 @@ fn main() {
     let v3 = Vec2(3.0, 3.0);
     let v4 = Vec2(4.0, 4.0);
 
-    let v_asym = Vec2(5.0, 10.0);
+    let _v_asym = Vec2(5.0, 10.0);
 
     let f = 5.0;
     let z = 0.0;
@@ -54,7 +53,10 @@ fn main() {
 
     assert_eq!(Vec2(2.0, 4.0) / 2.0, Vec2(1.0, 2.0));
     assert_eq!(Vec2(2.0, 4.0) / Vec2(2.0, 4.0), Vec2(1.0, 1.0));
-    assert_eq!(Vec2(2.0, 4.0) / Vec2(0.0, 0.0), Vec2(INFINITY, INFINITY));
+    assert_eq!(
+        Vec2(2.0, 4.0) / Vec2(0.0, 0.0),
+        Vec2(f64::INFINITY, f64::INFINITY)
+    );
 
     let mut mod_assign_v2_f = Vec2(5.0, 8.0);
     mod_assign_v2_f %= 5.0;
@@ -72,7 +74,7 @@ fn main() {
     let v_mul_2 = 3.0 * Vec2(2.0, 2.0) * Vec3(4.0, 4.0, 4.0).s2("xy");
     assert_eq!(v_mul_2, Vec2(24.0, 24.0));
 
-    let v2_swz = Vec2(0.0, 1.0);
+    let _v2_swz = Vec2(0.0, 1.0);
     let v3_swz = Vec3(3.0, 5.0, 7.0);
 
     /*assert_eq!(v2_swz.s::<Vec2>("xx"), Vec2(0.0, 0.0));
@@ -93,7 +95,7 @@ fn main() {
     let swz = swizz.get("xx");
     v3_swz.r("xxxx").v3();*/
 
-    let swz = v3_swz.s3("xxx");
+    let _swz = v3_swz.s3("xxx");
 
     assert_eq!(v3_swz.s3("xxx"), Vec3(3.0, 3.0, 3.0));
     assert_eq!(v3_swz.s3("yyy"), Vec3(5.0, 5.0, 5.0));
@@ -120,7 +122,7 @@ fn main() {
     assert_eq!(Vec2(2.0, 2.0).dot(&Vec2(-3.0, -3.0)), -1.0);
     assert_eq!(Vec2(2.0, 2.0).dot(&Vec2(3.0, 3.0)), 1.0);
 
-    //assert_eq!(Vec2(0.0, 0.0).angle(&Vec2(0.0, 0.0)), INFINITY);
+    //assert_eq!(Vec2(0.0, 0.0).angle(&Vec2(0.0, 0.0)), f64::INFINITY);
 
     /*let add_v1_v2 = v1 + v2;
     assert_eq!(add_v1_v2, Vec2(3.0, 3.0));
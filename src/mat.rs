@@ -1,14 +1,47 @@
-use crate::{vecx, Vec3, Vec4};
+use crate::lu::RightHandSide;
+use crate::{eigen, svd, Lu, Qr, Vec3, Vec4, VecX, VecxError};
 use std::ops::{self};
 
 type FloatMat = Vec<Vec<f64>>;
 type MatIndex = (usize, usize);
 
-#[derive(Debug)]
+/// Pivots smaller than this are treated as zero when eliminating
+pub(crate) const SINGULAR_EPSILON: f64 = 1e-12;
+
+/// Whether `det` is negligible next to the product of the row norms, its largest possible
+/// magnitude by Hadamard's inequality, so uniformly scaling a matrix doesn't change the answer
+pub(crate) fn is_singular_determinant(det: f64, row_norms: &[f64]) -> bool {
+    det.abs() <= SINGULAR_EPSILON * row_norms.iter().product::<f64>()
+}
+
+/// Range the near and far planes are mapped to in NDC
+///
+/// `ZeroToOne` matches Direct3D, Vulkan and Metal, `NegOneToOne` matches OpenGL
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DepthRange {
+    ZeroToOne,
+    NegOneToOne,
+}
+
+/// Order of the values in the flat storage of a `Matrix`
+///
+/// `RowMajor` stores rows one after the other, `ColMajor` stores columns, as OpenGL expects
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Layout {
+    #[default]
+    RowMajor,
+    ColMajor,
+}
+
+/// Matrix of any size stored in a single contiguous `Vec<f64>`
+///
+/// Rows and columns are 1-based in the accessors, like in maths notation
+#[derive(Debug, Clone)]
 pub struct Matrix {
     rows: usize,
     cols: usize,
-    content: FloatMat,
+    layout: Layout,
+    data: Vec<f64>,
 }
 
 impl ops::Mul<&Matrix> for &Matrix {
@@ -39,6 +72,137 @@ impl ops::Mul<Matrix> for &Matrix {
     }
 }
 
+impl ops::Mul<Vec4> for &Matrix {
+    type Output = Vec4;
+    fn mul(self, rhs: Vec4) -> Self::Output {
+        self.multiply_vec4(rhs)
+    }
+}
+
+impl ops::Mul<Vec4> for Matrix {
+    type Output = Vec4;
+    fn mul(self, rhs: Vec4) -> Self::Output {
+        self.multiply_vec4(rhs)
+    }
+}
+
+impl ops::Mul<f64> for &Matrix {
+    type Output = Matrix;
+    fn mul(self, rhs: f64) -> Self::Output {
+        self.map(|value| value * rhs)
+    }
+}
+
+impl ops::Mul<f64> for Matrix {
+    type Output = Matrix;
+    fn mul(self, rhs: f64) -> Self::Output {
+        &self * rhs
+    }
+}
+
+impl ops::Mul<Matrix> for f64 {
+    type Output = Matrix;
+    fn mul(self, rhs: Matrix) -> Self::Output {
+        &rhs * self
+    }
+}
+
+impl ops::Mul<&Matrix> for f64 {
+    type Output = Matrix;
+    fn mul(self, rhs: &Matrix) -> Self::Output {
+        rhs * self
+    }
+}
+
+impl ops::MulAssign<f64> for Matrix {
+    fn mul_assign(&mut self, rhs: f64) {
+        *self = &*self * rhs;
+    }
+}
+
+impl ops::Div<f64> for &Matrix {
+    type Output = Matrix;
+    fn div(self, rhs: f64) -> Self::Output {
+        self.map(|value| value / rhs)
+    }
+}
+
+impl ops::Div<f64> for Matrix {
+    type Output = Matrix;
+    fn div(self, rhs: f64) -> Self::Output {
+        &self / rhs
+    }
+}
+
+impl ops::DivAssign<f64> for Matrix {
+    fn div_assign(&mut self, rhs: f64) {
+        *self = &*self / rhs;
+    }
+}
+
+impl ops::Neg for &Matrix {
+    type Output = Matrix;
+    fn neg(self) -> Self::Output {
+        self.map(|value| -value)
+    }
+}
+
+impl ops::Neg for Matrix {
+    type Output = Matrix;
+    fn neg(self) -> Self::Output {
+        -&self
+    }
+}
+
+/// Implements an element-wise operator for every combination of owned and borrowed matrices
+/// and its assign form, panicking with the error of `$try_method` when dimensions differ
+macro_rules! impl_element_wise_op {
+    ($trait:ident, $method:ident, $assign_trait:ident, $assign_method:ident, $try_method:ident) => {
+        impl ops::$trait<&Matrix> for &Matrix {
+            type Output = Matrix;
+            fn $method(self, rhs: &Matrix) -> Self::Output {
+                self.$try_method(rhs).unwrap_or_else(|e| panic!("{}", e))
+            }
+        }
+
+        impl ops::$trait<Matrix> for Matrix {
+            type Output = Matrix;
+            fn $method(self, rhs: Matrix) -> Self::Output {
+                ops::$trait::$method(&self, &rhs)
+            }
+        }
+
+        impl ops::$trait<&Matrix> for Matrix {
+            type Output = Matrix;
+            fn $method(self, rhs: &Matrix) -> Self::Output {
+                ops::$trait::$method(&self, rhs)
+            }
+        }
+
+        impl ops::$trait<Matrix> for &Matrix {
+            type Output = Matrix;
+            fn $method(self, rhs: Matrix) -> Self::Output {
+                ops::$trait::$method(self, &rhs)
+            }
+        }
+
+        impl ops::$assign_trait<&Matrix> for Matrix {
+            fn $assign_method(&mut self, rhs: &Matrix) {
+                *self = ops::$trait::$method(&*self, rhs);
+            }
+        }
+
+        impl ops::$assign_trait<Matrix> for Matrix {
+            fn $assign_method(&mut self, rhs: Matrix) {
+                *self = ops::$trait::$method(&*self, &rhs);
+            }
+        }
+    };
+}
+
+impl_element_wise_op!(Add, add, AddAssign, add_assign, try_add);
+impl_element_wise_op!(Sub, sub, SubAssign, sub_assign, try_sub);
+
 impl PartialEq<Matrix> for Matrix {
     fn eq(&self, other: &Matrix) -> bool {
         let same_dimensions = self.rows == other.rows && self.cols == other.cols;
@@ -46,39 +210,516 @@ impl PartialEq<Matrix> for Matrix {
             return false;
         }
 
-        for row in 0..self.rows {
-            if self.content[row] != other.content[row] {
-                return false;
-            }
+        if self.layout == other.layout {
+            return self.data == other.data;
         }
-        return true;
+        (0..self.rows).all(|row| (0..self.cols).all(|col| self.at(row, col) == other.at(row, col)))
     }
 }
 
 impl Matrix {
+    /// Zero matrix stored in row-major order
     pub fn new(rows: usize, cols: usize) -> Self {
+        Matrix::with_layout(rows, cols, Layout::RowMajor)
+    }
+
+    /// Zero matrix stored in `layout` order
+    pub fn with_layout(rows: usize, cols: usize, layout: Layout) -> Self {
         Matrix {
             rows,
             cols,
-            content: vec![vec![0.0; cols]; rows],
+            layout,
+            data: vec![0.0; rows * cols],
+        }
+    }
+
+    pub fn from_slice(rows: usize, cols: usize, layout: Layout, data: &[f64]) -> Self {
+        Matrix::try_from_slice(rows, cols, layout, data).unwrap_or_else(|e| panic!("{}", e))
+    }
+
+    /// Builds a `rows`x`cols` matrix from values stored in `layout` order
+    pub fn try_from_slice(
+        rows: usize,
+        cols: usize,
+        layout: Layout,
+        data: &[f64],
+    ) -> Result<Self, VecxError> {
+        if data.len() != rows * cols {
+            return Err(VecxError::InvalidDataLength {
+                len: data.len(),
+                matrix: (rows, cols),
+            });
         }
+        Ok(Matrix {
+            rows,
+            cols,
+            layout,
+            data: data.to_vec(),
+        })
+    }
+
+    pub fn layout(&self) -> Layout {
+        self.layout
+    }
+
+    /// Values in `layout` order, e.g. to upload them to the GPU without copying
+    pub fn as_slice(&self) -> &[f64] {
+        &self.data
+    }
+
+    pub fn as_mut_slice(&mut self) -> &mut [f64] {
+        &mut self.data
+    }
+
+    /// Same matrix stored in `layout` order
+    pub fn to_layout(&self, layout: Layout) -> Matrix {
+        if layout == self.layout {
+            return self.clone();
+        }
+        let mut result = Matrix::with_layout(self.rows, self.cols, layout);
+        for row in 0..self.rows {
+            for col in 0..self.cols {
+                let offset = result.offset(row, col);
+                result.data[offset] = self.at(row, col);
+            }
+        }
+        result
+    }
+
+    /// Distance in `as_slice` between two consecutive values of a row
+    pub fn col_stride(&self) -> usize {
+        match self.layout {
+            Layout::RowMajor => 1,
+            Layout::ColMajor => self.rows,
+        }
+    }
+
+    /// Distance in `as_slice` between two consecutive values of a column
+    pub fn row_stride(&self) -> usize {
+        match self.layout {
+            Layout::RowMajor => self.cols,
+            Layout::ColMajor => 1,
+        }
+    }
+
+    /// Offset in `data` of the 0-based `(row, col)`
+    fn offset(&self, row: usize, col: usize) -> usize {
+        row * self.row_stride() + col * self.col_stride()
+    }
+
+    /// Value at the 0-based `(row, col)`
+    fn at(&self, row: usize, col: usize) -> f64 {
+        self.data[self.offset(row, col)]
     }
 
     pub fn multiply(m1: &Matrix, m2: &Matrix) -> Matrix {
+        Matrix::try_multiply(m1, m2).unwrap_or_else(|e| panic!("{}", e))
+    }
+
+    /// Returns `m1 * m2` in row-major order, or `VecxError::InvalidMultiplication` if the
+    /// columns of `m1` don't match the rows of `m2`
+    ///
+    /// Each row of `m2` scaled by a value of `m1` is accumulated into a row of the result,
+    /// so a row-major `m2` is read sequentially and nothing is allocated but the result.
+    pub fn try_multiply(m1: &Matrix, m2: &Matrix) -> Result<Matrix, VecxError> {
         if m1.cols != m2.rows {
-            panic!("Invalid Matrix multiplication: {}x{} by {}x{}, columns of left need to equal rows of right", m1.rows, m1.cols, m2.rows, m2.cols);
+            return Err(VecxError::InvalidMultiplication {
+                left: (m1.rows, m1.cols),
+                right: (m2.rows, m2.cols),
+            });
         }
 
         let mut result = Matrix::new(m1.rows, m2.cols);
-        for row in 1..=result.rows {
-            for col in 1..=result.cols {
-                let value = vecx::vec_dot(m1.get_row(row), m2.get_col(col));
-                result.set((row, col), value);
+        if m2.cols == 0 {
+            return Ok(result);
+        }
+        let (row_stride, col_stride) = (m2.row_stride(), m2.col_stride());
+        for (row, result_row) in result.data.chunks_exact_mut(m2.cols).enumerate() {
+            for k in 0..m1.cols {
+                let factor = m1.at(row, k);
+                let m2_row = &m2.data[k * row_stride..];
+                for (col, value) in result_row.iter_mut().enumerate() {
+                    *value += factor * m2_row[col * col_stride];
+                }
+            }
+        }
+        Ok(result)
+    }
+
+    /// Returns `self + other`, or `VecxError::DimensionMismatch` if their dimensions differ
+    pub fn try_add(&self, other: &Matrix) -> Result<Matrix, VecxError> {
+        self.zip_map(other, |a, b| a + b)
+    }
+
+    /// Returns `self - other`, or `VecxError::DimensionMismatch` if their dimensions differ
+    pub fn try_sub(&self, other: &Matrix) -> Result<Matrix, VecxError> {
+        self.zip_map(other, |a, b| a - b)
+    }
+
+    /// Element-wise product of two matrices of the same dimensions
+    pub fn hadamard(&self, other: &Matrix) -> Matrix {
+        self.try_hadamard(other).unwrap_or_else(|e| panic!("{}", e))
+    }
+
+    pub fn try_hadamard(&self, other: &Matrix) -> Result<Matrix, VecxError> {
+        self.zip_map(other, |a, b| a * b)
+    }
+
+    /// Multiplies a square matrix by itself `n` times, `pow(0)` is the identity
+    ///
+    /// Uses exponentiation by squaring, so it takes O(log n) multiplications. Panics if the
+    /// matrix isn't square, see `try_pow`.
+    pub fn pow(&self, n: u32) -> Matrix {
+        self.try_pow(n).unwrap_or_else(|e| panic!("{}", e))
+    }
+
+    /// Multiplies a square matrix by itself `n` times, or `VecxError::NotSquare`
+    pub fn try_pow(&self, n: u32) -> Result<Matrix, VecxError> {
+        self.check_square()?;
+
+        let mut result = Matrix::identity(self.rows);
+        let mut base = self.clone();
+        let mut exponent = n;
+        while exponent > 0 {
+            if exponent & 1 == 1 {
+                result = &result * &base;
+            }
+            exponent >>= 1;
+            if exponent > 0 {
+                base = &base * &base;
+            }
+        }
+        Ok(result)
+    }
+
+    fn map(&self, f: impl Fn(f64) -> f64) -> Matrix {
+        Matrix {
+            rows: self.rows,
+            cols: self.cols,
+            layout: self.layout,
+            data: self.data.iter().map(|&value| f(value)).collect(),
+        }
+    }
+
+    fn zip_map(&self, other: &Matrix, f: impl Fn(f64, f64) -> f64) -> Result<Matrix, VecxError> {
+        if self.rows != other.rows || self.cols != other.cols {
+            return Err(VecxError::DimensionMismatch {
+                left: (self.rows, self.cols),
+                right: (other.rows, other.cols),
+            });
+        }
+
+        let other = other.to_layout(self.layout);
+        Ok(Matrix {
+            rows: self.rows,
+            cols: self.cols,
+            layout: self.layout,
+            data: self
+                .data
+                .iter()
+                .zip(&other.data)
+                .map(|(&a, &b)| f(a, b))
+                .collect(),
+        })
+    }
+
+    /// Multiplies a 4x4 matrix by the column vector `v` without allocating
+    ///
+    /// Panics if the matrix isn't 4x4, see `try_multiply_vec4`.
+    pub fn multiply_vec4(&self, v: Vec4) -> Vec4 {
+        self.try_multiply_vec4(v)
+            .unwrap_or_else(|e| panic!("{}", e))
+    }
+
+    /// Multiplies a 4x4 matrix by `v`, or `VecxError::Not4x4`
+    pub fn try_multiply_vec4(&self, v: Vec4) -> Result<Vec4, VecxError> {
+        self.check_4x4()?;
+
+        let row = |r: usize| {
+            self.at(r, 0) * v.0 + self.at(r, 1) * v.1 + self.at(r, 2) * v.2 + self.at(r, 3) * v.3
+        };
+        Ok(Vec4(row(0), row(1), row(2), row(3)))
+    }
+
+    /// Transforms the point `p` by a 4x4 matrix
+    ///
+    /// `p` is treated as (x, y, z, 1), the result is divided by w when w is neither 0 nor 1.
+    /// A w of 0 puts the point at infinity, its x, y and z are then returned undivided, as a
+    /// direction, see `try_transform_point`.
+    pub fn transform_point(&self, p: Vec3) -> Vec3 {
+        let transformed = self.multiply_vec4(p.as_homogenous_point());
+        let w = transformed.w();
+        if w == 1.0 || w == 0.0 {
+            Vec3::from(transformed)
+        } else {
+            transformed.perspective_divide()
+        }
+    }
+
+    /// Transforms the point `p` by a 4x4 matrix, or `VecxError::Not4x4`, or
+    /// `VecxError::PointAtInfinity` when the transformed w is 0
+    pub fn try_transform_point(&self, p: Vec3) -> Result<Vec3, VecxError> {
+        let transformed = self.try_multiply_vec4(p.as_homogenous_point())?;
+        let w = transformed.w();
+        if w == 0.0 {
+            Err(VecxError::PointAtInfinity)
+        } else if w == 1.0 {
+            Ok(Vec3::from(transformed))
+        } else {
+            Ok(transformed.perspective_divide())
+        }
+    }
+
+    /// Transforms the direction `v` by a 4x4 matrix
+    ///
+    /// `v` is treated as (x, y, z, 0) so translation is ignored
+    pub fn transform_vector(&self, v: Vec3) -> Vec3 {
+        Vec3::from(self.multiply_vec4(v.as_homogenous_vector()))
+    }
+
+    /// Returns a new matrix whose rows are the columns of `self`
+    pub fn transpose(&self) -> Matrix {
+        let mut result = Matrix::new(self.cols, self.rows);
+        if self.rows == 0 {
+            return result;
+        }
+        let (row_stride, col_stride) = (self.row_stride(), self.col_stride());
+        for (col, result_row) in result.data.chunks_exact_mut(self.rows).enumerate() {
+            let self_col = &self.data[col * col_stride..];
+            for (row, value) in result_row.iter_mut().enumerate() {
+                *value = self_col[row * row_stride];
             }
         }
         result
     }
 
+    /// Returns the determinant of a square matrix
+    ///
+    /// Computed by Gaussian elimination with partial pivoting. Panics if the matrix isn't
+    /// square, see `try_determinant`.
+    pub fn determinant(&self) -> f64 {
+        self.try_determinant().unwrap_or_else(|e| panic!("{}", e))
+    }
+
+    /// Returns the determinant, or `VecxError::NotSquare`
+    pub fn try_determinant(&self) -> Result<f64, VecxError> {
+        self.check_square()?;
+
+        let n = self.rows;
+        let mut m = self.to_rows();
+        let mut det = 1.0;
+        // relative to the matrix like `Lu`, so uniformly scaling it doesn't change the answer
+        let threshold = SINGULAR_EPSILON * self.norm_1();
+
+        for pivot in 0..n {
+            let max_row = Self::pivot_row(&m, pivot);
+            if m[max_row][pivot].abs() <= threshold {
+                return Ok(0.0);
+            }
+            if max_row != pivot {
+                m.swap(max_row, pivot);
+                det = -det;
+            }
+
+            det *= m[pivot][pivot];
+            let (upper, lower) = m.split_at_mut(pivot + 1);
+            let pivot_values = &upper[pivot];
+            for row_values in lower.iter_mut() {
+                let factor = row_values[pivot] / pivot_values[pivot];
+                for (value, pivot_value) in row_values.iter_mut().zip(pivot_values).skip(pivot) {
+                    *value -= factor * pivot_value;
+                }
+            }
+        }
+        Ok(det)
+    }
+
+    /// Returns the inverse of a square matrix, or `None` if it is singular
+    ///
+    /// Computed by Gauss-Jordan elimination with partial pivoting. Panics if the matrix isn't
+    /// square, see `try_inverse`.
+    pub fn inverse(&self) -> Option<Matrix> {
+        match self.try_inverse() {
+            Ok(inverse) => Some(inverse),
+            Err(VecxError::SingularMatrix) => None,
+            Err(e) => panic!("{}", e),
+        }
+    }
+
+    /// Returns the inverse, or `VecxError::NotSquare` or `VecxError::SingularMatrix`
+    pub fn try_inverse(&self) -> Result<Matrix, VecxError> {
+        self.check_square()?;
+
+        let n = self.rows;
+        let mut m = self.to_rows();
+        let mut inv = Matrix::identity(n).to_rows();
+        let threshold = SINGULAR_EPSILON * self.norm_1();
+
+        for pivot in 0..n {
+            let max_row = Self::pivot_row(&m, pivot);
+            if m[max_row][pivot].abs() <= threshold {
+                return Err(VecxError::SingularMatrix);
+            }
+            m.swap(max_row, pivot);
+            inv.swap(max_row, pivot);
+
+            let pivot_value = m[pivot][pivot];
+            for col in 0..n {
+                m[pivot][col] /= pivot_value;
+                inv[pivot][col] /= pivot_value;
+            }
+
+            for row in 0..n {
+                if row == pivot {
+                    continue;
+                }
+                let factor = m[row][pivot];
+                if factor == 0.0 {
+                    continue;
+                }
+                for col in 0..n {
+                    m[row][col] -= factor * m[pivot][col];
+                    inv[row][col] -= factor * inv[pivot][col];
+                }
+            }
+        }
+
+        Ok(Matrix::from(inv))
+    }
+
+    /// Returns the LU decomposition with partial pivoting of a square matrix
+    pub fn lu(&self) -> Lu {
+        Lu::new(self)
+    }
+
+    pub fn solve<B: RightHandSide>(&self, b: &B) -> B {
+        self.try_solve(b).unwrap_or_else(|e| panic!("{}", e))
+    }
+
+    /// Solves `self * x = b` for a vector or for every column of a matrix using `lu`
+    ///
+    /// Fails if `self` isn't square, `b` doesn't have as many rows as `self`, or the system
+    /// is singular or ill-conditioned. Factor once with `lu` to solve several systems.
+    pub fn try_solve<B: RightHandSide>(&self, b: &B) -> Result<B, VecxError> {
+        Lu::try_new(self)?.try_solve(b)
+    }
+
+    /// Returns the QR decomposition by Householder reflections
+    pub fn qr(&self) -> Qr {
+        Qr::new(self)
+    }
+
+    pub fn least_squares<B: RightHandSide>(&self, b: &B) -> B {
+        self.try_least_squares(b)
+            .unwrap_or_else(|e| panic!("{}", e))
+    }
+
+    /// Returns the `x` minimizing `|self * x - b|` using `qr`, see `Qr::try_least_squares`
+    pub fn try_least_squares<B: RightHandSide>(&self, b: &B) -> Result<B, VecxError> {
+        self.qr().try_least_squares(b)
+    }
+
+    pub fn symmetric_eigen(&self) -> (Vec<f64>, Matrix) {
+        self.try_symmetric_eigen()
+            .unwrap_or_else(|e| panic!("{}", e))
+    }
+
+    /// Returns the eigenvalues of a symmetric matrix in decreasing order, and a matrix whose
+    /// columns are the matching unit eigenvectors
+    ///
+    /// Computed with the cyclic Jacobi method. Fails if `self` isn't square or symmetric, or if the
+    /// iterations don't converge, which non-finite values cause.
+    pub fn try_symmetric_eigen(&self) -> Result<(Vec<f64>, Matrix), VecxError> {
+        eigen::symmetric_eigen(self)
+    }
+
+    /// Returns the thin singular value decomposition `(U, Σ, VT)` with `self = U * Σ * VT`
+    ///
+    /// For an `m`x`n` matrix and `k = min(m, n)`, `U` is `m`x`k` with orthonormal columns,
+    /// `Σ` is `k`x`k` diagonal with singular values in decreasing order and `VT` is `k`x`n`
+    /// with orthonormal rows. Computed with one-sided Jacobi rotations.
+    pub fn svd(&self) -> (Matrix, Matrix, Matrix) {
+        svd::svd(self)
+    }
+
+    /// Singular values in decreasing order
+    pub fn singular_values(&self) -> Vec<f64> {
+        let (_, sigma, _) = self.svd();
+        (1..=sigma.rows()).map(|i| sigma.get((i, i))).collect()
+    }
+
+    /// Number of singular values above `max(m, n) * f64::EPSILON * largest singular value`
+    pub fn rank(&self) -> usize {
+        let values = self.singular_values();
+        let tolerance = self.rank_tolerance(&values);
+        values.iter().filter(|&&value| value > tolerance).count()
+    }
+
+    /// Ratio of the largest to the smallest singular value, infinite for rank-deficient matrices
+    pub fn condition_number(&self) -> f64 {
+        let values = self.singular_values();
+        let smallest = values.last().copied().unwrap_or(0.0);
+        if smallest <= self.rank_tolerance(&values) {
+            return f64::INFINITY;
+        }
+        values[0] / smallest
+    }
+
+    /// Moore-Penrose pseudo-inverse `V * Σ+ * UT`, `n`x`m` for an `m`x`n` matrix
+    ///
+    /// Singular values below the `rank` tolerance are treated as 0, so it exists for any matrix.
+    /// Equals `inverse` for invertible matrices and gives least-squares solutions otherwise.
+    pub fn pseudo_inverse(&self) -> Matrix {
+        let (u, mut sigma, vt) = self.svd();
+        let values: Vec<f64> = (1..=sigma.rows()).map(|i| sigma.get((i, i))).collect();
+        let tolerance = self.rank_tolerance(&values);
+        for (i, value) in values.iter().enumerate() {
+            let inverse = if *value > tolerance { 1.0 / value } else { 0.0 };
+            sigma.set((i + 1, i + 1), inverse);
+        }
+        vt.transpose() * sigma * u.transpose()
+    }
+
+    fn rank_tolerance(&self, singular_values: &[f64]) -> f64 {
+        let largest = singular_values.first().copied().unwrap_or(0.0);
+        self.rows.max(self.cols) as f64 * f64::EPSILON * largest
+    }
+
+    /// 1-norm, the largest absolute column sum, which pivots are compared to when eliminating
+    pub(crate) fn norm_1(&self) -> f64 {
+        (0..self.cols)
+            .map(|col| {
+                (0..self.rows)
+                    .map(|row| self.at(row, col).abs())
+                    .sum::<f64>()
+            })
+            .fold(0.0, f64::max)
+    }
+
+    /// Index of the row at or below `pivot` with the largest absolute value in column `pivot`
+    pub(crate) fn pivot_row(m: &FloatMat, pivot: usize) -> usize {
+        let mut max_row = pivot;
+        for row in (pivot + 1)..m.len() {
+            if m[row][pivot].abs() > m[max_row][pivot].abs() {
+                max_row = row;
+            }
+        }
+        max_row
+    }
+
+    pub fn is_square(&self) -> bool {
+        self.rows == self.cols
+    }
+
+    /// Identity matrix of size `n`x`n`
+    pub fn identity(n: usize) -> Self {
+        let mut id_mat = Matrix::new(n, n);
+        for i in 1..=n {
+            id_mat.set((i, i), 1.0);
+        }
+        id_mat
+    }
+
     pub fn sqr4() -> Self {
         Matrix::new(4, 4)
     }
@@ -203,6 +844,268 @@ impl Matrix {
         rotate_y
     }
 
+    /// Rotation matrix 4x4 of `angle` radians around `axis`, using Rodrigues' formula
+    ///
+    /// Same convention as `m4_rotate_x` and `m4_rotate_y`, `m4_rotate_axis(x, a)` is
+    /// `m4_rotate_x(a)` while `m4_rotate_axis(z, a)` is `m4_rotate_z(-a)`. Panics if `axis` is zero.
+    ///
+    /// \[c + x²(1-c), xy(1-c) - zs, xz(1-c) + ys, 0]
+    ///
+    /// \[xy(1-c) + zs, c + y²(1-c), yz(1-c) - xs, 0]
+    ///
+    /// \[xz(1-c) - ys, yz(1-c) + xs, c + z²(1-c), 0]
+    ///
+    /// \[0, 0, 0, 1]
+    pub fn m4_rotate_axis(axis: Vec3, angle: f64) -> Matrix {
+        let k = axis.normalized();
+        let cos = f64::cos(angle);
+        let sin = f64::sin(angle);
+        let t = 1.0 - cos;
+        let (x, y, z) = (k.x(), k.y(), k.z());
+
+        let mut rotate = Matrix::id4();
+        rotate.set((1, 1), cos + x * x * t);
+        rotate.set((1, 2), x * y * t - z * sin);
+        rotate.set((1, 3), x * z * t + y * sin);
+        rotate.set((2, 1), x * y * t + z * sin);
+        rotate.set((2, 2), cos + y * y * t);
+        rotate.set((2, 3), y * z * t - x * sin);
+        rotate.set((3, 1), x * z * t - y * sin);
+        rotate.set((3, 2), y * z * t + x * sin);
+        rotate.set((3, 3), cos + z * z * t);
+
+        rotate
+    }
+
+    /// Left-handed view 4x4 matrix
+    ///
+    /// Alias for `m4_look_at_lh(eye, target, up)`
+    pub fn m4_look_at(eye: Vec3, target: Vec3, up: Vec3) -> Matrix {
+        Matrix::m4_look_at_lh(eye, target, up)
+    }
+
+    /// Left-handed view 4x4 matrix looking from `eye` towards `target`
+    /// (f, r, u) = (target - eye).look_basis_lh(up)
+    ///
+    /// \[rx, ry, rz, -r.eye]
+    ///
+    /// \[ux, uy, uz, -u.eye]
+    ///
+    /// \[fx, fy, fz, -f.eye]
+    ///
+    /// \[0, 0, 0, 1]
+    pub fn m4_look_at_lh(eye: Vec3, target: Vec3, up: Vec3) -> Matrix {
+        let (forward, right, up) = (target - eye).look_basis_lh(&up);
+        Matrix::view(eye, right, up, forward)
+    }
+
+    /// Right-handed view 4x4 matrix looking from `eye` towards `target`
+    /// (f, r, u) = (target - eye).look_basis_rh(up)
+    ///
+    /// \[rx, ry, rz, -r.eye]
+    ///
+    /// \[ux, uy, uz, -u.eye]
+    ///
+    /// \[-fx, -fy, -fz, f.eye]
+    ///
+    /// \[0, 0, 0, 1]
+    pub fn m4_look_at_rh(eye: Vec3, target: Vec3, up: Vec3) -> Matrix {
+        let (forward, right, up) = (target - eye).look_basis_rh(&up);
+        Matrix::view(eye, right, up, -forward)
+    }
+
+    fn view(eye: Vec3, x_axis: Vec3, y_axis: Vec3, z_axis: Vec3) -> Matrix {
+        Matrix::from(vec![
+            vec![
+                x_axis.x(),
+                x_axis.y(),
+                x_axis.z(),
+                -x_axis.dot_product(&eye),
+            ],
+            vec![
+                y_axis.x(),
+                y_axis.y(),
+                y_axis.z(),
+                -y_axis.dot_product(&eye),
+            ],
+            vec![
+                z_axis.x(),
+                z_axis.y(),
+                z_axis.z(),
+                -z_axis.dot_product(&eye),
+            ],
+            vec![0.0, 0.0, 0.0, 1.0],
+        ])
+    }
+
+    /// Left-handed perspective projection 4x4 matrix, depth mapped to \[0, 1]
+    ///
+    /// Alias for `m4_perspective_lh(fov, aspect, near, far, DepthRange::ZeroToOne)`
+    pub fn m4_perspective(fov: f64, aspect: f64, near: f64, far: f64) -> Matrix {
+        Matrix::m4_perspective_lh(fov, aspect, near, far, DepthRange::ZeroToOne)
+    }
+
+    /// Left-handed perspective projection 4x4 matrix
+    /// vertical field of view fov, f = 1 / tan(fov / 2)
+    /// depth \[0, 1]: zs = far / (far - near), zt = -near * far / (far - near)
+    /// depth \[-1, 1]: zs = (far + near) / (far - near), zt = -2 * near * far / (far - near)
+    ///
+    /// \[f / aspect, 0, 0, 0]
+    ///
+    /// \[0, f, 0, 0]
+    ///
+    /// \[0, 0, zs, zt]
+    ///
+    /// \[0, 0, 1, 0]
+    pub fn m4_perspective_lh(
+        fov: f64,
+        aspect: f64,
+        near: f64,
+        far: f64,
+        depth: DepthRange,
+    ) -> Matrix {
+        let (z_scale, z_translate) = match depth {
+            DepthRange::ZeroToOne => (far / (far - near), -near * far / (far - near)),
+            DepthRange::NegOneToOne => (
+                (far + near) / (far - near),
+                -2.0 * near * far / (far - near),
+            ),
+        };
+        Matrix::perspective(fov, aspect, z_scale, z_translate, 1.0)
+    }
+
+    /// Right-handed perspective projection 4x4 matrix
+    /// vertical field of view fov, f = 1 / tan(fov / 2)
+    /// depth \[0, 1]: zs = far / (near - far), zt = near * far / (near - far)
+    /// depth \[-1, 1]: zs = (far + near) / (near - far), zt = 2 * near * far / (near - far)
+    ///
+    /// \[f / aspect, 0, 0, 0]
+    ///
+    /// \[0, f, 0, 0]
+    ///
+    /// \[0, 0, zs, zt]
+    ///
+    /// \[0, 0, -1, 0]
+    pub fn m4_perspective_rh(
+        fov: f64,
+        aspect: f64,
+        near: f64,
+        far: f64,
+        depth: DepthRange,
+    ) -> Matrix {
+        let (z_scale, z_translate) = match depth {
+            DepthRange::ZeroToOne => (far / (near - far), near * far / (near - far)),
+            DepthRange::NegOneToOne => {
+                ((far + near) / (near - far), 2.0 * near * far / (near - far))
+            }
+        };
+        Matrix::perspective(fov, aspect, z_scale, z_translate, -1.0)
+    }
+
+    fn perspective(fov: f64, aspect: f64, z_scale: f64, z_translate: f64, w_sign: f64) -> Matrix {
+        let f = 1.0 / f64::tan(fov / 2.0);
+
+        let mut perspective = Matrix::sqr4();
+        perspective.set((1, 1), f / aspect);
+        perspective.set((2, 2), f);
+        perspective.set((3, 3), z_scale);
+        perspective.set((3, 4), z_translate);
+        perspective.set((4, 3), w_sign);
+
+        perspective
+    }
+
+    /// Left-handed orthographic projection 4x4 matrix, depth mapped to \[0, 1]
+    ///
+    /// Alias for `m4_orthographic_lh(left, right, bottom, top, near, far, DepthRange::ZeroToOne)`
+    pub fn m4_orthographic(
+        left: f64,
+        right: f64,
+        bottom: f64,
+        top: f64,
+        near: f64,
+        far: f64,
+    ) -> Matrix {
+        Matrix::m4_orthographic_lh(left, right, bottom, top, near, far, DepthRange::ZeroToOne)
+    }
+
+    /// Left-handed orthographic projection 4x4 matrix
+    /// w = right - left, h = top - bottom, d = far - near
+    /// depth \[0, 1]: zs = 1 / d, zt = -near / d
+    /// depth \[-1, 1]: zs = 2 / d, zt = -(far + near) / d
+    ///
+    /// \[2 / w, 0, 0, -(right + left) / w]
+    ///
+    /// \[0, 2 / h, 0, -(top + bottom) / h]
+    ///
+    /// \[0, 0, zs, zt]
+    ///
+    /// \[0, 0, 0, 1]
+    pub fn m4_orthographic_lh(
+        left: f64,
+        right: f64,
+        bottom: f64,
+        top: f64,
+        near: f64,
+        far: f64,
+        depth: DepthRange,
+    ) -> Matrix {
+        let d = far - near;
+        let (z_scale, z_translate) = match depth {
+            DepthRange::ZeroToOne => (1.0 / d, -near / d),
+            DepthRange::NegOneToOne => (2.0 / d, -(far + near) / d),
+        };
+        Matrix::orthographic(left, right, bottom, top, z_scale, z_translate)
+    }
+
+    /// Right-handed orthographic projection 4x4 matrix
+    /// w = right - left, h = top - bottom, d = far - near
+    /// depth \[0, 1]: zs = -1 / d, zt = -near / d
+    /// depth \[-1, 1]: zs = -2 / d, zt = -(far + near) / d
+    ///
+    /// \[2 / w, 0, 0, -(right + left) / w]
+    ///
+    /// \[0, 2 / h, 0, -(top + bottom) / h]
+    ///
+    /// \[0, 0, zs, zt]
+    ///
+    /// \[0, 0, 0, 1]
+    pub fn m4_orthographic_rh(
+        left: f64,
+        right: f64,
+        bottom: f64,
+        top: f64,
+        near: f64,
+        far: f64,
+        depth: DepthRange,
+    ) -> Matrix {
+        let d = far - near;
+        let (z_scale, z_translate) = match depth {
+            DepthRange::ZeroToOne => (-1.0 / d, -near / d),
+            DepthRange::NegOneToOne => (-2.0 / d, -(far + near) / d),
+        };
+        Matrix::orthographic(left, right, bottom, top, z_scale, z_translate)
+    }
+
+    fn orthographic(
+        left: f64,
+        right: f64,
+        bottom: f64,
+        top: f64,
+        z_scale: f64,
+        z_translate: f64,
+    ) -> Matrix {
+        let mut orthographic = Matrix::id4();
+        orthographic.set((1, 1), 2.0 / (right - left));
+        orthographic.set((1, 4), -(right + left) / (right - left));
+        orthographic.set((2, 2), 2.0 / (top - bottom));
+        orthographic.set((2, 4), -(top + bottom) / (top - bottom));
+        orthographic.set((3, 3), z_scale);
+        orthographic.set((3, 4), z_translate);
+
+        orthographic
+    }
+
     pub fn rows(&self) -> usize {
         self.rows
     }
@@ -212,79 +1115,181 @@ impl Matrix {
     }
 
     pub fn set(&mut self, rc: MatIndex, value: f64) {
-        self.verify_mat_index(rc);
+        self.try_set(rc, value).unwrap_or_else(|e| panic!("{}", e))
+    }
 
-        let vec_index = Self::mat_index_to_vec_index(rc);
-        self.content[vec_index.0][vec_index.1] = value;
+    pub fn try_set(&mut self, rc: MatIndex, value: f64) -> Result<(), VecxError> {
+        self.check_mat_index(rc)?;
+        let offset = self.offset(rc.0 - 1, rc.1 - 1);
+        self.data[offset] = value;
+        Ok(())
     }
 
     pub fn get(&self, rc: MatIndex) -> f64 {
-        self.verify_mat_index(rc);
-        let vec_index = Self::mat_index_to_vec_index(rc);
-        self.content[vec_index.0][vec_index.1]
+        self.try_get(rc).unwrap_or_else(|e| panic!("{}", e))
+    }
+
+    pub fn try_get(&self, rc: MatIndex) -> Result<f64, VecxError> {
+        self.check_mat_index(rc)?;
+        Ok(self.at(rc.0 - 1, rc.1 - 1))
     }
 
     pub fn get_row(&self, index: usize) -> Vec<f64> {
-        self.verify_row_index(index);
-        self.content[index - 1].clone()
+        self.try_get_row(index).unwrap_or_else(|e| panic!("{}", e))
+    }
+
+    pub fn try_get_row(&self, index: usize) -> Result<Vec<f64>, VecxError> {
+        Ok(self.try_row_iter(index)?.collect())
     }
 
     pub fn get_col(&self, col: usize) -> Vec<f64> {
-        self.verify_col_index(col);
-        let col_index = col - 1;
-        self.content.iter().map(|row| row[col_index]).collect()
+        self.try_get_col(col).unwrap_or_else(|e| panic!("{}", e))
+    }
+
+    pub fn try_get_col(&self, col: usize) -> Result<Vec<f64>, VecxError> {
+        Ok(self.try_col_iter(col)?.collect())
+    }
+
+    /// Values of the 1-based row `index`, without allocating
+    pub fn row_iter(&self, index: usize) -> impl Iterator<Item = f64> + '_ {
+        self.try_row_iter(index).unwrap_or_else(|e| panic!("{}", e))
+    }
+
+    pub fn try_row_iter(&self, index: usize) -> Result<impl Iterator<Item = f64> + '_, VecxError> {
+        self.check_row_index(index)?;
+        Ok((0..self.cols).map(move |col| self.at(index - 1, col)))
+    }
+
+    /// Values of the 1-based column `col`, without allocating
+    pub fn col_iter(&self, col: usize) -> impl Iterator<Item = f64> + '_ {
+        self.try_col_iter(col).unwrap_or_else(|e| panic!("{}", e))
+    }
+
+    pub fn try_col_iter(&self, col: usize) -> Result<impl Iterator<Item = f64> + '_, VecxError> {
+        self.check_col_index(col)?;
+        Ok((0..self.rows).map(move |row| self.at(row, col - 1)))
+    }
+
+    /// Copies the values into one `Vec` per row
+    fn to_rows(&self) -> FloatMat {
+        (0..self.rows)
+            .map(|row| (0..self.cols).map(|col| self.at(row, col)).collect())
+            .collect()
     }
 
     pub fn set_content(&mut self, mat: FloatMat) {
-        self.verify_content(&mat);
-        self.content = mat;
+        self.try_set_content(mat)
+            .unwrap_or_else(|e| panic!("{}", e))
     }
 
-    fn mat_index_to_vec_index(rc: MatIndex) -> (usize, usize) {
-        (rc.0 - 1, rc.1 - 1)
+    pub fn try_set_content(&mut self, mat: FloatMat) -> Result<(), VecxError> {
+        self.check_content(&mat)?;
+        for (row, values) in mat.iter().enumerate() {
+            for (col, &value) in values.iter().enumerate() {
+                let offset = self.offset(row, col);
+                self.data[offset] = value;
+            }
+        }
+        Ok(())
     }
 
     pub fn verify_row_index(&self, row: usize) {
+        self.check_row_index(row)
+            .unwrap_or_else(|e| panic!("{}", e))
+    }
+
+    pub fn check_row_index(&self, row: usize) -> Result<(), VecxError> {
         if row < 1 || row > self.rows {
-            panic!(
-                "Invalid row index {} for {}x{} matrix",
-                row, self.rows, self.cols
-            );
+            return Err(VecxError::InvalidRowIndex {
+                row,
+                rows: self.rows,
+                cols: self.cols,
+            });
         }
+        Ok(())
     }
 
     pub fn verify_col_index(&self, col: usize) {
+        self.check_col_index(col)
+            .unwrap_or_else(|e| panic!("{}", e))
+    }
+
+    pub fn check_col_index(&self, col: usize) -> Result<(), VecxError> {
         if col < 1 || col > self.cols {
-            panic!(
-                "Invalid column index {} for {}x{} matrix",
-                col, self.rows, self.cols
-            );
+            return Err(VecxError::InvalidColIndex {
+                col,
+                rows: self.rows,
+                cols: self.cols,
+            });
         }
+        Ok(())
     }
 
     pub fn verify_mat_index(&self, rc: MatIndex) {
-        let row = rc.0;
-        let col = rc.1;
+        self.check_mat_index(rc).unwrap_or_else(|e| panic!("{}", e))
+    }
+
+    pub fn check_mat_index(&self, rc: MatIndex) -> Result<(), VecxError> {
+        self.check_row_index(rc.0)?;
+        self.check_col_index(rc.1)
+    }
 
-        self.verify_row_index(row);
-        self.verify_col_index(col);
+    pub fn verify_4x4(&self, operation: &str) {
+        self.check_4x4()
+            .unwrap_or_else(|e| panic!("Invalid Matrix {}: {}", operation, e))
+    }
+
+    pub fn check_4x4(&self) -> Result<(), VecxError> {
+        if self.rows != 4 || self.cols != 4 {
+            return Err(VecxError::Not4x4 {
+                rows: self.rows,
+                cols: self.cols,
+            });
+        }
+        Ok(())
+    }
+
+    pub fn verify_square(&self, operation: &str) {
+        self.check_square()
+            .unwrap_or_else(|e| panic!("Invalid Matrix {}: {}", operation, e))
+    }
+
+    pub fn check_square(&self) -> Result<(), VecxError> {
+        if !self.is_square() {
+            return Err(VecxError::NotSquare {
+                rows: self.rows,
+                cols: self.cols,
+            });
+        }
+        Ok(())
     }
 
     pub fn verify_content(&self, content: &FloatMat) {
+        self.check_content(content)
+            .unwrap_or_else(|e| panic!("{}", e))
+    }
+
+    /// Checks that `content` is a `rows`x`cols` grid with the same number of values in every row
+    pub fn check_content(&self, content: &FloatMat) -> Result<(), VecxError> {
         let content_rows = content.len();
-        let content_cols = content[0].len();
-        if content_rows != self.rows || content_cols != self.cols {
-            panic!(
-                "Attempt to set {}x{} content into a {}x{} matrix",
-                content_rows, content_cols, self.rows, self.cols
-            );
+        let content_cols = content.first().map_or(0, |row| row.len());
+        let ragged_row = content.iter().find(|row| row.len() != content_cols);
+        if content_rows != self.rows || content_cols != self.cols || ragged_row.is_some() {
+            return Err(VecxError::InvalidContent {
+                content: (
+                    content_rows,
+                    ragged_row.map_or(content_cols, |row| row.len()),
+                ),
+                matrix: (self.rows, self.cols),
+            });
         }
+        Ok(())
     }
 }
 
 impl From<FloatMat> for Matrix {
     fn from(mat: FloatMat) -> Self {
-        let mut matrix = Matrix::new(mat.len(), mat[0].len());
+        let mut matrix = Matrix::new(mat.len(), mat.first().map_or(0, |row| row.len()));
         matrix.set_content(mat);
         matrix
     }
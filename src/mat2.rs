@@ -0,0 +1,229 @@
+use crate::mat::is_singular_determinant;
+use crate::{Matrix, Vec2, VecX};
+use std::fmt;
+use std::ops::{self, Index};
+
+/*
+    Definition
+*/
+/// Stack allocated 2x2 matrix stored as 2 row vectors
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Mat2(pub Vec2, pub Vec2);
+
+impl Default for Mat2 {
+    fn default() -> Self {
+        Mat2::IDENTITY
+    }
+}
+
+impl Mat2 {
+    pub const ZERO: Mat2 = Mat2(Vec2(0.0, 0.0), Vec2(0.0, 0.0));
+
+    pub const IDENTITY: Mat2 = Mat2(Vec2(1.0, 0.0), Vec2(0.0, 1.0));
+
+    pub fn zero() -> Self {
+        Mat2::ZERO
+    }
+
+    pub fn identity() -> Self {
+        Mat2::IDENTITY
+    }
+
+    pub fn from_rows(r0: Vec2, r1: Vec2) -> Self {
+        Mat2(r0, r1)
+    }
+
+    pub fn from_cols(c0: Vec2, c1: Vec2) -> Self {
+        Mat2(c0, c1).transpose()
+    }
+
+    /// Scale 2x2 matrix
+    ///
+    /// \[sx, 0]
+    ///
+    /// \[0, sy]
+    pub fn scale(scale: Vec2) -> Self {
+        Mat2(Vec2(scale.x(), 0.0), Vec2(0.0, scale.y()))
+    }
+
+    /// Rotation 2x2 matrix, upper left of `Mat4::rotate_z`
+    ///
+    /// \[c, s]
+    ///
+    /// \[-s, c]
+    pub fn rotate(angle: f64) -> Self {
+        let cos = f64::cos(angle);
+        let sin = f64::sin(angle);
+        Mat2(Vec2(cos, sin), Vec2(-sin, cos))
+    }
+
+    pub fn row(&self, idx: usize) -> Vec2 {
+        self[idx]
+    }
+
+    pub fn col(&self, idx: usize) -> Vec2 {
+        Vec2(self.0[idx], self.1[idx])
+    }
+
+    pub fn transpose(&self) -> Self {
+        Mat2(self.col(0), self.col(1))
+    }
+
+    pub fn determinant(&self) -> f64 {
+        self.0 .0 * self.1 .1 - self.0 .1 * self.1 .0
+    }
+
+    /// Returns the inverse matrix, or `None` if it is singular
+    pub fn inverse(&self) -> Option<Self> {
+        let det = self.determinant();
+        if is_singular_determinant(det, &[self.0.magnitude(), self.1.magnitude()]) {
+            return None;
+        }
+
+        let adjugate = Mat2(Vec2(self.1 .1, -self.0 .1), Vec2(-self.1 .0, self.0 .0));
+        Some(adjugate * (1.0 / det))
+    }
+}
+
+/*
+    Ops
+*/
+/*
+    Neg
+*/
+impl ops::Neg for Mat2 {
+    type Output = Self;
+
+    fn neg(self) -> Self::Output {
+        Mat2(-self.0, -self.1)
+    }
+}
+
+/*
+    Add
+*/
+impl ops::Add<Mat2> for Mat2 {
+    type Output = Mat2;
+
+    fn add(self, rhs: Mat2) -> Self::Output {
+        Mat2(self.0 + rhs.0, self.1 + rhs.1)
+    }
+}
+
+impl ops::AddAssign<Mat2> for Mat2 {
+    fn add_assign(&mut self, rhs: Mat2) {
+        *self = *self + rhs;
+    }
+}
+
+/*
+    Sub
+*/
+impl ops::Sub<Mat2> for Mat2 {
+    type Output = Mat2;
+
+    fn sub(self, rhs: Mat2) -> Self::Output {
+        Mat2(self.0 - rhs.0, self.1 - rhs.1)
+    }
+}
+
+impl ops::SubAssign<Mat2> for Mat2 {
+    fn sub_assign(&mut self, rhs: Mat2) {
+        *self = *self - rhs;
+    }
+}
+
+/*
+    Mul
+*/
+impl ops::Mul<f64> for Mat2 {
+    type Output = Mat2;
+
+    fn mul(self, rhs: f64) -> Self::Output {
+        Mat2(self.0 * rhs, self.1 * rhs)
+    }
+}
+
+impl ops::Mul<Mat2> for f64 {
+    type Output = Mat2;
+
+    fn mul(self, rhs: Mat2) -> Self::Output {
+        rhs * self
+    }
+}
+
+impl ops::Mul<Vec2> for Mat2 {
+    type Output = Vec2;
+
+    fn mul(self, rhs: Vec2) -> Self::Output {
+        Vec2(self.0.dot_product(&rhs), self.1.dot_product(&rhs))
+    }
+}
+
+impl ops::Mul<Mat2> for Mat2 {
+    type Output = Mat2;
+
+    fn mul(self, rhs: Mat2) -> Self::Output {
+        let cols = rhs.transpose();
+        Mat2(cols * self.0, cols * self.1)
+    }
+}
+
+impl ops::MulAssign<f64> for Mat2 {
+    fn mul_assign(&mut self, rhs: f64) {
+        *self = *self * rhs;
+    }
+}
+
+impl ops::MulAssign<Mat2> for Mat2 {
+    fn mul_assign(&mut self, rhs: Mat2) {
+        *self = *self * rhs;
+    }
+}
+
+/*
+    Display
+*/
+impl fmt::Display for Mat2 {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "[{}, {}]", self.0, self.1)
+    }
+}
+
+/*
+    From
+*/
+impl From<Mat2> for Matrix {
+    fn from(m: Mat2) -> Self {
+        Matrix::from(vec![vec![m.0 .0, m.0 .1], vec![m.1 .0, m.1 .1]])
+    }
+}
+
+impl From<Matrix> for Mat2 {
+    fn from(mat: Matrix) -> Self {
+        if mat.rows() != 2 || mat.cols() != 2 {
+            panic!(
+                "Invalid matrix ({},{}) supplied to Mat2::from, provide a (2,2) matrix",
+                mat.rows(),
+                mat.cols()
+            );
+        }
+
+        Mat2(Vec2::from(mat.get_row(1)), Vec2::from(mat.get_row(2)))
+    }
+}
+
+/*
+    Index
+*/
+impl Index<usize> for Mat2 {
+    type Output = Vec2;
+
+    fn index(&self, idx: usize) -> &Self::Output {
+        match idx {
+            0 => &self.0,
+            1 => &self.1,
+            _ => panic!("Warning: accessing matrix {} by invalid row {}", self, idx),
+        }
+    }
+}
@@ -0,0 +1,279 @@
+use crate::mat::is_singular_determinant;
+use crate::{Mat4, Matrix, Vec3, Vec4, VecX};
+use std::fmt;
+use std::ops::{self, Index};
+
+/*
+    Definition
+*/
+/// Stack allocated 3x3 matrix stored as 3 row vectors
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Mat3(pub Vec3, pub Vec3, pub Vec3);
+
+impl Default for Mat3 {
+    fn default() -> Self {
+        Mat3::IDENTITY
+    }
+}
+
+impl Mat3 {
+    pub const ZERO: Mat3 = Mat3(
+        Vec3(0.0, 0.0, 0.0),
+        Vec3(0.0, 0.0, 0.0),
+        Vec3(0.0, 0.0, 0.0),
+    );
+
+    pub const IDENTITY: Mat3 = Mat3(
+        Vec3(1.0, 0.0, 0.0),
+        Vec3(0.0, 1.0, 0.0),
+        Vec3(0.0, 0.0, 1.0),
+    );
+
+    pub fn zero() -> Self {
+        Mat3::ZERO
+    }
+
+    pub fn identity() -> Self {
+        Mat3::IDENTITY
+    }
+
+    pub fn from_rows(r0: Vec3, r1: Vec3, r2: Vec3) -> Self {
+        Mat3(r0, r1, r2)
+    }
+
+    pub fn from_cols(c0: Vec3, c1: Vec3, c2: Vec3) -> Self {
+        Mat3(c0, c1, c2).transpose()
+    }
+
+    /// Scale 3x3 matrix, upper left of `Mat4::scale`
+    pub fn scale(scale: Vec3) -> Self {
+        Mat3(
+            Vec3(scale.x(), 0.0, 0.0),
+            Vec3(0.0, scale.y(), 0.0),
+            Vec3(0.0, 0.0, scale.z()),
+        )
+    }
+
+    /// X rotation 3x3 matrix, upper left of `Mat4::rotate_x`
+    pub fn rotate_x(angle: f64) -> Self {
+        Mat3::from(Mat4::rotate_x(angle))
+    }
+
+    /// Y rotation 3x3 matrix, upper left of `Mat4::rotate_y`
+    pub fn rotate_y(angle: f64) -> Self {
+        Mat3::from(Mat4::rotate_y(angle))
+    }
+
+    /// Z rotation 3x3 matrix, upper left of `Mat4::rotate_z`
+    pub fn rotate_z(angle: f64) -> Self {
+        Mat3::from(Mat4::rotate_z(angle))
+    }
+
+    pub fn row(&self, idx: usize) -> Vec3 {
+        self[idx]
+    }
+
+    pub fn col(&self, idx: usize) -> Vec3 {
+        Vec3(self.0[idx], self.1[idx], self.2[idx])
+    }
+
+    pub fn transpose(&self) -> Self {
+        Mat3(self.col(0), self.col(1), self.col(2))
+    }
+
+    pub fn determinant(&self) -> f64 {
+        self.0.dot_product(&self.1.cross(&self.2))
+    }
+
+    /// Returns the inverse matrix, or `None` if it is singular
+    pub fn inverse(&self) -> Option<Self> {
+        let det = self.determinant();
+        if is_singular_determinant(
+            det,
+            &[self.0.magnitude(), self.1.magnitude(), self.2.magnitude()],
+        ) {
+            return None;
+        }
+
+        let adjugate = Mat3(
+            self.1.cross(&self.2),
+            self.2.cross(&self.0),
+            self.0.cross(&self.1),
+        )
+        .transpose();
+        Some(adjugate * (1.0 / det))
+    }
+}
+
+/*
+    Ops
+*/
+/*
+    Neg
+*/
+impl ops::Neg for Mat3 {
+    type Output = Self;
+
+    fn neg(self) -> Self::Output {
+        Mat3(-self.0, -self.1, -self.2)
+    }
+}
+
+/*
+    Add
+*/
+impl ops::Add<Mat3> for Mat3 {
+    type Output = Mat3;
+
+    fn add(self, rhs: Mat3) -> Self::Output {
+        Mat3(self.0 + rhs.0, self.1 + rhs.1, self.2 + rhs.2)
+    }
+}
+
+impl ops::AddAssign<Mat3> for Mat3 {
+    fn add_assign(&mut self, rhs: Mat3) {
+        *self = *self + rhs;
+    }
+}
+
+/*
+    Sub
+*/
+impl ops::Sub<Mat3> for Mat3 {
+    type Output = Mat3;
+
+    fn sub(self, rhs: Mat3) -> Self::Output {
+        Mat3(self.0 - rhs.0, self.1 - rhs.1, self.2 - rhs.2)
+    }
+}
+
+impl ops::SubAssign<Mat3> for Mat3 {
+    fn sub_assign(&mut self, rhs: Mat3) {
+        *self = *self - rhs;
+    }
+}
+
+/*
+    Mul
+*/
+impl ops::Mul<f64> for Mat3 {
+    type Output = Mat3;
+
+    fn mul(self, rhs: f64) -> Self::Output {
+        Mat3(self.0 * rhs, self.1 * rhs, self.2 * rhs)
+    }
+}
+
+impl ops::Mul<Mat3> for f64 {
+    type Output = Mat3;
+
+    fn mul(self, rhs: Mat3) -> Self::Output {
+        rhs * self
+    }
+}
+
+impl ops::Mul<Vec3> for Mat3 {
+    type Output = Vec3;
+
+    fn mul(self, rhs: Vec3) -> Self::Output {
+        Vec3(
+            self.0.dot_product(&rhs),
+            self.1.dot_product(&rhs),
+            self.2.dot_product(&rhs),
+        )
+    }
+}
+
+impl ops::Mul<Mat3> for Mat3 {
+    type Output = Mat3;
+
+    fn mul(self, rhs: Mat3) -> Self::Output {
+        let cols = rhs.transpose();
+        Mat3(cols * self.0, cols * self.1, cols * self.2)
+    }
+}
+
+impl ops::MulAssign<f64> for Mat3 {
+    fn mul_assign(&mut self, rhs: f64) {
+        *self = *self * rhs;
+    }
+}
+
+impl ops::MulAssign<Mat3> for Mat3 {
+    fn mul_assign(&mut self, rhs: Mat3) {
+        *self = *self * rhs;
+    }
+}
+
+/*
+    Display
+*/
+impl fmt::Display for Mat3 {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "[{}, {}, {}]", self.0, self.1, self.2)
+    }
+}
+
+/*
+    From
+*/
+impl From<Mat4> for Mat3 {
+    fn from(m: Mat4) -> Self {
+        Mat3(Vec3::from(m.0), Vec3::from(m.1), Vec3::from(m.2))
+    }
+}
+
+impl From<Mat3> for Mat4 {
+    fn from(m: Mat3) -> Self {
+        Mat4(
+            Vec4::from((m.0, 0.0)),
+            Vec4::from((m.1, 0.0)),
+            Vec4::from((m.2, 0.0)),
+            Vec4(0.0, 0.0, 0.0, 1.0),
+        )
+    }
+}
+
+impl From<Mat3> for Matrix {
+    fn from(m: Mat3) -> Self {
+        Matrix::from(vec![
+            vec![m.0 .0, m.0 .1, m.0 .2],
+            vec![m.1 .0, m.1 .1, m.1 .2],
+            vec![m.2 .0, m.2 .1, m.2 .2],
+        ])
+    }
+}
+
+impl From<Matrix> for Mat3 {
+    fn from(mat: Matrix) -> Self {
+        if mat.rows() != 3 || mat.cols() != 3 {
+            panic!(
+                "Invalid matrix ({},{}) supplied to Mat3::from, provide a (3,3) matrix",
+                mat.rows(),
+                mat.cols()
+            );
+        }
+
+        Mat3(
+            Vec3::from(mat.get_row(1)),
+            Vec3::from(mat.get_row(2)),
+            Vec3::from(mat.get_row(3)),
+        )
+    }
+}
+
+/*
+    Index
+*/
+impl Index<usize> for Mat3 {
+    type Output = Vec3;
+
+    fn index(&self, idx: usize) -> &Self::Output {
+        match idx {
+            0 => &self.0,
+            1 => &self.1,
+            2 => &self.2,
+            _ => panic!("Warning: accessing matrix {} by invalid row {}", self, idx),
+        }
+    }
+}
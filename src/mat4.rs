@@ -0,0 +1,396 @@
+use crate::mat::is_singular_determinant;
+use crate::{Matrix, Vec3, Vec4, VecX, VecxError};
+use std::fmt;
+use std::ops::{self, Index};
+
+/*
+    Definition
+*/
+/// Stack allocated 4x4 matrix stored as 4 row vectors
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Mat4(pub Vec4, pub Vec4, pub Vec4, pub Vec4);
+
+impl Default for Mat4 {
+    fn default() -> Self {
+        Mat4::IDENTITY
+    }
+}
+
+impl Mat4 {
+    pub const ZERO: Mat4 = Mat4(
+        Vec4(0.0, 0.0, 0.0, 0.0),
+        Vec4(0.0, 0.0, 0.0, 0.0),
+        Vec4(0.0, 0.0, 0.0, 0.0),
+        Vec4(0.0, 0.0, 0.0, 0.0),
+    );
+
+    pub const IDENTITY: Mat4 = Mat4(
+        Vec4(1.0, 0.0, 0.0, 0.0),
+        Vec4(0.0, 1.0, 0.0, 0.0),
+        Vec4(0.0, 0.0, 1.0, 0.0),
+        Vec4(0.0, 0.0, 0.0, 1.0),
+    );
+
+    pub fn zero() -> Self {
+        Mat4::ZERO
+    }
+
+    pub fn identity() -> Self {
+        Mat4::IDENTITY
+    }
+
+    pub fn from_rows(r0: Vec4, r1: Vec4, r2: Vec4, r3: Vec4) -> Self {
+        Mat4(r0, r1, r2, r3)
+    }
+
+    pub fn from_cols(c0: Vec4, c1: Vec4, c2: Vec4, c3: Vec4) -> Self {
+        Mat4(c0, c1, c2, c3).transpose()
+    }
+
+    /// Scale 4x4 matrix, same layout as `Matrix::m4_scale`
+    pub fn scale(scale: Vec3) -> Self {
+        Mat4(
+            Vec4(scale.x(), 0.0, 0.0, 0.0),
+            Vec4(0.0, scale.y(), 0.0, 0.0),
+            Vec4(0.0, 0.0, scale.z(), 0.0),
+            Vec4(0.0, 0.0, 0.0, 1.0),
+        )
+    }
+
+    /// Translate 4x4 matrix, same layout as `Matrix::m4_translate`
+    pub fn translate(translation: Vec3) -> Self {
+        Mat4(
+            Vec4(1.0, 0.0, 0.0, translation.x()),
+            Vec4(0.0, 1.0, 0.0, translation.y()),
+            Vec4(0.0, 0.0, 1.0, translation.z()),
+            Vec4(0.0, 0.0, 0.0, 1.0),
+        )
+    }
+
+    /// X rotation 4x4 matrix, same layout as `Matrix::m4_rotate_x`
+    pub fn rotate_x(angle: f64) -> Self {
+        let cos = f64::cos(angle);
+        let sin = f64::sin(angle);
+        Mat4(
+            Vec4(1.0, 0.0, 0.0, 0.0),
+            Vec4(0.0, cos, -sin, 0.0),
+            Vec4(0.0, sin, cos, 0.0),
+            Vec4(0.0, 0.0, 0.0, 1.0),
+        )
+    }
+
+    /// Y rotation 4x4 matrix, same layout as `Matrix::m4_rotate_y`
+    pub fn rotate_y(angle: f64) -> Self {
+        let cos = f64::cos(angle);
+        let sin = f64::sin(angle);
+        Mat4(
+            Vec4(cos, 0.0, sin, 0.0),
+            Vec4(0.0, 1.0, 0.0, 0.0),
+            Vec4(-sin, 0.0, cos, 0.0),
+            Vec4(0.0, 0.0, 0.0, 1.0),
+        )
+    }
+
+    /// Z rotation 4x4 matrix, same layout as `Matrix::m4_rotate_z`
+    pub fn rotate_z(angle: f64) -> Self {
+        let cos = f64::cos(angle);
+        let sin = f64::sin(angle);
+        Mat4(
+            Vec4(cos, sin, 0.0, 0.0),
+            Vec4(-sin, cos, 0.0, 0.0),
+            Vec4(0.0, 0.0, 1.0, 0.0),
+            Vec4(0.0, 0.0, 0.0, 1.0),
+        )
+    }
+
+    pub fn row(&self, idx: usize) -> Vec4 {
+        self[idx]
+    }
+
+    pub fn col(&self, idx: usize) -> Vec4 {
+        Vec4(self.0[idx], self.1[idx], self.2[idx], self.3[idx])
+    }
+
+    pub fn transpose(&self) -> Self {
+        Mat4(self.col(0), self.col(1), self.col(2), self.col(3))
+    }
+
+    pub fn determinant(&self) -> f64 {
+        let (s, c) = self.sub_determinants();
+        Self::determinant_from(&s, &c)
+    }
+
+    /// Returns the inverse matrix, or `None` if it is singular
+    pub fn inverse(&self) -> Option<Self> {
+        let (s, c) = self.sub_determinants();
+        let det = Self::determinant_from(&s, &c);
+        if is_singular_determinant(
+            det,
+            &[
+                self.0.magnitude(),
+                self.1.magnitude(),
+                self.2.magnitude(),
+                self.3.magnitude(),
+            ],
+        ) {
+            return None;
+        }
+
+        let Mat4(r0, r1, r2, r3) = *self;
+        let inverse = Mat4(
+            Vec4(
+                r1.1 * c[5] - r1.2 * c[4] + r1.3 * c[3],
+                -r0.1 * c[5] + r0.2 * c[4] - r0.3 * c[3],
+                r3.1 * s[5] - r3.2 * s[4] + r3.3 * s[3],
+                -r2.1 * s[5] + r2.2 * s[4] - r2.3 * s[3],
+            ),
+            Vec4(
+                -r1.0 * c[5] + r1.2 * c[2] - r1.3 * c[1],
+                r0.0 * c[5] - r0.2 * c[2] + r0.3 * c[1],
+                -r3.0 * s[5] + r3.2 * s[2] - r3.3 * s[1],
+                r2.0 * s[5] - r2.2 * s[2] + r2.3 * s[1],
+            ),
+            Vec4(
+                r1.0 * c[4] - r1.1 * c[2] + r1.3 * c[0],
+                -r0.0 * c[4] + r0.1 * c[2] - r0.3 * c[0],
+                r3.0 * s[4] - r3.1 * s[2] + r3.3 * s[0],
+                -r2.0 * s[4] + r2.1 * s[2] - r2.3 * s[0],
+            ),
+            Vec4(
+                -r1.0 * c[3] + r1.1 * c[1] - r1.2 * c[0],
+                r0.0 * c[3] - r0.1 * c[1] + r0.2 * c[0],
+                -r3.0 * s[3] + r3.1 * s[1] - r3.2 * s[0],
+                r2.0 * s[3] - r2.1 * s[1] + r2.2 * s[0],
+            ),
+        );
+        Some(inverse * (1.0 / det))
+    }
+
+    /// 2x2 determinants of the two upper rows (s) and the two lower rows (c)
+    fn sub_determinants(&self) -> ([f64; 6], [f64; 6]) {
+        let Mat4(r0, r1, r2, r3) = *self;
+        let s = [
+            r0.0 * r1.1 - r1.0 * r0.1,
+            r0.0 * r1.2 - r1.0 * r0.2,
+            r0.0 * r1.3 - r1.0 * r0.3,
+            r0.1 * r1.2 - r1.1 * r0.2,
+            r0.1 * r1.3 - r1.1 * r0.3,
+            r0.2 * r1.3 - r1.2 * r0.3,
+        ];
+        let c = [
+            r2.0 * r3.1 - r3.0 * r2.1,
+            r2.0 * r3.2 - r3.0 * r2.2,
+            r2.0 * r3.3 - r3.0 * r2.3,
+            r2.1 * r3.2 - r3.1 * r2.2,
+            r2.1 * r3.3 - r3.1 * r2.3,
+            r2.2 * r3.3 - r3.2 * r2.3,
+        ];
+        (s, c)
+    }
+
+    fn determinant_from(s: &[f64; 6], c: &[f64; 6]) -> f64 {
+        s[0] * c[5] - s[1] * c[4] + s[2] * c[3] + s[3] * c[2] - s[4] * c[1] + s[5] * c[0]
+    }
+
+    /// Transforms the point `p`, dividing by w when w is neither 0 nor 1
+    ///
+    /// A w of 0 puts the point at infinity, its x, y and z are then returned undivided, as a
+    /// direction, see `try_transform_point`.
+    pub fn transform_point(&self, p: Vec3) -> Vec3 {
+        let transformed = *self * p.as_homogenous_point();
+        let w = transformed.w();
+        if w == 1.0 || w == 0.0 {
+            Vec3::from(transformed)
+        } else {
+            transformed.perspective_divide()
+        }
+    }
+
+    /// Transforms the point `p`, or `VecxError::PointAtInfinity` when the transformed w is 0
+    pub fn try_transform_point(&self, p: Vec3) -> Result<Vec3, VecxError> {
+        let transformed = *self * p.as_homogenous_point();
+        let w = transformed.w();
+        if w == 0.0 {
+            Err(VecxError::PointAtInfinity)
+        } else if w == 1.0 {
+            Ok(Vec3::from(transformed))
+        } else {
+            Ok(transformed.perspective_divide())
+        }
+    }
+
+    /// Transforms the direction `v`, ignoring translation
+    pub fn transform_vector(&self, v: Vec3) -> Vec3 {
+        Vec3::from(*self * v.as_homogenous_vector())
+    }
+}
+
+/*
+    Ops
+*/
+/*
+    Neg
+*/
+impl ops::Neg for Mat4 {
+    type Output = Self;
+
+    fn neg(self) -> Self::Output {
+        Mat4(-self.0, -self.1, -self.2, -self.3)
+    }
+}
+
+/*
+    Add
+*/
+impl ops::Add<Mat4> for Mat4 {
+    type Output = Mat4;
+
+    fn add(self, rhs: Mat4) -> Self::Output {
+        Mat4(
+            self.0 + rhs.0,
+            self.1 + rhs.1,
+            self.2 + rhs.2,
+            self.3 + rhs.3,
+        )
+    }
+}
+
+impl ops::AddAssign<Mat4> for Mat4 {
+    fn add_assign(&mut self, rhs: Mat4) {
+        *self = *self + rhs;
+    }
+}
+
+/*
+    Sub
+*/
+impl ops::Sub<Mat4> for Mat4 {
+    type Output = Mat4;
+
+    fn sub(self, rhs: Mat4) -> Self::Output {
+        Mat4(
+            self.0 - rhs.0,
+            self.1 - rhs.1,
+            self.2 - rhs.2,
+            self.3 - rhs.3,
+        )
+    }
+}
+
+impl ops::SubAssign<Mat4> for Mat4 {
+    fn sub_assign(&mut self, rhs: Mat4) {
+        *self = *self - rhs;
+    }
+}
+
+/*
+    Mul
+*/
+impl ops::Mul<f64> for Mat4 {
+    type Output = Mat4;
+
+    fn mul(self, rhs: f64) -> Self::Output {
+        Mat4(self.0 * rhs, self.1 * rhs, self.2 * rhs, self.3 * rhs)
+    }
+}
+
+impl ops::Mul<Mat4> for f64 {
+    type Output = Mat4;
+
+    fn mul(self, rhs: Mat4) -> Self::Output {
+        rhs * self
+    }
+}
+
+impl ops::Mul<Vec4> for Mat4 {
+    type Output = Vec4;
+
+    fn mul(self, rhs: Vec4) -> Self::Output {
+        Vec4(
+            self.0.dot_product(&rhs),
+            self.1.dot_product(&rhs),
+            self.2.dot_product(&rhs),
+            self.3.dot_product(&rhs),
+        )
+    }
+}
+
+impl ops::Mul<Mat4> for Mat4 {
+    type Output = Mat4;
+
+    fn mul(self, rhs: Mat4) -> Self::Output {
+        let cols = rhs.transpose();
+        Mat4(cols * self.0, cols * self.1, cols * self.2, cols * self.3)
+    }
+}
+
+impl ops::MulAssign<f64> for Mat4 {
+    fn mul_assign(&mut self, rhs: f64) {
+        *self = *self * rhs;
+    }
+}
+
+impl ops::MulAssign<Mat4> for Mat4 {
+    fn mul_assign(&mut self, rhs: Mat4) {
+        *self = *self * rhs;
+    }
+}
+
+/*
+    Display
+*/
+impl fmt::Display for Mat4 {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "[{}, {}, {}, {}]", self.0, self.1, self.2, self.3)
+    }
+}
+
+/*
+    From
+*/
+impl From<Mat4> for Matrix {
+    fn from(m: Mat4) -> Self {
+        Matrix::from(vec![
+            vec![m.0 .0, m.0 .1, m.0 .2, m.0 .3],
+            vec![m.1 .0, m.1 .1, m.1 .2, m.1 .3],
+            vec![m.2 .0, m.2 .1, m.2 .2, m.2 .3],
+            vec![m.3 .0, m.3 .1, m.3 .2, m.3 .3],
+        ])
+    }
+}
+
+impl From<Matrix> for Mat4 {
+    fn from(mat: Matrix) -> Self {
+        if mat.rows() != 4 || mat.cols() != 4 {
+            panic!(
+                "Invalid matrix ({},{}) supplied to Mat4::from, provide a (4,4) matrix",
+                mat.rows(),
+                mat.cols()
+            );
+        }
+
+        Mat4(
+            Vec4::from(mat.get_row(1)),
+            Vec4::from(mat.get_row(2)),
+            Vec4::from(mat.get_row(3)),
+            Vec4::from(mat.get_row(4)),
+        )
+    }
+}
+
+/*
+    Index
+*/
+impl Index<usize> for Mat4 {
+    type Output = Vec4;
+
+    fn index(&self, idx: usize) -> &Self::Output {
+        match idx {
+            0 => &self.0,
+            1 => &self.1,
+            2 => &self.2,
+            3 => &self.3,
+            _ => panic!("Warning: accessing matrix {} by invalid row {}", self, idx),
+        }
+    }
+}
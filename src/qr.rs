@@ -0,0 +1,135 @@
+use crate::mat::SINGULAR_EPSILON;
+use crate::{Matrix, RightHandSide, VecxError};
+
+/*
+    Definition
+*/
+/// QR decomposition by Householder reflections, `A = Q * R`
+///
+/// For an `m`x`n` matrix `Q` is `m`x`m` orthogonal and `R` is `m`x`n` upper triangular
+#[derive(Debug, Clone)]
+pub struct Qr {
+    q: Vec<Vec<f64>>,
+    r: Vec<Vec<f64>>,
+}
+
+impl Qr {
+    pub fn new(m: &Matrix) -> Self {
+        let (rows, cols) = (m.rows(), m.cols());
+        let mut r: Vec<Vec<f64>> = (1..=rows).map(|row| m.get_row(row)).collect();
+        let identity = Matrix::identity(rows);
+        let mut q: Vec<Vec<f64>> = (1..=rows).map(|row| identity.get_row(row)).collect();
+
+        for k in 0..cols.min(rows.saturating_sub(1)) {
+            // Householder vector taking column k below the diagonal to a multiple of e_k,
+            // alpha has the opposite sign of the pivot to avoid cancellation
+            let mut v: Vec<f64> = r[k..].iter().map(|row| row[k]).collect();
+            let norm = v.iter().map(|x| x * x).sum::<f64>().sqrt();
+            if norm == 0.0 {
+                continue;
+            }
+            let alpha = if v[0] > 0.0 { -norm } else { norm };
+            v[0] -= alpha;
+            let v_norm = v.iter().map(|x| x * x).sum::<f64>().sqrt();
+            if v_norm == 0.0 {
+                continue;
+            }
+            v.iter_mut().for_each(|x| *x /= v_norm);
+
+            // R = H * R, H = I - 2 * v * vT
+            for col in k..cols {
+                let dot: f64 = v.iter().zip(&r[k..]).map(|(vi, row)| vi * row[col]).sum();
+                for (vi, row) in v.iter().zip(&mut r[k..]) {
+                    row[col] -= 2.0 * vi * dot;
+                }
+            }
+            // Q = Q * H
+            for row in q.iter_mut() {
+                let dot: f64 = v.iter().zip(&row[k..]).map(|(vi, x)| vi * x).sum();
+                for (vi, x) in v.iter().zip(&mut row[k..]) {
+                    *x -= 2.0 * vi * dot;
+                }
+            }
+            for row in r.iter_mut().skip(k + 1) {
+                row[k] = 0.0;
+            }
+        }
+
+        Qr { q, r }
+    }
+
+    /// Orthogonal factor, `m`x`m`
+    pub fn q(&self) -> Matrix {
+        Matrix::from(self.q.clone())
+    }
+
+    /// Upper triangular factor, `m`x`n`
+    pub fn r(&self) -> Matrix {
+        Matrix::from(self.r.clone())
+    }
+
+    fn rows(&self) -> usize {
+        self.r.len()
+    }
+
+    fn cols(&self) -> usize {
+        self.r.first().map_or(0, |row| row.len())
+    }
+
+    /// True when a diagonal value of `R` is negligible compared to the largest one,
+    /// meaning the columns of `A` are linearly dependent
+    pub fn is_rank_deficient(&self) -> bool {
+        let n = self.cols().min(self.rows());
+        let largest = (0..n).map(|i| self.r[i][i].abs()).fold(0.0, f64::max);
+        n < self.cols()
+            || largest == 0.0
+            || (0..n).any(|i| self.r[i][i].abs() <= SINGULAR_EPSILON * largest)
+    }
+
+    pub fn least_squares<B: RightHandSide>(&self, b: &B) -> B {
+        self.try_least_squares(b)
+            .unwrap_or_else(|e| panic!("{}", e))
+    }
+
+    /// Returns the `x` minimizing `|A * x - b|` for a vector or for every column of a matrix
+    ///
+    /// `x` has as many rows as `A` has columns, fixed-size vectors are padded with zeros.
+    /// Fails if `A` has fewer rows than columns, linearly dependent columns, or if `b`
+    /// doesn't have as many rows as `A`.
+    pub fn try_least_squares<B: RightHandSide>(&self, b: &B) -> Result<B, VecxError> {
+        let (rows, cols) = (self.rows(), self.cols());
+        if rows < cols {
+            return Err(VecxError::Underdetermined { rows, cols });
+        }
+        let (b_rows, columns) = (b.rows(), b.columns());
+        if b_rows != rows || columns.iter().any(|col| col.len() != b_rows) {
+            return Err(VecxError::InvalidRhs {
+                matrix: (rows, cols),
+                rhs: (b_rows, columns.len()),
+            });
+        }
+        if self.is_rank_deficient() {
+            return Err(VecxError::RankDeficient);
+        }
+
+        Ok(B::from_columns(
+            cols,
+            columns.iter().map(|col| self.substitute(col)).collect(),
+        ))
+    }
+
+    /// Solves `R * x = QT * b` by back substitution on the top square block of `R`
+    fn substitute(&self, b: &[f64]) -> Vec<f64> {
+        let n = self.cols();
+        let mut x: Vec<f64> = (0..n)
+            .map(|col| self.q.iter().zip(b).map(|(row, bi)| row[col] * bi).sum())
+            .collect();
+        for row in (0..n).rev() {
+            for col in (row + 1)..n {
+                x[row] -= self.r[row][col] * x[col];
+            }
+            x[row] /= self.r[row][row];
+        }
+        x
+    }
+}
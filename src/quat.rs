@@ -0,0 +1,367 @@
+use crate::{Mat3, Mat4, Matrix, Vec3, Vec4, VecX};
+use std::fmt;
+use std::ops;
+
+/*
+    Definition
+*/
+/// Rotation quaternion `x*i + y*j + z*k + w`
+///
+/// Rotations follow the right-hand rule around their axis, which matches `Matrix::m4_rotate_x`
+/// and `Matrix::m4_rotate_y`. `Matrix::m4_rotate_z(a)` is a rotation of `-a` around z.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Quat(pub f64, pub f64, pub f64, pub f64);
+
+impl Default for Quat {
+    fn default() -> Self {
+        Quat::IDENTITY
+    }
+}
+
+impl Quat {
+    pub const IDENTITY: Quat = Quat(0.0, 0.0, 0.0, 1.0);
+
+    pub fn identity() -> Self {
+        Quat::IDENTITY
+    }
+
+    /// Rotation of `angle` radians around `axis`
+    ///
+    /// Panics if `axis` is a zero vector
+    pub fn from_axis_angle(axis: Vec3, angle: f64) -> Self {
+        let axis = axis.normalized();
+        let half = angle / 2.0;
+        let sin = f64::sin(half);
+        Quat(
+            axis.x() * sin,
+            axis.y() * sin,
+            axis.z() * sin,
+            f64::cos(half),
+        )
+    }
+
+    /// Rotation around x by `euler.x()`, then around y by `euler.y()`, then around z by `euler.z()`
+    ///
+    /// Angles follow the right-hand rule like `from_axis_angle`. `Vec3::rot` uses the same order
+    /// but rotates the other way, use `from_euler_lh` to match it.
+    pub fn from_euler(euler: Vec3) -> Self {
+        let x = Quat::from_axis_angle(Vec3(1.0, 0.0, 0.0), euler.x());
+        let y = Quat::from_axis_angle(Vec3(0.0, 1.0, 0.0), euler.y());
+        let z = Quat::from_axis_angle(Vec3(0.0, 0.0, 1.0), euler.z());
+        z * y * x
+    }
+
+    /// Same as `from_euler` with left-handed angles, so `from_euler_lh(e).rotate(v)` is `v.rot(&e)`
+    pub fn from_euler_lh(euler: Vec3) -> Self {
+        Quat::from_euler(-euler)
+    }
+
+    pub fn x(&self) -> f64 {
+        self.0
+    }
+
+    pub fn y(&self) -> f64 {
+        self.1
+    }
+
+    pub fn z(&self) -> f64 {
+        self.2
+    }
+
+    pub fn w(&self) -> f64 {
+        self.3
+    }
+
+    /// Vector part (x, y, z)
+    pub fn vector(&self) -> Vec3 {
+        Vec3(self.0, self.1, self.2)
+    }
+
+    /// Returns the `(axis, angle)` of the rotation, the axis defaults to x for the identity
+    pub fn to_axis_angle(&self) -> (Vec3, f64) {
+        let q = self.normalized();
+        let sin = q.vector().magnitude();
+        if sin == 0.0 {
+            return (Vec3(1.0, 0.0, 0.0), 0.0);
+        }
+        (q.vector() / sin, 2.0 * f64::atan2(sin, q.w()))
+    }
+
+    pub fn dot(&self, other: &Quat) -> f64 {
+        self.0 * other.0 + self.1 * other.1 + self.2 * other.2 + self.3 * other.3
+    }
+
+    pub fn magnitude(&self) -> f64 {
+        f64::sqrt(self.dot(self))
+    }
+
+    pub fn normalized(&self) -> Quat {
+        let m = self.magnitude();
+        if m == 0.0 {
+            panic!("{}: Can't be normalized because its magnitude is 0", self);
+        }
+        *self * (1.0 / m)
+    }
+
+    pub fn conjugate(&self) -> Quat {
+        Quat(-self.0, -self.1, -self.2, self.3)
+    }
+
+    /// Returns the inverse quaternion, or `None` if its magnitude is 0
+    ///
+    /// For unit quaternions this is the same as `conjugate`
+    pub fn inverse(&self) -> Option<Quat> {
+        let norm = self.dot(self);
+        if norm == 0.0 {
+            return None;
+        }
+        Some(self.conjugate() * (1.0 / norm))
+    }
+
+    /// Rotates `v` by this unit quaternion
+    pub fn rotate(&self, v: Vec3) -> Vec3 {
+        let q = self.vector();
+        let t = 2.0 * q.cross(&v);
+        v + self.w() * t + q.cross(&t)
+    }
+
+    /// Normalized linear interpolation along the shortest path
+    pub fn nlerp(&self, other: &Quat, t: f64) -> Quat {
+        let end = if self.dot(other) < 0.0 {
+            -*other
+        } else {
+            *other
+        };
+        (*self * (1.0 - t) + end * t).normalized()
+    }
+
+    /// Spherical linear interpolation along the shortest path
+    ///
+    /// Falls back to `nlerp` when both rotations are almost identical
+    pub fn slerp(&self, other: &Quat, t: f64) -> Quat {
+        let mut cos = self.dot(other);
+        let mut end = *other;
+        if cos < 0.0 {
+            cos = -cos;
+            end = -end;
+        }
+
+        if cos > 0.9995 {
+            return self.nlerp(&end, t);
+        }
+
+        let theta = f64::acos(cos);
+        let sin = f64::sin(theta);
+        *self * (f64::sin((1.0 - t) * theta) / sin) + end * (f64::sin(t * theta) / sin)
+    }
+
+    /// 3x3 rotation matrix of this unit quaternion
+    pub fn to_mat3(&self) -> Mat3 {
+        let Quat(x, y, z, w) = *self;
+        Mat3(
+            Vec3(
+                1.0 - 2.0 * (y * y + z * z),
+                2.0 * (x * y - z * w),
+                2.0 * (x * z + y * w),
+            ),
+            Vec3(
+                2.0 * (x * y + z * w),
+                1.0 - 2.0 * (x * x + z * z),
+                2.0 * (y * z - x * w),
+            ),
+            Vec3(
+                2.0 * (x * z - y * w),
+                2.0 * (y * z + x * w),
+                1.0 - 2.0 * (x * x + y * y),
+            ),
+        )
+    }
+
+    /// 4x4 rotation matrix of this unit quaternion
+    pub fn to_mat4(&self) -> Mat4 {
+        Mat4::from(self.to_mat3())
+    }
+
+    /// Unit quaternion of a pure rotation matrix
+    pub fn from_mat3(m: Mat3) -> Self {
+        let trace = m.0 .0 + m.1 .1 + m.2 .2;
+        let q = if trace > 0.0 {
+            let s = f64::sqrt(trace + 1.0) * 2.0;
+            Quat(
+                (m.2 .1 - m.1 .2) / s,
+                (m.0 .2 - m.2 .0) / s,
+                (m.1 .0 - m.0 .1) / s,
+                s / 4.0,
+            )
+        } else if m.0 .0 > m.1 .1 && m.0 .0 > m.2 .2 {
+            let s = f64::sqrt(1.0 + m.0 .0 - m.1 .1 - m.2 .2) * 2.0;
+            Quat(
+                s / 4.0,
+                (m.0 .1 + m.1 .0) / s,
+                (m.0 .2 + m.2 .0) / s,
+                (m.2 .1 - m.1 .2) / s,
+            )
+        } else if m.1 .1 > m.2 .2 {
+            let s = f64::sqrt(1.0 + m.1 .1 - m.0 .0 - m.2 .2) * 2.0;
+            Quat(
+                (m.0 .1 + m.1 .0) / s,
+                s / 4.0,
+                (m.1 .2 + m.2 .1) / s,
+                (m.0 .2 - m.2 .0) / s,
+            )
+        } else {
+            let s = f64::sqrt(1.0 + m.2 .2 - m.0 .0 - m.1 .1) * 2.0;
+            Quat(
+                (m.0 .2 + m.2 .0) / s,
+                (m.1 .2 + m.2 .1) / s,
+                s / 4.0,
+                (m.1 .0 - m.0 .1) / s,
+            )
+        };
+        q.normalized()
+    }
+}
+
+/*
+    Ops
+*/
+/*
+    Neg
+*/
+impl ops::Neg for Quat {
+    type Output = Self;
+
+    fn neg(self) -> Self::Output {
+        Quat(-self.0, -self.1, -self.2, -self.3)
+    }
+}
+
+/*
+    Add
+*/
+impl ops::Add<Quat> for Quat {
+    type Output = Quat;
+
+    fn add(self, rhs: Quat) -> Self::Output {
+        Quat(
+            self.0 + rhs.0,
+            self.1 + rhs.1,
+            self.2 + rhs.2,
+            self.3 + rhs.3,
+        )
+    }
+}
+
+/*
+    Sub
+*/
+impl ops::Sub<Quat> for Quat {
+    type Output = Quat;
+
+    fn sub(self, rhs: Quat) -> Self::Output {
+        Quat(
+            self.0 - rhs.0,
+            self.1 - rhs.1,
+            self.2 - rhs.2,
+            self.3 - rhs.3,
+        )
+    }
+}
+
+/*
+    Mul
+*/
+impl ops::Mul<f64> for Quat {
+    type Output = Quat;
+
+    fn mul(self, rhs: f64) -> Self::Output {
+        Quat(self.0 * rhs, self.1 * rhs, self.2 * rhs, self.3 * rhs)
+    }
+}
+
+/// Hamilton product, `a * b` rotates by `b` then by `a`
+impl ops::Mul<Quat> for Quat {
+    type Output = Quat;
+
+    fn mul(self, rhs: Quat) -> Self::Output {
+        let Quat(x1, y1, z1, w1) = self;
+        let Quat(x2, y2, z2, w2) = rhs;
+        Quat(
+            w1 * x2 + x1 * w2 + y1 * z2 - z1 * y2,
+            w1 * y2 - x1 * z2 + y1 * w2 + z1 * x2,
+            w1 * z2 + x1 * y2 - y1 * x2 + z1 * w2,
+            w1 * w2 - x1 * x2 - y1 * y2 - z1 * z2,
+        )
+    }
+}
+
+impl ops::MulAssign<Quat> for Quat {
+    fn mul_assign(&mut self, rhs: Quat) {
+        *self = *self * rhs;
+    }
+}
+
+impl ops::Mul<Vec3> for Quat {
+    type Output = Vec3;
+
+    fn mul(self, rhs: Vec3) -> Self::Output {
+        self.rotate(rhs)
+    }
+}
+
+/*
+    Display
+*/
+impl fmt::Display for Quat {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "({}i + {}j + {}k + {})", self.0, self.1, self.2, self.3)
+    }
+}
+
+/*
+    From
+*/
+impl From<Vec4> for Quat {
+    fn from(v: Vec4) -> Self {
+        Quat(v.0, v.1, v.2, v.3)
+    }
+}
+
+impl From<Quat> for Vec4 {
+    fn from(q: Quat) -> Self {
+        Vec4(q.0, q.1, q.2, q.3)
+    }
+}
+
+impl From<Mat3> for Quat {
+    fn from(m: Mat3) -> Self {
+        Quat::from_mat3(m)
+    }
+}
+
+impl From<Mat4> for Quat {
+    fn from(m: Mat4) -> Self {
+        Quat::from_mat3(Mat3::from(m))
+    }
+}
+
+impl From<Quat> for Matrix {
+    fn from(q: Quat) -> Self {
+        Matrix::from(q.to_mat4())
+    }
+}
+
+impl From<Matrix> for Quat {
+    fn from(mat: Matrix) -> Self {
+        if mat.rows() < 3 || mat.cols() < 3 {
+            panic!(
+                "Invalid matrix ({},{}) supplied to Quat::from, provide a (3,3) or (4,4) rotation matrix",
+                mat.rows(),
+                mat.cols()
+            );
+        }
+
+        let row = |r: usize| Vec3(mat.get((r, 1)), mat.get((r, 2)), mat.get((r, 3)));
+        Quat::from_mat3(Mat3(row(1), row(2), row(3)))
+    }
+}
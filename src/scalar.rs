@@ -0,0 +1,143 @@
+use std::fmt::{Debug, Display};
+use std::ops::{
+    Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, Rem, RemAssign, Sub, SubAssign,
+};
+
+use crate::VecxError;
+
+/// Component type of `Vec2`, `Vec3` and `Vec4`, implemented for `f32`, `f64`, `i32` and `i64`
+///
+/// Lengths, angles and normalization are computed in `f64` and converted back with `from_f64`,
+/// so integer vectors truncate the result toward zero.
+pub trait Scalar:
+    Copy
+    + Debug
+    + Display
+    + Default
+    + PartialEq
+    + PartialOrd
+    + Add<Output = Self>
+    + Sub<Output = Self>
+    + Mul<Output = Self>
+    + Div<Output = Self>
+    + Rem<Output = Self>
+    + Neg<Output = Self>
+    + AddAssign
+    + SubAssign
+    + MulAssign
+    + DivAssign
+    + RemAssign
+{
+    const ZERO: Self;
+    const ONE: Self;
+
+    fn to_f64(self) -> f64;
+
+    /// Lossy conversion, integers are truncated toward zero and saturate at their bounds
+    fn from_f64(value: f64) -> Self;
+
+    /// Division that fails on 0 / 0, and on any division by 0 for integers
+    fn try_div(self, rhs: Self) -> Result<Self, VecxError>;
+
+    /// Remainder that fails on a divisor of 0
+    fn try_rem(self, rhs: Self) -> Result<Self, VecxError>;
+
+    /// Absolute value, integers wrap at their minimum like `wrapping_abs`
+    fn abs(self) -> Self;
+
+    /// Largest integer less than or equal to the value, integers are returned as is
+    fn floor(self) -> Self;
+
+    /// Smallest integer greater than or equal to the value, integers are returned as is
+    fn ceil(self) -> Self;
+}
+
+macro_rules! impl_float_scalar {
+    ($($t:ty),*) => {$(
+        impl Scalar for $t {
+            const ZERO: Self = 0.0;
+            const ONE: Self = 1.0;
+
+            fn to_f64(self) -> f64 {
+                self as f64
+            }
+
+            fn from_f64(value: f64) -> Self {
+                value as $t
+            }
+
+            fn try_div(self, rhs: Self) -> Result<Self, VecxError> {
+                if self == 0.0 && rhs == 0.0 {
+                    return Err(VecxError::ZeroByZeroDivision);
+                }
+                Ok(self / rhs)
+            }
+
+            fn try_rem(self, rhs: Self) -> Result<Self, VecxError> {
+                if rhs == 0.0 {
+                    return Err(VecxError::ZeroDivisor);
+                }
+                Ok(self % rhs)
+            }
+
+            fn abs(self) -> Self {
+                <$t>::abs(self)
+            }
+
+            fn floor(self) -> Self {
+                <$t>::floor(self)
+            }
+
+            fn ceil(self) -> Self {
+                <$t>::ceil(self)
+            }
+        }
+    )*};
+}
+
+macro_rules! impl_int_scalar {
+    ($($t:ty),*) => {$(
+        impl Scalar for $t {
+            const ZERO: Self = 0;
+            const ONE: Self = 1;
+
+            fn to_f64(self) -> f64 {
+                self as f64
+            }
+
+            fn from_f64(value: f64) -> Self {
+                value as $t
+            }
+
+            fn try_div(self, rhs: Self) -> Result<Self, VecxError> {
+                match (self, rhs) {
+                    (0, 0) => Err(VecxError::ZeroByZeroDivision),
+                    (_, 0) => Err(VecxError::IntegerDivisionByZero),
+                    _ => Ok(self.wrapping_div(rhs)),
+                }
+            }
+
+            fn try_rem(self, rhs: Self) -> Result<Self, VecxError> {
+                if rhs == 0 {
+                    return Err(VecxError::ZeroDivisor);
+                }
+                Ok(self.wrapping_rem(rhs))
+            }
+
+            fn abs(self) -> Self {
+                self.wrapping_abs()
+            }
+
+            fn floor(self) -> Self {
+                self
+            }
+
+            fn ceil(self) -> Self {
+                self
+            }
+        }
+    )*};
+}
+
+impl_float_scalar!(f32, f64);
+impl_int_scalar!(i32, i64);
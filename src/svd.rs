@@ -0,0 +1,109 @@
+use crate::eigen::rotate_vectors;
+use crate::Matrix;
+
+/// Sweeps after which the Jacobi iterations give up, convergence usually takes fewer than 10
+const MAX_SWEEPS: usize = 100;
+
+/// Thin singular value decomposition `A = U * Σ * VT` of an `m`x`n` matrix, `k = min(m, n)`
+///
+/// `U` is `m`x`k` and `VT` is `k`x`n`, both with orthonormal rows or columns, and `Σ` is the
+/// `k`x`k` diagonal of singular values in decreasing order.
+/// Uses one-sided Jacobi rotations, accurate even for tiny singular values.
+pub(crate) fn svd(m: &Matrix) -> (Matrix, Matrix, Matrix) {
+    if m.rows() < m.cols() {
+        // A = (AT)T = (U' * Σ * V'T)T = V' * Σ * U'T
+        let (u, sigma, vt) = svd(&m.transpose());
+        return (vt.transpose(), sigma, u.transpose());
+    }
+
+    let (rows, cols) = (m.rows(), m.cols());
+    let mut u: Vec<Vec<f64>> = (1..=cols).map(|col| m.get_col(col)).collect();
+    let mut v: Vec<Vec<f64>> = (0..cols)
+        .map(|col| {
+            (0..cols)
+                .map(|row| if row == col { 1.0 } else { 0.0 })
+                .collect()
+        })
+        .collect();
+
+    // orthogonalize every pair of columns until they're all orthogonal, V accumulates the rotations
+    for _ in 0..MAX_SWEEPS {
+        let mut rotated = false;
+        for p in 0..cols {
+            for q in (p + 1)..cols {
+                let alpha = dot(&u[p], &u[p]);
+                let beta = dot(&u[q], &u[q]);
+                let gamma = dot(&u[p], &u[q]);
+                if gamma == 0.0 || gamma.abs() <= f64::EPSILON * f64::sqrt(alpha * beta) {
+                    continue;
+                }
+                rotated = true;
+
+                let zeta = (beta - alpha) / (2.0 * gamma);
+                let t = zeta.signum() / (zeta.abs() + f64::sqrt(1.0 + zeta * zeta));
+                let c = 1.0 / f64::sqrt(1.0 + t * t);
+                let s = c * t;
+                rotate_vectors(&mut u, p, q, c, s);
+                rotate_vectors(&mut v, p, q, c, s);
+            }
+        }
+        if !rotated {
+            break;
+        }
+    }
+
+    let mut order: Vec<usize> = (0..cols).collect();
+    let norms: Vec<f64> = u.iter().map(|col| dot(col, col).sqrt()).collect();
+    order.sort_by(|&i, &j| norms[j].total_cmp(&norms[i]));
+
+    let largest = norms.iter().fold(0.0, |max: f64, &n| max.max(n));
+    let tolerance = rows as f64 * f64::EPSILON * largest;
+    let mut u_cols: Vec<Vec<f64>> = Vec::with_capacity(cols);
+    for &i in &order {
+        let col = if norms[i] > tolerance {
+            u[i].iter().map(|x| x / norms[i]).collect()
+        } else {
+            orthogonal_complement(&u_cols, rows)
+        };
+        u_cols.push(col);
+    }
+
+    let mut sigma = Matrix::new(cols, cols);
+    for (idx, &i) in order.iter().enumerate() {
+        sigma.set((idx + 1, idx + 1), norms[i]);
+    }
+    let u = Matrix::from(
+        (0..rows)
+            .map(|row| u_cols.iter().map(|col| col[row]).collect())
+            .collect::<Vec<Vec<f64>>>(),
+    );
+    let vt = Matrix::from(order.iter().map(|&i| v[i].clone()).collect::<Vec<_>>());
+    (u, sigma, vt)
+}
+
+fn dot(a: &[f64], b: &[f64]) -> f64 {
+    a.iter().zip(b).map(|(x, y)| x * y).sum()
+}
+
+/// Unit vector orthogonal to every column of `basis`, built by Gram-Schmidt from the standard basis
+fn orthogonal_complement(basis: &[Vec<f64>], rows: usize) -> Vec<f64> {
+    let mut best = vec![0.0; rows];
+    let mut best_norm = 0.0;
+    for axis in 0..rows {
+        let mut candidate = vec![0.0; rows];
+        candidate[axis] = 1.0;
+        for col in basis {
+            let projection = dot(&candidate, col);
+            candidate
+                .iter_mut()
+                .zip(col)
+                .for_each(|(x, c)| *x -= projection * c);
+        }
+        let norm = dot(&candidate, &candidate).sqrt();
+        if norm > best_norm {
+            best = candidate;
+            best_norm = norm;
+        }
+    }
+    best.iter().map(|x| x / best_norm).collect()
+}
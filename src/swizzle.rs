@@ -0,0 +1,10 @@
+//! Swizzle accessors generated by `build.rs`
+//!
+//! Every combination of 2 to 4 components is available as a method, e.g. `v.xzy()`,
+//! `v.rgba()` or `v.xxyy()`, so invalid swizzles are compile errors and nothing is allocated.
+//! Vec2 accepts `xy`, Vec3 `xyz` and `rgb`, Vec4 `xyzw` and `rgba`.
+//!
+//! Swizzles without duplicate components also have a setter, e.g. `v.set_zx(Vec2(1.0, 2.0))`.
+use crate::{Scalar, Vec2, Vec3, Vec4};
+
+include!(concat!(env!("OUT_DIR"), "/swizzle.rs"));
@@ -1,4 +1,20 @@
+mod approx;
+mod curve;
+mod euler;
+mod fit;
+mod geometry;
+mod interpolation;
+mod lu;
 mod mat;
+mod mat2;
+mod mat3;
+mod mat4;
+mod qr;
+mod quat;
+mod svd;
+mod swizzle;
+mod transform;
 mod vec2;
 mod vec3;
 mod vec4;
+mod vecn;
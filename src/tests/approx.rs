@@ -0,0 +1,69 @@
+use crate::{
+    assert_approx_eq, assert_relative_eq, assert_ulps_eq, ApproxEq, Mat3, Matrix, Quat, Vec2, Vec3,
+    Vec4,
+};
+
+#[test]
+fn f64_abs_diff_eq() {
+    assert!(0.3.abs_diff_eq(&(0.1 + 0.2), 1e-12));
+    assert!(1.0.abs_diff_eq(&1.05, 0.1));
+    assert!(!1.0.abs_diff_eq(&1.2, 0.1));
+    assert!(f64::INFINITY.abs_diff_eq(&f64::INFINITY, 0.0));
+    assert!(!f64::NAN.abs_diff_eq(&f64::NAN, 1.0));
+}
+
+#[test]
+fn f64_relative_eq() {
+    assert!(1e12.relative_eq(&(1e12 + 1.0), 0.0, 1e-9));
+    assert!(!1e12.relative_eq(&(1e12 + 1e4), 0.0, 1e-9));
+    assert!(!1e-12.relative_eq(&2e-12, 0.0, 1e-9));
+    assert!(1e-12.relative_eq(&2e-12, 1e-9, 1e-9));
+    assert!(!f64::INFINITY.relative_eq(&f64::MAX, 1e-9, 1.0));
+}
+
+#[test]
+fn f64_ulps_eq() {
+    let next = f64::from_bits(1.0f64.to_bits() + 1);
+    assert!(1.0.ulps_eq(&next, 0.0, 1));
+    assert!(!1.0.ulps_eq(&f64::from_bits(1.0f64.to_bits() + 5), 0.0, 4));
+    assert!(!1e-300.ulps_eq(&-1e-300, 0.0, u64::MAX));
+    assert!(0.0.ulps_eq(&-0.0, 0.0, 0));
+    assert!(!f64::NAN.ulps_eq(&f64::NAN, 0.0, u64::MAX));
+}
+
+#[test]
+fn vectors() {
+    assert!(Vec2(0.1 + 0.2, 1.0).approx_eq(&Vec2(0.3, 1.0)));
+    assert!(!Vec2(0.1, 1.0).approx_eq(&Vec2(0.1, 1.1)));
+    assert!(Vec3(1.0, 2.0, 3.0).abs_diff_eq(&Vec3(1.05, 2.0, 2.95), 0.1));
+    assert!(!Vec4(1.0, 2.0, 3.0, 4.0).abs_diff_eq(&Vec4(1.0, 2.0, 3.0, 4.2), 0.1));
+    assert!(!Vec3(f64::NAN, 0.0, 0.0).approx_eq(&Vec3(f64::NAN, 0.0, 0.0)));
+}
+
+#[test]
+fn matrices() {
+    let a = Matrix::from(vec![vec![0.1 + 0.2, 1.0], vec![2.0, 3.0]]);
+    let b = Matrix::from(vec![vec![0.3, 1.0], vec![2.0, 3.0]]);
+    assert_ne!(a, b);
+    assert!(a.approx_eq(&b));
+    assert!(!a.approx_eq(&Matrix::from(vec![
+        vec![0.3, 1.0, 0.0],
+        vec![2.0, 3.0, 0.0]
+    ])));
+    assert_approx_eq!(Mat3::from(Matrix::identity(3)), Mat3::IDENTITY);
+    assert_approx_eq!(Quat::from_euler(Vec3(0.0, 0.0, 0.0)), Quat::IDENTITY);
+}
+
+#[test]
+fn macros() {
+    assert_approx_eq!(0.1 + 0.2, 0.3);
+    assert_approx_eq!(Vec3(1.0, 1.0, 1.0), Vec3(1.05, 1.0, 1.0), 0.1);
+    assert_relative_eq!(Vec2(1000.0, 1.0), Vec2(1000.1, 1.0), 0.0, 1e-3);
+    assert_ulps_eq!(Vec2(0.1 + 0.2, 0.0), Vec2(0.3, 0.0), 0.0, 1);
+}
+
+#[test]
+#[should_panic]
+fn macros_fail() {
+    assert_approx_eq!(Vec2(1.0, 1.0), Vec2(1.2, 1.0), 0.1);
+}
@@ -0,0 +1,232 @@
+use crate::{assert_approx_eq, BSpline, Bezier, Curve, Vec2, Vec3, VecX, VecxError};
+
+fn quarter_arc() -> Bezier<Vec2> {
+    // classic cubic approximation of a quarter of the unit circle
+    let k = 0.551_915_024_494;
+    Bezier::cubic(Vec2(1.0, 0.0), Vec2(1.0, k), Vec2(k, 1.0), Vec2(0.0, 1.0))
+}
+
+#[test]
+fn bezier_evaluation() {
+    let quadratic = Bezier::quadratic(Vec2(0.0, 0.0), Vec2(1.0, 2.0), Vec2(2.0, 0.0));
+    assert_eq!(quadratic.degree(), 2);
+    assert_eq!(quadratic.at(0.0), Vec2(0.0, 0.0));
+    assert_eq!(quadratic.at(1.0), Vec2(2.0, 0.0));
+    assert_eq!(quadratic.at(0.5), Vec2(1.0, 1.0));
+    assert_eq!(quadratic.derivative_at(0.0), Vec2(2.0, 4.0));
+    assert_eq!(quadratic.derivative_at(0.5), Vec2(2.0, 0.0));
+    assert_eq!(quadratic.derivative().degree(), 1);
+
+    let cubic = quarter_arc();
+    for t in [0.1, 0.3, 0.5, 0.9] {
+        assert_approx_eq!(cubic.at(t).magnitude(), 1.0, 1e-3);
+    }
+
+    // a straight n-degree curve with evenly spaced points moves at constant speed
+    let line = Bezier::new(
+        (0..6)
+            .map(|i| Vec3(i as f64, 0.0, -2.0 * i as f64))
+            .collect(),
+    );
+    assert_eq!(line.degree(), 5);
+    assert_approx_eq!(line.at(0.3), Vec3(1.5, 0.0, -3.0));
+    assert_approx_eq!(line.derivative_at(0.7), Vec3(5.0, 0.0, -10.0));
+
+    // past 8 points the evaluation works on a copy instead of the stack
+    let line = Bezier::new((0..12).map(|i| Vec2(i as f64, 1.0)).collect());
+    assert_approx_eq!(line.at(0.5), Vec2(5.5, 1.0));
+    assert_approx_eq!(line.length(), 11.0);
+}
+
+#[test]
+fn bezier_constant() {
+    let point = Bezier::new(vec![Vec2(1.0, 2.0)]);
+    assert_eq!(point.degree(), 0);
+    assert_eq!(point.at(0.4), Vec2(1.0, 2.0));
+    assert_eq!(point.derivative_at(0.4), Vec2(0.0, 0.0));
+    assert_eq!(point.length(), 0.0);
+    assert_eq!(point.t_at_length(1.0), 0.0);
+    assert_eq!(
+        Bezier::<Vec2>::try_new(vec![]),
+        Err(VecxError::NotEnoughPoints {
+            required: 1,
+            len: 0
+        })
+    );
+}
+
+#[test]
+fn bezier_split() {
+    let cubic = Bezier::cubic(
+        Vec3(0.0, 0.0, 0.0),
+        Vec3(1.0, 3.0, 0.0),
+        Vec3(3.0, -1.0, 2.0),
+        Vec3(4.0, 0.0, 1.0),
+    );
+    let (left, right) = cubic.split(0.3);
+    assert_eq!(left.degree(), 3);
+    assert_eq!(right.degree(), 3);
+    assert_approx_eq!(left.points()[3], cubic.at(0.3));
+    assert_approx_eq!(right.points()[0], cubic.at(0.3));
+    for t in [0.0, 0.25, 0.5, 1.0] {
+        assert_approx_eq!(left.at(t), cubic.at(0.3 * t));
+        assert_approx_eq!(right.at(t), cubic.at(0.3 + 0.7 * t));
+    }
+    assert_approx_eq!(left.length() + right.length(), cubic.length());
+}
+
+#[test]
+fn arc_length() {
+    let line = Bezier::quadratic(Vec2(0.0, 0.0), Vec2(3.0, 4.0), Vec2(6.0, 8.0));
+    assert_approx_eq!(line.length(), 10.0);
+    assert_approx_eq!(line.length_between(0.0, 0.5), 5.0);
+
+    let arc = quarter_arc();
+    assert_approx_eq!(arc.length(), std::f64::consts::FRAC_PI_2, 1e-3);
+
+    // unevenly spaced control points, the parameter doesn't follow the length
+    let uneven = Bezier::quadratic(Vec2(0.0, 0.0), Vec2(1.0, 0.0), Vec2(10.0, 0.0));
+    assert_approx_eq!(uneven.length(), 10.0);
+    for distance in [0.0, 2.5, 5.0, 9.0, 10.0] {
+        assert_approx_eq!(uneven.at_length(distance), Vec2(distance, 0.0));
+    }
+    assert_eq!(uneven.at_length(-1.0), Vec2(0.0, 0.0));
+    assert_eq!(uneven.at_length(11.0), Vec2(10.0, 0.0));
+
+    let t = arc.t_at_length(1.0);
+    assert_approx_eq!(arc.length_between(0.0, t), 1.0);
+}
+
+#[test]
+fn bezier_bounding_box() {
+    let quadratic = Bezier::quadratic(Vec2(0.0, 0.0), Vec2(1.0, 2.0), Vec2(2.0, 0.0));
+    assert_eq!(quadratic.bounding_box(), (Vec2(0.0, 0.0), Vec2(2.0, 1.0)));
+
+    let s_curve = Bezier::cubic(
+        Vec2(0.0, 0.0),
+        Vec2(0.0, 3.0),
+        Vec2(1.0, -3.0),
+        Vec2(1.0, 0.0),
+    );
+    let (min, max) = s_curve.bounding_box();
+    // y = 9t(1 - t)(1 - 2t) peaks where 6t² - 6t + 1 = 0
+    let t = (3.0 - f64::sqrt(3.0)) / 6.0;
+    assert_approx_eq!(max.y(), 9.0 * t * (1.0 - t) * (1.0 - 2.0 * t));
+    assert_approx_eq!(min.y(), -max.y());
+    assert_eq!((min.x(), max.x()), (0.0, 1.0));
+}
+
+#[test]
+fn bspline_clamped() {
+    let points = vec![
+        Vec2(0.0, 0.0),
+        Vec2(1.0, 2.0),
+        Vec2(3.0, 2.0),
+        Vec2(4.0, 0.0),
+    ];
+    // a clamped cubic B-spline with 4 points is the cubic Bezier curve
+    let spline = BSpline::clamped(3, points.clone());
+    let bezier = Bezier::new(points.clone());
+    assert_eq!(spline.knots(), &[0.0, 0.0, 0.0, 0.0, 1.0, 1.0, 1.0, 1.0]);
+    assert_eq!(spline.domain(), (0.0, 1.0));
+    for t in [0.0, 0.2, 0.5, 0.8, 1.0] {
+        assert_approx_eq!(spline.at(t), bezier.at(t));
+        assert_approx_eq!(spline.derivative_at(t), bezier.derivative_at(t));
+    }
+    assert_approx_eq!(spline.length(), bezier.length());
+    assert_approx_eq!(spline.bounding_box().1, bezier.bounding_box().1);
+
+    let quadratic = BSpline::clamped(2, points);
+    assert_eq!(quadratic.knots(), &[0.0, 0.0, 0.0, 0.5, 1.0, 1.0, 1.0]);
+    assert_eq!(quadratic.spans(), vec![(0.0, 0.5), (0.5, 1.0)]);
+    assert_approx_eq!(quadratic.at(0.0), Vec2(0.0, 0.0));
+    assert_approx_eq!(quadratic.at(1.0), Vec2(4.0, 0.0));
+    assert_approx_eq!(quadratic.at(0.5), Vec2(2.0, 2.0));
+}
+
+#[test]
+fn bspline_uniform() {
+    let points: Vec<Vec3> = (0..5)
+        .map(|i| Vec3(i as f64, (i % 2) as f64, 0.0))
+        .collect();
+    let spline = BSpline::uniform(3, points.clone());
+    assert_eq!(spline.domain(), (3.0, 5.0));
+    assert_eq!(spline.spans().len(), 2);
+    // uniform cubic B-spline starts at (p0 + 4 p1 + p2) / 6
+    assert_approx_eq!(
+        spline.at(3.0),
+        (points[0] + points[1] * 4.0 + points[2]) / 6.0
+    );
+    assert_approx_eq!(
+        spline.at(5.0),
+        (points[2] + points[3] * 4.0 + points[4]) / 6.0
+    );
+    // clamped outside of the domain
+    assert_eq!(spline.at(-1.0), spline.at(3.0));
+
+    let linear = BSpline::uniform(1, points.clone());
+    assert_approx_eq!(linear.at(2.5), Vec3(1.5, 0.5, 0.0));
+    assert_approx_eq!(linear.length(), 4.0 * f64::sqrt(2.0));
+    assert_eq!(
+        linear.bounding_box(),
+        (Vec3(0.0, 0.0, 0.0), Vec3(4.0, 1.0, 0.0))
+    );
+}
+
+#[test]
+fn bspline_non_uniform() {
+    let points = vec![
+        Vec2(0.0, 0.0),
+        Vec2(1.0, 1.0),
+        Vec2(2.0, -1.0),
+        Vec2(3.0, 0.0),
+    ];
+    let knots = vec![0.0, 0.0, 0.0, 0.2, 1.0, 1.0, 1.0];
+    let spline = BSpline::new(2, points.clone(), knots.clone());
+    assert_approx_eq!(spline.at(0.0), Vec2(0.0, 0.0));
+    assert_approx_eq!(spline.at(1.0), Vec2(3.0, 0.0));
+    // the derivative matches finite differences and is continuous across the inner knot
+    assert_approx_eq!(spline.derivative_at(0.2 - 1e-12), spline.derivative_at(0.2));
+    for t in [0.1, 0.6, 0.9] {
+        let h = 1e-6;
+        let difference = (spline.at(t + h) - spline.at(t - h)) / (2.0 * h);
+        assert_approx_eq!(spline.derivative_at(t), difference, 1e-5);
+    }
+    assert_eq!(spline.derivative().degree(), 1);
+
+    assert_eq!(
+        BSpline::try_new(3, points.clone(), knots),
+        Err(VecxError::InvalidKnotCount {
+            expected: 8,
+            len: 7
+        })
+    );
+    assert_eq!(
+        BSpline::try_new(2, points.clone(), vec![0.0; 3]),
+        Err(VecxError::InvalidKnotCount {
+            expected: 7,
+            len: 3
+        })
+    );
+    assert_eq!(
+        BSpline::try_new(2, points.clone(), vec![0.0, 0.0, 0.0, 0.5, 0.2, 1.0, 1.0]),
+        Err(VecxError::InvalidKnots)
+    );
+    assert_eq!(
+        BSpline::try_new(2, points, vec![0.0; 7]),
+        Err(VecxError::InvalidKnots)
+    );
+    assert_eq!(
+        BSpline::try_new(3, vec![Vec2(0.0, 0.0)], vec![]),
+        Err(VecxError::NotEnoughPoints {
+            required: 4,
+            len: 1
+        })
+    );
+}
+
+#[test]
+#[should_panic]
+fn bspline_not_enough_points() {
+    BSpline::clamped(3, vec![Vec2(0.0, 0.0), Vec2(1.0, 0.0)]);
+}
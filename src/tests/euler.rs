@@ -0,0 +1,110 @@
+use std::f64::consts::FRAC_PI_2;
+
+use crate::{
+    assert_approx_eq, ApproxEq, EulerAngles, EulerOrder, Handedness, Mat3, Matrix, Quat, Vec3,
+};
+
+const HANDEDNESS: [Handedness; 2] = [Handedness::Left, Handedness::Right];
+
+fn assert_quat_near(a: Quat, b: Quat) {
+    assert!(a.approx_eq(&b) || a.approx_eq(&-b), "{} != {}", a, b);
+}
+
+#[test]
+fn right_handed_xyz_matches_quat_from_euler() {
+    let angles = Vec3(0.3, -0.7, 1.2);
+    let e = EulerAngles::new(angles, EulerOrder::XYZ, Handedness::Right);
+    assert_quat_near(e.to_quat(), Quat::from_euler(angles));
+}
+
+#[test]
+fn right_handed_matches_matrix_rotations() {
+    // m4_rotate_x and m4_rotate_y follow the right-hand rule, m4_rotate_z doesn't
+    let x = EulerAngles::new(Vec3(0.4, 0.0, 0.0), EulerOrder::XYZ, Handedness::Right);
+    assert_approx_eq!(x.to_matrix(), Matrix::m4_rotate_x(0.4));
+    let y = EulerAngles::new(Vec3(0.0, 0.4, 0.0), EulerOrder::XYZ, Handedness::Right);
+    assert_approx_eq!(y.to_matrix(), Matrix::m4_rotate_y(0.4));
+    let z = EulerAngles::new(Vec3(0.0, 0.0, 0.4), EulerOrder::XYZ, Handedness::Left);
+    assert_approx_eq!(z.to_matrix(), Matrix::m4_rotate_z(0.4));
+
+    let e = EulerAngles::new(Vec3(0.3, -0.7, 1.2), EulerOrder::ZYX, Handedness::Right);
+    let expected = Matrix::m4_rotate_x(0.3) * Matrix::m4_rotate_y(-0.7) * Matrix::m4_rotate_z(-1.2);
+    assert_approx_eq!(Matrix::from(e), expected);
+}
+
+#[test]
+fn left_handed_xyz_matches_vec3_rot() {
+    let angles = Vec3(0.3, -0.7, 1.2);
+    let v = Vec3(1.0, 2.0, -3.0);
+    let e = EulerAngles::new(angles, EulerOrder::XYZ, Handedness::Left);
+    assert_approx_eq!(e.to_mat3() * v, v.rot(&angles));
+    assert_approx_eq!(e.to_quat().rotate(v), v.rot(&angles));
+}
+
+#[test]
+fn order_is_application_order() {
+    // XYZ rotates around x first: x then y takes the y axis to the z axis
+    let e = EulerAngles::new(
+        Vec3(FRAC_PI_2, FRAC_PI_2, 0.0),
+        EulerOrder::XYZ,
+        Handedness::Right,
+    );
+    assert_approx_eq!(e.to_quat().rotate(Vec3(0.0, 1.0, 0.0)), Vec3(1.0, 0.0, 0.0));
+    let e = EulerAngles::new(
+        Vec3(FRAC_PI_2, FRAC_PI_2, 0.0),
+        EulerOrder::YXZ,
+        Handedness::Right,
+    );
+    assert_approx_eq!(e.to_quat().rotate(Vec3(0.0, 1.0, 0.0)), Vec3(0.0, 0.0, 1.0));
+}
+
+#[test]
+fn round_trip_all_orders() {
+    let angles = Vec3(0.3, -0.7, 1.2);
+    for order in EulerOrder::ALL {
+        for handedness in HANDEDNESS {
+            let e = EulerAngles::new(angles, order, handedness);
+            let from_mat = EulerAngles::from_mat3(e.to_mat3(), order, handedness);
+            assert_approx_eq!(from_mat.angles, angles);
+            assert_eq!(from_mat.order, order);
+            assert_eq!(from_mat.handedness, handedness);
+            let from_quat = EulerAngles::from_quat(e.to_quat(), order, handedness);
+            assert_approx_eq!(from_quat.angles, angles);
+        }
+    }
+}
+
+#[test]
+fn round_trip_gimbal_lock() {
+    for order in EulerOrder::ALL {
+        for handedness in HANDEDNESS {
+            let mut angles = Vec3(0.3, 0.5, 0.9);
+            angles[order.axes()[1]] = FRAC_PI_2;
+            let e = EulerAngles::new(angles, order, handedness);
+            let back = EulerAngles::from_mat3(e.to_mat3(), order, handedness);
+            // the angles aren't unique, the rotation is
+            assert_approx_eq!(back.angles[order.axes()[2]], 0.0);
+            assert_approx_eq!(back.to_mat3(), e.to_mat3(), 1e-9);
+        }
+    }
+}
+
+#[test]
+fn to_order() {
+    let e = EulerAngles::new(Vec3(0.3, -0.7, 1.2), EulerOrder::XYZ, Handedness::Right);
+    for order in EulerOrder::ALL {
+        for handedness in HANDEDNESS {
+            let other = e.to_order(order, handedness);
+            assert_quat_near(other.to_quat(), e.to_quat());
+        }
+    }
+    let left = e.to_order(EulerOrder::XYZ, Handedness::Left);
+    assert_approx_eq!(left.angles, -e.angles);
+}
+
+#[test]
+fn from() {
+    let e = EulerAngles::new(Vec3(0.3, -0.7, 1.2), EulerOrder::ZXY, Handedness::Left);
+    assert_quat_near(Quat::from(e), e.to_quat());
+    assert_approx_eq!(Mat3::from(e), e.to_quat().to_mat3());
+}
@@ -0,0 +1,121 @@
+use crate::{assert_approx_eq, LineFit, PlaneFit, Vec2, Vec3, VecX};
+
+#[test]
+fn line_exact() {
+    let points: Vec<Vec2> = (0..5)
+        .map(|i| Vec2(i as f64, 2.0 * i as f64 - 1.0))
+        .collect();
+    let line = LineFit::fit(&points).unwrap();
+    assert_approx_eq!(line.point, Vec2(2.0, 3.0));
+    assert_approx_eq!(line.direction, Vec2(1.0, 2.0).normalized());
+    assert_approx_eq!(line.rms(), 0.0);
+    assert_approx_eq!(line.project(Vec2(10.0, 19.0)), Vec2(10.0, 19.0));
+    assert_approx_eq!(
+        line.distance(Vec2(2.0, 3.0) + Vec2(-2.0, 1.0)),
+        f64::sqrt(5.0)
+    );
+}
+
+#[test]
+fn line_orthogonal_residuals() {
+    // symmetric noise across a diagonal line, vertical least squares would tilt it
+    let points = [
+        Vec2(0.0, 1.0),
+        Vec2(1.0, 0.0),
+        Vec2(2.0, 3.0),
+        Vec2(3.0, 2.0),
+    ];
+    let line = LineFit::fit(&points).unwrap();
+    assert_approx_eq!(line.point, Vec2(1.5, 1.5));
+    assert_approx_eq!(line.direction, Vec2(1.0, 1.0).normalized());
+    let distance = f64::sqrt(0.5);
+    assert_approx_eq!(
+        line.residuals.as_slice(),
+        [distance, distance, distance, distance].as_slice()
+    );
+    assert_approx_eq!(line.rms(), distance);
+}
+
+#[test]
+fn line_vertical_and_3d() {
+    let vertical = LineFit::fit(&[Vec2(1.0, 2.0), Vec2(1.0, 5.0), Vec2(1.0, -1.0)]).unwrap();
+    // from the first sample toward the last one
+    assert_approx_eq!(vertical.direction, Vec2(0.0, -1.0));
+    assert_approx_eq!(vertical.point, Vec2(1.0, 2.0));
+
+    let points: Vec<Vec3> = (0..4)
+        .map(|i| Vec3(1.0, 2.0, 3.0) + Vec3(2.0, -1.0, 2.0) * i as f64)
+        .collect();
+    let line = LineFit::fit(&points).unwrap();
+    assert_approx_eq!(line.direction, Vec3(2.0, -1.0, 2.0) / 3.0);
+    assert_approx_eq!(line.rms(), 0.0);
+
+    let two = LineFit::fit(&[Vec3(0.0, 0.0, 0.0), Vec3(0.0, 0.0, 2.0)]).unwrap();
+    assert_approx_eq!(two.direction, Vec3(0.0, 0.0, 1.0));
+    assert_approx_eq!(two.point, Vec3(0.0, 0.0, 1.0));
+}
+
+#[test]
+fn line_degenerate() {
+    assert_eq!(LineFit::<Vec2>::fit(&[]), None);
+    assert_eq!(LineFit::fit(&[Vec2(1.0, 2.0)]), None);
+    assert_eq!(LineFit::fit(&[Vec3(0.1, 0.2, 0.3); 3]), None);
+}
+
+#[test]
+fn plane_exact() {
+    let points: Vec<Vec3> = [(0.0, 0.0), (1.0, 0.0), (0.0, 1.0), (2.0, 3.0), (-1.0, 4.0)]
+        .iter()
+        .map(|&(x, y)| Vec3(x, y, 0.5 * x - 2.0 * y + 3.0))
+        .collect();
+    let plane = PlaneFit::fit(&points).unwrap();
+    assert_approx_eq!(plane.normal, Vec3(-0.5, 2.0, 1.0).normalized());
+    assert_approx_eq!(plane.rms(), 0.0);
+    assert_approx_eq!(plane.signed_distance(Vec3(0.0, 0.0, 3.0)), 0.0);
+}
+
+#[test]
+fn plane_noisy() {
+    let noise = [0.1, -0.1, -0.1, 0.1];
+    let points: Vec<Vec3> = [(0.0, 0.0), (1.0, 0.0), (0.0, 1.0), (1.0, 1.0)]
+        .iter()
+        .zip(noise)
+        .map(|(&(x, y), n)| Vec3(x, y, 1.0 + n))
+        .collect();
+    let plane = PlaneFit::fit(&points).unwrap();
+    assert_approx_eq!(plane.normal, Vec3(0.0, 0.0, 1.0));
+    assert_approx_eq!(plane.point, Vec3(0.5, 0.5, 1.0));
+    assert_approx_eq!(plane.residuals.as_slice(), noise.as_slice());
+    assert_approx_eq!(plane.rms(), 0.1);
+    assert_approx_eq!(plane.project(Vec3(0.0, 0.0, 5.0)), Vec3(0.0, 0.0, 1.0));
+}
+
+#[test]
+fn plane_vertical() {
+    // x = 2, which vertical least squares can't represent
+    let points = [
+        Vec3(2.0, 0.0, 0.0),
+        Vec3(2.0, 1.0, 0.0),
+        Vec3(2.0, 0.0, 1.0),
+        Vec3(2.0, 3.0, -2.0),
+    ];
+    let plane = PlaneFit::fit(&points).unwrap();
+    assert_approx_eq!(plane.normal, Vec3(1.0, 0.0, 0.0));
+    assert_approx_eq!(plane.signed_distance(Vec3(5.0, 1.0, 1.0)), 3.0);
+    assert_approx_eq!(plane.rms(), 0.0);
+}
+
+#[test]
+fn plane_degenerate() {
+    assert_eq!(
+        PlaneFit::fit(&[Vec3(0.0, 0.0, 1.0), Vec3(1.0, 0.0, 2.0)]),
+        None
+    );
+    let collinear = [
+        Vec3(0.0, 0.0, 1.0),
+        Vec3(1.0, 1.0, 2.0),
+        Vec3(2.0, 2.0, 3.0),
+        Vec3(3.0, 3.0, 4.0),
+    ];
+    assert_eq!(PlaneFit::fit(&collinear), None);
+}
@@ -0,0 +1,262 @@
+use crate::{assert_approx_eq, Aabb, Hit, Intersect, Plane, Ray, Sphere, Triangle, Vec3, VecX};
+
+fn assert_hit(hit: Option<Hit>, distance: f64, point: Vec3, normal: Vec3) {
+    let hit = hit.unwrap_or_else(|| panic!("expected a hit at {}", point));
+    assert_approx_eq!(hit.distance, distance);
+    assert_approx_eq!(hit.point, point);
+    assert_approx_eq!(hit.normal, normal);
+}
+
+#[test]
+fn ray() {
+    let ray = Ray::new(Vec3(1.0, 0.0, 0.0), Vec3(0.0, 0.0, 2.0));
+    assert_eq!(ray.direction, Vec3(0.0, 0.0, 1.0));
+    assert_eq!(ray.at(3.0), Vec3(1.0, 0.0, 3.0));
+}
+
+#[test]
+fn plane() {
+    let plane = Plane::new(Vec3(0.0, 2.0, 0.0), 4.0);
+    assert_approx_eq!(plane.normal, Vec3(0.0, 1.0, 0.0));
+    assert_approx_eq!(plane.distance, 2.0);
+    assert_approx_eq!(plane.signed_distance(Vec3(5.0, 5.0, 1.0)), 3.0);
+    assert_approx_eq!(plane.project(Vec3(5.0, 5.0, 1.0)), Vec3(5.0, 2.0, 1.0));
+
+    let from_points = Plane::from_points(
+        Vec3(0.0, 2.0, 0.0),
+        Vec3(0.0, 2.0, 1.0),
+        Vec3(1.0, 2.0, 0.0),
+    )
+    .unwrap();
+    assert_approx_eq!(from_points.normal, plane.normal);
+    assert_approx_eq!(from_points.distance, plane.distance);
+    assert_eq!(
+        Plane::from_points(
+            Vec3(0.0, 0.0, 0.0),
+            Vec3(1.0, 1.0, 1.0),
+            Vec3(2.0, 2.0, 2.0)
+        ),
+        None
+    );
+}
+
+#[test]
+fn ray_plane() {
+    let plane = Plane::from_point_normal(Vec3(0.0, 2.0, 0.0), Vec3(0.0, 1.0, 0.0));
+    let down = Ray::new(Vec3(1.0, 5.0, 0.0), Vec3(0.0, -1.0, 0.0));
+    assert_hit(
+        plane.intersect(&down),
+        3.0,
+        Vec3(1.0, 2.0, 0.0),
+        Vec3(0.0, 1.0, 0.0),
+    );
+    // from below the normal faces the ray origin
+    let up = Ray::new(Vec3(0.0, 0.0, 0.0), Vec3(0.0, 1.0, 1.0));
+    let sqrt2 = f64::sqrt(2.0);
+    assert_hit(
+        up.cast(&plane),
+        2.0 * sqrt2,
+        Vec3(0.0, 2.0, 2.0),
+        Vec3(0.0, -1.0, 0.0),
+    );
+
+    let away = Ray::new(Vec3(0.0, 5.0, 0.0), Vec3(0.0, 1.0, 0.0));
+    assert_eq!(plane.intersect(&away), None);
+    let parallel = Ray::new(Vec3(0.0, 5.0, 0.0), Vec3(1.0, 0.0, 0.0));
+    assert_eq!(plane.intersect(&parallel), None);
+}
+
+#[test]
+fn ray_sphere() {
+    let sphere = Sphere::new(Vec3(0.0, 0.0, 5.0), 2.0);
+    let ray = Ray::new(Vec3(0.0, 0.0, 0.0), Vec3(0.0, 0.0, 1.0));
+    assert_hit(
+        sphere.intersect(&ray),
+        3.0,
+        Vec3(0.0, 0.0, 3.0),
+        Vec3(0.0, 0.0, -1.0),
+    );
+
+    let inside = Ray::new(Vec3(0.0, 0.0, 5.0), Vec3(1.0, 0.0, 0.0));
+    assert!(sphere.contains(inside.origin));
+    assert_hit(
+        sphere.intersect(&inside),
+        2.0,
+        Vec3(2.0, 0.0, 5.0),
+        Vec3(1.0, 0.0, 0.0),
+    );
+
+    let tangent = Ray::new(Vec3(2.0, 0.0, 0.0), Vec3(0.0, 0.0, 1.0));
+    assert_hit(
+        sphere.intersect(&tangent),
+        5.0,
+        Vec3(2.0, 0.0, 5.0),
+        Vec3(1.0, 0.0, 0.0),
+    );
+
+    let miss = Ray::new(Vec3(2.1, 0.0, 0.0), Vec3(0.0, 0.0, 1.0));
+    assert_eq!(sphere.intersect(&miss), None);
+    let behind = Ray::new(Vec3(0.0, 0.0, 0.0), Vec3(0.0, 0.0, -1.0));
+    assert_eq!(sphere.intersect(&behind), None);
+}
+
+#[test]
+fn aabb() {
+    let aabb = Aabb::new(Vec3(1.0, -1.0, 3.0), Vec3(-1.0, 1.0, 1.0));
+    assert_eq!(aabb.min, Vec3(-1.0, -1.0, 1.0));
+    assert_eq!(aabb.max, Vec3(1.0, 1.0, 3.0));
+    assert_eq!(aabb.center(), Vec3(0.0, 0.0, 2.0));
+    assert_eq!(aabb.size(), Vec3(2.0, 2.0, 2.0));
+    assert!(aabb.contains(Vec3(1.0, 0.0, 2.0)));
+    assert!(!aabb.contains(Vec3(1.1, 0.0, 2.0)));
+
+    let points = [
+        Vec3(1.0, 2.0, 3.0),
+        Vec3(-1.0, 5.0, 0.0),
+        Vec3(0.0, 0.0, 4.0),
+    ];
+    let bounds = Aabb::from_points(&points).unwrap();
+    assert_eq!(bounds, Aabb::new(Vec3(-1.0, 0.0, 0.0), Vec3(1.0, 5.0, 4.0)));
+    assert_eq!(Aabb::from_points(&[]), None);
+}
+
+#[test]
+fn ray_aabb() {
+    let aabb = Aabb::new(Vec3(-1.0, -1.0, 1.0), Vec3(1.0, 1.0, 3.0));
+    let ray = Ray::new(Vec3(0.5, 0.5, 0.0), Vec3(0.0, 0.0, 1.0));
+    assert_hit(
+        aabb.intersect(&ray),
+        1.0,
+        Vec3(0.5, 0.5, 1.0),
+        Vec3(0.0, 0.0, -1.0),
+    );
+
+    let side = Ray::new(Vec3(-3.0, 0.0, 2.0), Vec3(1.0, 0.0, 0.0));
+    assert_hit(
+        aabb.intersect(&side),
+        2.0,
+        Vec3(-1.0, 0.0, 2.0),
+        Vec3(-1.0, 0.0, 0.0),
+    );
+
+    let diagonal = Ray::new(Vec3(3.0, 3.0, 2.0), Vec3(-1.0, -1.0, 0.0));
+    let sqrt2 = f64::sqrt(2.0);
+    let hit = aabb.intersect(&diagonal).unwrap();
+    assert_approx_eq!(hit.distance, 2.0 * sqrt2);
+    assert_approx_eq!(hit.point, Vec3(1.0, 1.0, 2.0));
+
+    let inside = Ray::new(Vec3(0.0, 0.0, 2.0), Vec3(0.0, 1.0, 0.0));
+    assert_hit(
+        aabb.intersect(&inside),
+        1.0,
+        Vec3(0.0, 1.0, 2.0),
+        Vec3(0.0, 1.0, 0.0),
+    );
+
+    let miss = Ray::new(Vec3(2.0, 0.0, 0.0), Vec3(0.0, 0.0, 1.0));
+    assert_eq!(aabb.intersect(&miss), None);
+    let skew_miss = Ray::new(Vec3(-3.0, 0.0, 0.0), Vec3(1.0, 0.0, 0.1));
+    assert_eq!(aabb.intersect(&skew_miss), None);
+    let behind = Ray::new(Vec3(0.0, 0.0, 5.0), Vec3(0.0, 0.0, 1.0));
+    assert_eq!(aabb.intersect(&behind), None);
+}
+
+#[test]
+fn triangle() {
+    let triangle = Triangle(
+        Vec3(0.0, 0.0, 0.0),
+        Vec3(2.0, 0.0, 0.0),
+        Vec3(0.0, 2.0, 0.0),
+    );
+    assert_eq!(triangle.normal(), Vec3(0.0, 0.0, 1.0));
+    assert_eq!(triangle.area(), 2.0);
+    assert_approx_eq!(triangle.centroid(), Vec3(2.0 / 3.0, 2.0 / 3.0, 0.0));
+}
+
+#[test]
+fn ray_triangle() {
+    let triangle = Triangle(
+        Vec3(0.0, 0.0, 0.0),
+        Vec3(2.0, 0.0, 0.0),
+        Vec3(0.0, 2.0, 0.0),
+    );
+    let front = Ray::new(Vec3(0.5, 0.5, 3.0), Vec3(0.0, 0.0, -1.0));
+    assert_hit(
+        triangle.intersect(&front),
+        3.0,
+        Vec3(0.5, 0.5, 0.0),
+        Vec3(0.0, 0.0, 1.0),
+    );
+    let back = Ray::new(Vec3(0.5, 0.5, -3.0), Vec3(0.0, 0.0, 1.0));
+    assert_hit(
+        triangle.intersect(&back),
+        3.0,
+        Vec3(0.5, 0.5, 0.0),
+        Vec3(0.0, 0.0, -1.0),
+    );
+
+    let slanted = Ray::new(Vec3(0.0, 0.0, 1.0), Vec3(1.0, 1.0, -1.0));
+    let hit = slanted.cast(&triangle).unwrap();
+    assert_approx_eq!(hit.point, Vec3(1.0, 1.0, 0.0));
+    assert_approx_eq!(hit.distance, f64::sqrt(3.0));
+
+    // outside the (1, 1) hypotenuse, then past each edge
+    for origin in [
+        Vec3(1.1, 1.0, 1.0),
+        Vec3(-0.1, 0.5, 1.0),
+        Vec3(0.5, -0.1, 1.0),
+    ] {
+        let ray = Ray::new(origin, Vec3(0.0, 0.0, -1.0));
+        assert_eq!(triangle.intersect(&ray), None);
+    }
+    let parallel = Ray::new(Vec3(-1.0, 0.5, 0.0), Vec3(1.0, 0.0, 0.0));
+    assert_eq!(triangle.intersect(&parallel), None);
+    let away = Ray::new(Vec3(0.5, 0.5, 3.0), Vec3(0.0, 0.0, 1.0));
+    assert_eq!(triangle.intersect(&away), None);
+}
+
+#[test]
+fn ray_tiny_triangle() {
+    // edges of 1e-7, so the determinant is 1e-14
+    let triangle = Triangle(
+        Vec3(0.0, 0.0, 0.0),
+        Vec3(1e-7, 0.0, 0.0),
+        Vec3(0.0, 1e-7, 0.0),
+    );
+    let ray = Ray::new(Vec3(2e-8, 2e-8, 1.0), Vec3(0.0, 0.0, -1.0));
+    assert_hit(
+        triangle.intersect(&ray),
+        1.0,
+        Vec3(2e-8, 2e-8, 0.0),
+        Vec3(0.0, 0.0, 1.0),
+    );
+    let parallel = Ray::new(Vec3(-1e-7, 2e-8, 0.0), Vec3(1.0, 0.0, 0.0));
+    assert_eq!(triangle.intersect(&parallel), None);
+
+    let degenerate = Triangle(
+        Vec3(0.0, 0.0, 0.0),
+        Vec3(1e-7, 0.0, 0.0),
+        Vec3(2e-7, 0.0, 0.0),
+    );
+    assert_eq!(degenerate.intersect(&ray), None);
+}
+
+#[test]
+fn triangle_agrees_with_plane() {
+    let triangle = Triangle(
+        Vec3(1.0, 0.0, 0.0),
+        Vec3(0.0, 1.0, 0.0),
+        Vec3(0.0, 0.0, 1.0),
+    );
+    let plane = Plane::from_points(triangle.0, triangle.1, triangle.2).unwrap();
+    let ray = Ray::new(Vec3(0.0, 0.0, 0.0), Vec3(1.0, 2.0, 3.0));
+    let (a, b) = (
+        triangle.intersect(&ray).unwrap(),
+        plane.intersect(&ray).unwrap(),
+    );
+    assert_approx_eq!(a.distance, b.distance);
+    assert_approx_eq!(a.point, b.point);
+    assert_approx_eq!(a.normal, b.normal);
+    assert_approx_eq!(a.normal, -triangle.normal());
+    assert_approx_eq!(a.point.dot_product(&Vec3(1.0, 1.0, 1.0)), 1.0);
+}
@@ -0,0 +1,92 @@
+use crate::{
+    assert_approx_eq, catmull_rom, hermite, smootherstep, smoothstep, Keyframe, Track, Vec2, Vec3,
+    Vec4,
+};
+
+#[test]
+fn easing() {
+    assert_eq!(smoothstep(0.0, 1.0, -1.0), 0.0);
+    assert_eq!(smoothstep(0.0, 1.0, 2.0), 1.0);
+    assert_eq!(smoothstep(0.0, 2.0, 1.0), 0.5);
+    assert_approx_eq!(smoothstep(0.0, 1.0, 0.25), 0.15625);
+    assert_eq!(smootherstep(0.0, 2.0, 1.0), 0.5);
+    assert_approx_eq!(smootherstep(0.0, 1.0, 0.25), 0.103515625);
+    // reversed edges go from 1.0 down to 0.0
+    assert_eq!(smoothstep(1.0, 0.0, 0.0), 1.0);
+}
+
+#[test]
+fn hermite_curve() {
+    let p0 = Vec2(0.0, 0.0);
+    let p1 = Vec2(1.0, 0.0);
+    assert_eq!(hermite(p0, Vec2(0.0, 1.0), p1, Vec2(0.0, -1.0), 0.0), p0);
+    assert_eq!(hermite(p0, Vec2(0.0, 1.0), p1, Vec2(0.0, -1.0), 1.0), p1);
+    assert_approx_eq!(
+        hermite(p0, Vec2(0.0, 1.0), p1, Vec2(0.0, -1.0), 0.5),
+        Vec2(0.5, 0.25)
+    );
+    // matching linear tangents give a straight line at constant speed
+    assert_approx_eq!(hermite(p0, p1, p1, p1, 0.3), Vec2(0.3, 0.0));
+}
+
+#[test]
+fn catmull_rom_curve() {
+    let points = [
+        Vec3(0.0, 0.0, 0.0),
+        Vec3(1.0, 1.0, 0.0),
+        Vec3(2.0, 0.0, 1.0),
+        Vec3(3.0, 1.0, 1.0),
+    ];
+    let [p0, p1, p2, p3] = points;
+    assert_eq!(catmull_rom(p0, p1, p2, p3, 0.0), p1);
+    assert_eq!(catmull_rom(p0, p1, p2, p3, 1.0), p2);
+    assert_approx_eq!(catmull_rom(p0, p1, p2, p3, 0.5), Vec3(1.5, 0.5, 0.5));
+}
+
+#[test]
+fn track_sampling() {
+    let track = Track::new(vec![
+        Keyframe::new(2.0, Vec2(2.0, 0.0)),
+        Keyframe::new(0.0, Vec2(0.0, 0.0)),
+        Keyframe::new(1.0, Vec2(1.0, 2.0)),
+    ]);
+    assert_eq!(track.keys()[0].time, 0.0);
+
+    assert_eq!(track.linear(-1.0), Some(Vec2(0.0, 0.0)));
+    assert_eq!(track.linear(0.5), Some(Vec2(0.5, 1.0)));
+    assert_eq!(track.linear(1.0), Some(Vec2(1.0, 2.0)));
+    assert_eq!(track.linear(5.0), Some(Vec2(2.0, 0.0)));
+
+    for time in [0.0, 1.0, 2.0] {
+        assert_eq!(track.catmull_rom(time), track.linear(time));
+        assert_eq!(track.hermite(time), track.linear(time));
+    }
+    // zero tangents ease in and out of every keyframe
+    assert_approx_eq!(track.hermite(0.5).unwrap(), Vec2(0.5, 1.0));
+    assert_approx_eq!(track.hermite(0.25).unwrap(), Vec2(0.15625, 0.3125));
+
+    assert_eq!(Track::<Vec2>::new(vec![]).linear(0.0), None);
+    let single = Track::new(vec![Keyframe::new(1.0, Vec4(1.0, 2.0, 3.0, 4.0))]);
+    assert_eq!(single.catmull_rom(3.0), Some(Vec4(1.0, 2.0, 3.0, 4.0)));
+}
+
+#[test]
+fn track_tangents() {
+    // evenly moving keyframes give a straight line at constant speed, even unevenly spaced
+    let track = Track::new(vec![
+        Keyframe::new(0.0, Vec3(0.0, 0.0, 0.0)),
+        Keyframe::new(1.0, Vec3(2.0, 0.0, 0.0)),
+        Keyframe::new(4.0, Vec3(8.0, 0.0, 0.0)),
+    ]);
+    for time in [0.3, 1.5, 2.5, 3.9] {
+        assert_approx_eq!(track.catmull_rom(time).unwrap(), Vec3(2.0 * time, 0.0, 0.0));
+    }
+
+    let velocity = Vec3(1.0, -1.0, 0.5);
+    let hermite = Track::new(vec![
+        Keyframe::with_tangent(0.0, Vec3(0.0, 0.0, 0.0), velocity),
+        Keyframe::with_tangent(2.0, velocity * 2.0, velocity),
+    ]);
+    assert_approx_eq!(hermite.hermite(0.5).unwrap(), velocity * 0.5);
+    assert_approx_eq!(hermite.hermite(1.5).unwrap(), velocity * 1.5);
+}
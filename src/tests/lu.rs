@@ -0,0 +1,152 @@
+use crate::{assert_approx_eq, Lu, Matrix, Vec2, Vec3, VecN, VecxError};
+
+fn sample() -> Matrix {
+    Matrix::from(vec![
+        vec![2.0, 1.0, 1.0],
+        vec![4.0, -6.0, 0.0],
+        vec![-2.0, 7.0, 2.0],
+    ])
+}
+
+fn hilbert(n: usize) -> Matrix {
+    Matrix::from(
+        (0..n)
+            .map(|row| (0..n).map(|col| 1.0 / (row + col + 1) as f64).collect())
+            .collect::<Vec<Vec<f64>>>(),
+    )
+}
+
+#[test]
+fn factors() {
+    let m = sample();
+    let lu = m.lu();
+    assert_approx_eq!(lu.p() * &m, lu.l() * lu.u());
+    // partial pivoting keeps every multiplier at or below 1
+    for row in 1..=3 {
+        for col in 1..row {
+            assert!(lu.l().get((row, col)).abs() <= 1.0);
+        }
+        for col in (row + 1)..=3 {
+            assert_eq!(lu.l().get((row, col)), 0.0);
+            assert_eq!(lu.u().get((col, row)), 0.0);
+        }
+    }
+    assert_approx_eq!(lu.determinant(), m.determinant());
+    assert_approx_eq!(lu.determinant(), -16.0);
+}
+
+#[test]
+fn solve_vector() {
+    let m = sample();
+    let x = Vec3(1.0, -2.0, 3.0);
+    let b = Vec3::from(&m * Matrix::from(x));
+    assert_approx_eq!(m.solve(&b), x);
+    assert_approx_eq!(m.lu().solve(&b), x);
+
+    let b: Vec<f64> = vec![b.0, b.1, b.2];
+    let solved = m.solve(&b);
+    assert_approx_eq!(solved.as_slice(), [1.0, -2.0, 3.0].as_slice());
+
+    let b = VecN([b[0], b[1], b[2]]);
+    assert_approx_eq!(m.solve(&b), VecN([1.0, -2.0, 3.0]));
+
+    let m2 = Matrix::from(vec![vec![0.0, 2.0], vec![3.0, 1.0]]);
+    assert_approx_eq!(m2.solve(&Vec2(4.0, 5.0)), Vec2(1.0, 2.0));
+}
+
+#[test]
+fn solve_matrix() {
+    let m = sample();
+    let x = Matrix::from(vec![vec![1.0, 0.5], vec![-2.0, 4.0], vec![3.0, -1.0]]);
+    let b = &m * &x;
+    assert_approx_eq!(m.solve(&b), x);
+    assert_approx_eq!(m.solve(&Matrix::identity(3)), m.inverse().unwrap());
+    // no columns, no systems to solve
+    assert_eq!(m.solve(&Matrix::new(3, 0)), Matrix::new(3, 0));
+}
+
+#[test]
+fn singular() {
+    let m = Matrix::from(vec![
+        vec![1.0, 2.0, 3.0],
+        vec![2.0, 4.0, 6.0],
+        vec![1.0, 0.0, 1.0],
+    ]);
+    let lu = m.lu();
+    assert!(lu.is_singular());
+    assert_eq!(lu.rcond(), 0.0);
+    assert_approx_eq!(lu.determinant(), 0.0);
+    assert_eq!(
+        m.try_solve(&Vec3(1.0, 2.0, 3.0)),
+        Err(VecxError::SingularMatrix)
+    );
+    assert_eq!(
+        Matrix::new(2, 2).try_solve(&Vec2(1.0, 2.0)),
+        Err(VecxError::SingularMatrix)
+    );
+}
+
+#[test]
+fn ill_conditioned() {
+    let well = hilbert(3).lu();
+    assert!(!well.is_singular());
+    assert!(well.rcond() > 1e-4);
+
+    let ill = hilbert(8);
+    let lu = ill.lu();
+    assert!(!lu.is_singular());
+    assert!(lu.rcond() < 1e-10);
+    assert_eq!(ill.try_solve(&vec![1.0; 8]), Err(VecxError::IllConditioned));
+}
+
+#[test]
+fn scale_invariant() {
+    let tiny = Matrix::from(vec![vec![2e-14, 1e-14], vec![1e-14, 3e-14]]);
+    assert!(!tiny.lu().is_singular());
+    assert_approx_eq!(tiny.solve(&Vec2(3e-14, 4e-14)), Vec2(1.0, 1.0));
+}
+
+#[test]
+fn invalid_dimensions() {
+    let m = sample();
+    assert_eq!(
+        m.try_solve(&Vec2(1.0, 2.0)),
+        Err(VecxError::InvalidRhs {
+            matrix: (3, 3),
+            rhs: (2, 1)
+        })
+    );
+    assert_eq!(
+        m.try_solve(&Matrix::new(4, 2)),
+        Err(VecxError::InvalidRhs {
+            matrix: (3, 3),
+            rhs: (4, 2)
+        })
+    );
+    assert_eq!(
+        m.try_solve(&Matrix::new(4, 0)),
+        Err(VecxError::InvalidRhs {
+            matrix: (3, 3),
+            rhs: (4, 0)
+        })
+    );
+    assert_eq!(
+        Matrix::new(2, 3).try_solve(&Vec2(1.0, 2.0)),
+        Err(VecxError::NotSquare { rows: 2, cols: 3 })
+    );
+}
+
+#[test]
+#[should_panic]
+fn lu_not_square() {
+    Matrix::new(2, 3).lu();
+}
+
+#[test]
+fn lu_try_new() {
+    assert!(Lu::try_new(&sample()).is_ok());
+    assert_eq!(
+        Lu::try_new(&Matrix::new(2, 3)).err(),
+        Some(VecxError::NotSquare { rows: 2, cols: 3 })
+    );
+}
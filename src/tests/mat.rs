@@ -1,4 +1,6 @@
-use crate::{Matrix, Vec3};
+use std::f64::consts::PI;
+
+use crate::{assert_approx_eq, DepthRange, Layout, Lu, Mat4, Matrix, Vec3, Vec4, VecX, VecxError};
 
 #[test]
 fn from_vec() {
@@ -92,3 +94,636 @@ fn matrix_chain_multiply() {
     let result = translate * scale * vec_mat4;
     assert_eq!(Vec3::from(result), Vec3(10.0 + 5.0, 3.0 + 10.0, 21.0 + 2.0));
 }
+
+#[test]
+fn transpose() {
+    let mat = Matrix::from(vec![vec![1.0, 2.0, 3.0], vec![4.0, 5.0, 6.0]]);
+    assert_eq!(
+        mat.transpose(),
+        Matrix::from(vec![vec![1.0, 4.0], vec![2.0, 5.0], vec![3.0, 6.0]])
+    );
+    assert_eq!(Matrix::id4().transpose(), Matrix::id4());
+    assert_eq!(
+        Matrix::m4_translate(Vec3(1.0, 2.0, 3.0))
+            .transpose()
+            .get_row(4),
+        vec![1.0, 2.0, 3.0, 1.0]
+    );
+    assert_eq!(mat.to_layout(Layout::ColMajor).transpose(), mat.transpose());
+    assert_eq!(Matrix::new(0, 3).transpose(), Matrix::new(3, 0));
+}
+
+#[test]
+fn determinant() {
+    assert_eq!(Matrix::id4().determinant(), 1.0);
+    assert_eq!(Matrix::sqr4().determinant(), 0.0);
+    assert_eq!(
+        Matrix::from(vec![vec![1.0, 2.0], vec![3.0, 4.0]]).determinant(),
+        -2.0
+    );
+    assert_eq!(Matrix::m4_scale(Vec3(2.0, 3.0, 4.0)).determinant(), 24.0);
+    assert_eq!(
+        Matrix::m4_translate(Vec3(5.0, -2.0, 7.0)).determinant(),
+        1.0
+    );
+    assert!((Matrix::m4_rotate_x(0.7).determinant() - 1.0).abs() < 1e-12);
+
+    // Rows swapped by pivoting
+    let mat = Matrix::from(vec![
+        vec![0.0, 2.0, 1.0],
+        vec![1.0, 0.0, 0.0],
+        vec![3.0, 1.0, 4.0],
+    ]);
+    assert!((mat.determinant() - -7.0).abs() < 1e-12);
+}
+
+#[test]
+fn inverse() {
+    assert_eq!(Matrix::id4().inverse(), Some(Matrix::id4()));
+    assert_eq!(Matrix::sqr4().inverse(), None);
+
+    let translate = Matrix::m4_translate(Vec3(5.0, -2.0, 7.0));
+    assert_approx_eq!(
+        &translate.inverse().unwrap(),
+        &Matrix::m4_translate(Vec3(-5.0, 2.0, -7.0)),
+    );
+
+    let scale = Matrix::m4_scale(Vec3(2.0, 4.0, 0.5));
+    assert_approx_eq!(
+        &scale.inverse().unwrap(),
+        &Matrix::m4_scale(Vec3(0.5, 0.25, 2.0)),
+    );
+
+    let rotate = Matrix::m4_rotate_y(1.2);
+    assert_approx_eq!(&rotate.inverse().unwrap(), &rotate.transpose());
+
+    let model = Matrix::m4_translate(Vec3(1.0, 2.0, 3.0))
+        * Matrix::m4_rotate_z(0.3)
+        * Matrix::m4_scale(Vec3(2.0, 2.0, 2.0));
+    assert_approx_eq!(&(&model * &model.inverse().unwrap()), &Matrix::id4());
+}
+
+#[test]
+fn inverse_small_scale() {
+    // well-conditioned, only scaled below SINGULAR_EPSILON
+    let tiny = Matrix::identity(4) * 1e-13;
+    assert_approx_eq!(tiny.determinant(), 1e-52, 1e-64);
+    let inverse = tiny.inverse().unwrap();
+    assert_approx_eq!(&inverse, &(Matrix::identity(4) * 1e13));
+
+    // Lu and Mat4 agree with Matrix
+    let lu = Lu::new(&tiny);
+    assert!(!lu.is_singular());
+    assert_approx_eq!(lu.determinant(), tiny.determinant(), 1e-64);
+    let mat4 = Mat4::from(tiny.clone()).inverse().unwrap();
+    assert_approx_eq!(&Matrix::from(mat4), &inverse);
+}
+
+#[test]
+fn inverse_singular() {
+    let mat = Matrix::from(vec![vec![1.0, 2.0], vec![2.0, 4.0]]);
+    assert_eq!(mat.determinant(), 0.0);
+    assert_eq!(mat.inverse(), None);
+}
+
+#[test]
+#[should_panic]
+fn inverse_not_square() {
+    Matrix::new(2, 3).inverse();
+}
+
+#[test]
+fn try_not_square() {
+    let m = Matrix::new(2, 3);
+    let not_square = VecxError::NotSquare { rows: 2, cols: 3 };
+    assert_eq!(m.try_determinant(), Err(not_square.clone()));
+    assert_eq!(m.try_inverse(), Err(not_square.clone()));
+    assert_eq!(m.try_pow(2), Err(not_square));
+
+    let singular = Matrix::from(vec![vec![1.0, 2.0], vec![2.0, 4.0]]);
+    assert_eq!(singular.try_determinant(), Ok(0.0));
+    assert_eq!(singular.try_inverse(), Err(VecxError::SingularMatrix));
+    assert_eq!(Matrix::identity(2).try_pow(3), Ok(Matrix::identity(2)));
+}
+
+fn project(projection: &Matrix, point: Vec3) -> Vec3 {
+    Vec4::from(projection * point.as_mat4(1.0)).perspective_divide()
+}
+
+#[test]
+fn perspective_lh() {
+    let near = 0.1;
+    let far = 100.0;
+    let zero_to_one = Matrix::m4_perspective(PI / 2.0, 2.0, near, far);
+    assert_eq!(
+        zero_to_one,
+        Matrix::m4_perspective_lh(PI / 2.0, 2.0, near, far, DepthRange::ZeroToOne)
+    );
+    assert_eq!(zero_to_one.get_row(4), vec![0.0, 0.0, 1.0, 0.0]);
+    assert_approx_eq!(
+        project(&zero_to_one, Vec3(0.0, 0.0, near)),
+        Vec3(0.0, 0.0, 0.0),
+    );
+    assert_approx_eq!(
+        project(&zero_to_one, Vec3(0.0, 0.0, far)),
+        Vec3(0.0, 0.0, 1.0),
+    );
+    // 90 degree fov: the frustum edge at depth z is at y = z and x = z * aspect
+    assert_approx_eq!(
+        project(&zero_to_one, Vec3(20.0, 10.0, 10.0)).s3("xyy"),
+        Vec3(1.0, 1.0, 1.0),
+    );
+
+    let neg_one_to_one =
+        Matrix::m4_perspective_lh(PI / 2.0, 2.0, near, far, DepthRange::NegOneToOne);
+    assert_approx_eq!(
+        project(&neg_one_to_one, Vec3(0.0, 0.0, near)),
+        Vec3(0.0, 0.0, -1.0),
+    );
+    assert_approx_eq!(
+        project(&neg_one_to_one, Vec3(0.0, 0.0, far)),
+        Vec3(0.0, 0.0, 1.0),
+    );
+}
+
+#[test]
+fn perspective_rh() {
+    let near = 1.0;
+    let far = 50.0;
+    let zero_to_one = Matrix::m4_perspective_rh(PI / 3.0, 1.0, near, far, DepthRange::ZeroToOne);
+    assert_eq!(zero_to_one.get_row(4), vec![0.0, 0.0, -1.0, 0.0]);
+    assert_approx_eq!(
+        project(&zero_to_one, Vec3(0.0, 0.0, -near)),
+        Vec3(0.0, 0.0, 0.0),
+    );
+    assert_approx_eq!(
+        project(&zero_to_one, Vec3(0.0, 0.0, -far)),
+        Vec3(0.0, 0.0, 1.0),
+    );
+
+    let neg_one_to_one =
+        Matrix::m4_perspective_rh(PI / 3.0, 1.0, near, far, DepthRange::NegOneToOne);
+    assert_approx_eq!(
+        project(&neg_one_to_one, Vec3(0.0, 0.0, -near)),
+        Vec3(0.0, 0.0, -1.0),
+    );
+    assert_approx_eq!(
+        project(&neg_one_to_one, Vec3(0.0, 0.0, -far)),
+        Vec3(0.0, 0.0, 1.0),
+    );
+}
+
+#[test]
+fn orthographic_lh() {
+    let zero_to_one = Matrix::m4_orthographic(-4.0, 4.0, -2.0, 2.0, 1.0, 11.0);
+    assert_eq!(
+        zero_to_one,
+        Matrix::m4_orthographic_lh(-4.0, 4.0, -2.0, 2.0, 1.0, 11.0, DepthRange::ZeroToOne)
+    );
+    assert_approx_eq!(
+        project(&zero_to_one, Vec3(-4.0, -2.0, 1.0)),
+        Vec3(-1.0, -1.0, 0.0),
+    );
+    assert_approx_eq!(
+        project(&zero_to_one, Vec3(4.0, 2.0, 11.0)),
+        Vec3(1.0, 1.0, 1.0),
+    );
+
+    let neg_one_to_one =
+        Matrix::m4_orthographic_lh(0.0, 8.0, 0.0, 4.0, 1.0, 11.0, DepthRange::NegOneToOne);
+    assert_approx_eq!(
+        project(&neg_one_to_one, Vec3(0.0, 0.0, 1.0)),
+        Vec3(-1.0, -1.0, -1.0),
+    );
+    assert_approx_eq!(
+        project(&neg_one_to_one, Vec3(4.0, 2.0, 6.0)),
+        Vec3(0.0, 0.0, 0.0),
+    );
+}
+
+#[test]
+fn orthographic_rh() {
+    let zero_to_one =
+        Matrix::m4_orthographic_rh(-4.0, 4.0, -2.0, 2.0, 1.0, 11.0, DepthRange::ZeroToOne);
+    assert_approx_eq!(
+        project(&zero_to_one, Vec3(-4.0, -2.0, -1.0)),
+        Vec3(-1.0, -1.0, 0.0),
+    );
+    assert_approx_eq!(
+        project(&zero_to_one, Vec3(4.0, 2.0, -11.0)),
+        Vec3(1.0, 1.0, 1.0),
+    );
+
+    let neg_one_to_one =
+        Matrix::m4_orthographic_rh(-4.0, 4.0, -2.0, 2.0, 1.0, 11.0, DepthRange::NegOneToOne);
+    assert_approx_eq!(
+        project(&neg_one_to_one, Vec3(0.0, 0.0, -1.0)),
+        Vec3(0.0, 0.0, -1.0),
+    );
+    assert_approx_eq!(
+        project(&neg_one_to_one, Vec3(0.0, 0.0, -11.0)),
+        Vec3(0.0, 0.0, 1.0),
+    );
+}
+
+#[test]
+fn look_at_lh() {
+    let view = Matrix::m4_look_at(
+        Vec3(0.0, 0.0, -10.0),
+        Vec3(0.0, 0.0, 0.0),
+        Vec3(0.0, 1.0, 0.0),
+    );
+    assert_eq!(
+        view,
+        Matrix::m4_look_at_lh(
+            Vec3(0.0, 0.0, -10.0),
+            Vec3(0.0, 0.0, 0.0),
+            Vec3(0.0, 1.0, 0.0)
+        )
+    );
+    assert_eq!(view, Matrix::m4_translate(Vec3(0.0, 0.0, 10.0)));
+
+    // Looking down +x from (5, 0, 0): the target ends up straight ahead on +z
+    let eye = Vec3(5.0, 0.0, 0.0);
+    let view = Matrix::m4_look_at_lh(eye, Vec3(10.0, 0.0, 0.0), Vec3(0.0, 1.0, 0.0));
+    assert_approx_eq!(
+        Vec3::from(&view * Vec3(10.0, 0.0, 0.0).as_mat4(1.0)),
+        Vec3(0.0, 0.0, 5.0),
+    );
+    assert_approx_eq!(Vec3::from(&view * eye.as_mat4(1.0)), Vec3(0.0, 0.0, 0.0));
+    assert_approx_eq!(
+        Vec3::from(&view * Vec3(5.0, 1.0, 0.0).as_mat4(1.0)),
+        Vec3(0.0, 1.0, 0.0),
+    );
+}
+
+#[test]
+fn look_at_rh() {
+    let view = Matrix::m4_look_at_rh(
+        Vec3(0.0, 0.0, 10.0),
+        Vec3(0.0, 0.0, 0.0),
+        Vec3(0.0, 1.0, 0.0),
+    );
+    assert_eq!(view, Matrix::m4_translate(Vec3(0.0, 0.0, -10.0)));
+
+    // Target ends up straight ahead on -z
+    let eye = Vec3(1.0, 2.0, 3.0);
+    let target = Vec3(4.0, -1.0, 7.0);
+    let view = Matrix::m4_look_at_rh(eye, target, Vec3(0.0, 1.0, 0.0));
+    let distance = (target - eye).magnitude();
+    assert_approx_eq!(
+        Vec3::from(&view * target.as_mat4(1.0)),
+        Vec3(0.0, 0.0, -distance),
+    );
+    assert!((view.determinant() - 1.0).abs() < 1e-12);
+}
+
+#[test]
+fn mul_vec4() {
+    let v = Vec4(2.0, 4.0, 3.0, 1.0);
+    assert_eq!(&Matrix::id4() * v, v);
+
+    let mut scaling = Matrix::id4();
+    scaling.set((1, 1), 2.0);
+    scaling.set((2, 2), 0.5);
+    scaling.set((3, 3), 3.0);
+    assert_eq!(&scaling * v, Vec4(4.0, 2.0, 9.0, 1.0));
+
+    let model = Matrix::m4_translate(Vec3(5.0, 10.0, 2.0)) * scaling;
+    assert_eq!(&model * v, Vec4::from(&model * Matrix::from(v)),);
+    assert_eq!(model * v, Vec4(9.0, 12.0, 11.0, 1.0));
+}
+
+#[test]
+#[should_panic]
+fn mul_vec4_not_4x4() {
+    let _ = Matrix::new(3, 4) * Vec4(1.0, 1.0, 1.0, 1.0);
+}
+
+#[test]
+fn try_mul_vec4() {
+    let v = Vec4(1.0, 2.0, 3.0, 1.0);
+    assert_eq!(Matrix::id4().try_multiply_vec4(v), Ok(v));
+    assert_eq!(
+        Matrix::new(3, 4).try_multiply_vec4(v),
+        Err(VecxError::Not4x4 { rows: 3, cols: 4 })
+    );
+}
+
+#[test]
+fn transform_point() {
+    let translate = Matrix::m4_translate(Vec3(1.0, 2.0, 3.0));
+    assert_eq!(
+        translate.transform_point(Vec3(1.0, 1.0, 1.0)),
+        Vec3(2.0, 3.0, 4.0)
+    );
+
+    let scale = Matrix::m4_scale(Vec3(2.0, 3.0, 4.0));
+    assert_eq!(
+        scale.transform_point(Vec3(1.0, 1.0, 1.0)),
+        Vec3(2.0, 3.0, 4.0)
+    );
+
+    // Projection divides by w
+    let projection = Matrix::m4_perspective(PI / 2.0, 1.0, 1.0, 10.0);
+    assert_approx_eq!(
+        projection.transform_point(Vec3(5.0, 5.0, 10.0)),
+        Vec3(0.5, 0.5, 1.0),
+    );
+
+    // a point on the eye plane has a w of 0
+    let eye_plane = Vec3(1.0, 2.0, 0.0);
+    assert_eq!(
+        projection.transform_point(eye_plane),
+        Vec3::from(projection.multiply_vec4(Vec4(1.0, 2.0, 0.0, 1.0)))
+    );
+    assert_eq!(
+        projection.try_transform_point(eye_plane),
+        Err(VecxError::PointAtInfinity)
+    );
+    assert_approx_eq!(
+        projection
+            .try_transform_point(Vec3(5.0, 5.0, 10.0))
+            .unwrap(),
+        Vec3(0.5, 0.5, 1.0),
+    );
+    assert_eq!(
+        translate.try_transform_point(Vec3(1.0, 1.0, 1.0)),
+        Ok(Vec3(2.0, 3.0, 4.0))
+    );
+    assert_eq!(
+        Matrix::new(3, 3).try_transform_point(eye_plane),
+        Err(VecxError::Not4x4 { rows: 3, cols: 3 })
+    );
+}
+
+#[test]
+fn transform_vector() {
+    let translate = Matrix::m4_translate(Vec3(1.0, 2.0, 3.0));
+    assert_eq!(
+        translate.transform_vector(Vec3(1.0, 1.0, 1.0)),
+        Vec3(1.0, 1.0, 1.0)
+    );
+
+    let model = translate * Matrix::m4_scale(Vec3(2.0, 3.0, 4.0));
+    assert_eq!(
+        model.transform_vector(Vec3(1.0, 1.0, 1.0)),
+        Vec3(2.0, 3.0, 4.0)
+    );
+}
+
+#[test]
+fn try_get_set() {
+    let mut mat = Matrix::id4();
+    assert_eq!(mat.try_get((2, 2)), Ok(1.0));
+    assert_eq!(
+        mat.try_get((5, 1)),
+        Err(VecxError::InvalidRowIndex {
+            row: 5,
+            rows: 4,
+            cols: 4
+        })
+    );
+    assert_eq!(
+        mat.try_get((1, 0)),
+        Err(VecxError::InvalidColIndex {
+            col: 0,
+            rows: 4,
+            cols: 4
+        })
+    );
+
+    assert_eq!(mat.try_set((1, 4), 3.0), Ok(()));
+    assert_eq!(mat.get((1, 4)), 3.0);
+    assert!(mat.try_set((0, 1), 3.0).is_err());
+
+    assert_eq!(mat.try_get_row(1), Ok(vec![1.0, 0.0, 0.0, 3.0]));
+    assert_eq!(mat.try_get_col(4), Ok(vec![3.0, 0.0, 0.0, 1.0]));
+    assert!(mat.try_get_row(5).is_err());
+    assert!(mat.try_get_col(5).is_err());
+}
+
+#[test]
+fn try_set_content() {
+    let mut mat = Matrix::new(2, 2);
+    assert_eq!(
+        mat.try_set_content(vec![vec![1.0, 2.0], vec![3.0, 4.0]]),
+        Ok(())
+    );
+    assert_eq!(
+        mat.try_set_content(vec![vec![1.0, 2.0, 3.0], vec![3.0, 4.0, 5.0]]),
+        Err(VecxError::InvalidContent {
+            content: (2, 3),
+            matrix: (2, 2)
+        })
+    );
+    assert_eq!(
+        mat.try_set_content(vec![vec![1.0, 2.0], vec![3.0]]),
+        Err(VecxError::InvalidContent {
+            content: (2, 1),
+            matrix: (2, 2)
+        })
+    );
+    assert!(mat.try_set_content(vec![]).is_err());
+    assert_eq!(mat, Matrix::from(vec![vec![1.0, 2.0], vec![3.0, 4.0]]));
+}
+
+#[test]
+fn try_multiply() {
+    let a = Matrix::new(2, 3);
+    let b = Matrix::new(2, 3);
+    assert_eq!(
+        Matrix::try_multiply(&a, &b),
+        Err(VecxError::InvalidMultiplication {
+            left: (2, 3),
+            right: (2, 3)
+        })
+    );
+    assert_eq!(
+        Matrix::try_multiply(&a, &b.transpose()),
+        Ok(Matrix::new(2, 2))
+    );
+}
+
+#[test]
+fn m4_rotate_axis() {
+    let x = Vec3(1.0, 0.0, 0.0);
+    assert_approx_eq!(Matrix::m4_rotate_axis(x, 0.7), Matrix::m4_rotate_x(0.7));
+    assert_approx_eq!(
+        Matrix::m4_rotate_axis(Vec3(0.0, 2.0, 0.0), 0.7),
+        Matrix::m4_rotate_y(0.7)
+    );
+    assert_approx_eq!(
+        Matrix::m4_rotate_axis(Vec3(0.0, 0.0, 1.0), 0.7),
+        Matrix::m4_rotate_z(-0.7)
+    );
+
+    let axis = Vec3(1.0, -2.0, 0.5);
+    let v = Vec3(3.0, 1.0, -2.0);
+    let rotate = Matrix::m4_rotate_axis(axis, 1.1);
+    assert_approx_eq!(rotate.transform_vector(v), v.rotate_around(&axis, 1.1));
+    assert_approx_eq!(rotate.transform_vector(axis), axis);
+    assert_approx_eq!(rotate.determinant(), 1.0);
+    assert_approx_eq!(rotate.transpose(), Matrix::m4_rotate_axis(axis, -1.1));
+}
+
+#[test]
+fn add_sub() {
+    let a = Matrix::from(vec![vec![1.0, 2.0, 3.0], vec![4.0, 5.0, 6.0]]);
+    let b = Matrix::from(vec![vec![0.5, -1.0, 2.0], vec![1.0, 0.0, -3.0]]);
+    let sum = Matrix::from(vec![vec![1.5, 1.0, 5.0], vec![5.0, 5.0, 3.0]]);
+    let difference = Matrix::from(vec![vec![0.5, 3.0, 1.0], vec![3.0, 5.0, 9.0]]);
+    assert_eq!(&a + &b, sum);
+    assert_eq!(a.clone() + b.clone(), sum);
+    assert_eq!(&a - &b, difference);
+    assert_eq!(a.clone() - &b, difference);
+
+    let mut m = a.clone();
+    m += &b;
+    assert_eq!(m, sum);
+    m -= b;
+    assert_eq!(m, a);
+}
+
+#[test]
+fn add_sub_dimension_mismatch() {
+    let a = Matrix::new(2, 3);
+    let b = Matrix::new(3, 2);
+    let mismatch = Err(VecxError::DimensionMismatch {
+        left: (2, 3),
+        right: (3, 2),
+    });
+    assert_eq!(a.try_add(&b), mismatch);
+    assert_eq!(a.try_sub(&b), mismatch);
+    assert_eq!(a.try_hadamard(&b), mismatch);
+}
+
+#[test]
+#[should_panic]
+fn add_dimension_mismatch_panics() {
+    let _ = Matrix::new(2, 3) + Matrix::new(3, 2);
+}
+
+#[test]
+fn neg() {
+    let a = Matrix::from(vec![vec![1.0, -2.0], vec![0.0, 4.0]]);
+    assert_eq!(-&a, Matrix::from(vec![vec![-1.0, 2.0], vec![-0.0, -4.0]]));
+    assert_eq!(-(-a.clone()), a);
+}
+
+#[test]
+fn scalar_mul_div() {
+    let a = Matrix::from(vec![vec![1.0, -2.0], vec![3.0, 4.0]]);
+    let doubled = Matrix::from(vec![vec![2.0, -4.0], vec![6.0, 8.0]]);
+    assert_eq!(&a * 2.0, doubled);
+    assert_eq!(2.0 * &a, doubled);
+    assert_eq!(a.clone() * 2.0, doubled);
+    assert_eq!(&doubled / 2.0, a);
+
+    let mut m = a.clone();
+    m *= 2.0;
+    assert_eq!(m, doubled);
+    m /= 2.0;
+    assert_eq!(m, a);
+}
+
+#[test]
+fn hadamard() {
+    let a = Matrix::from(vec![vec![1.0, 2.0], vec![3.0, 4.0]]);
+    let b = Matrix::from(vec![vec![5.0, 6.0], vec![7.0, 8.0]]);
+    assert_eq!(
+        a.hadamard(&b),
+        Matrix::from(vec![vec![5.0, 12.0], vec![21.0, 32.0]])
+    );
+    assert_eq!(a.hadamard(&b), b.hadamard(&a));
+}
+
+#[test]
+fn pow() {
+    let fibonacci = Matrix::from(vec![vec![1.0, 1.0], vec![1.0, 0.0]]);
+    assert_eq!(fibonacci.pow(0), Matrix::identity(2));
+    assert_eq!(fibonacci.pow(1), fibonacci);
+    assert_eq!(
+        fibonacci.pow(10),
+        Matrix::from(vec![vec![89.0, 55.0], vec![55.0, 34.0]])
+    );
+    let rotation = Matrix::m4_rotate_x(PI / 8.0);
+    assert_approx_eq!(rotation.pow(5), Matrix::m4_rotate_x(5.0 * PI / 8.0));
+    assert_approx_eq!(rotation.pow(16), Matrix::id4());
+}
+
+#[test]
+#[should_panic]
+fn pow_not_square() {
+    Matrix::new(2, 3).pow(2);
+}
+
+#[test]
+fn flat_storage() {
+    let m = Matrix::from(vec![vec![1.0, 2.0, 3.0], vec![4.0, 5.0, 6.0]]);
+    assert_eq!(m.layout(), Layout::RowMajor);
+    assert_eq!(m.as_slice(), &[1.0, 2.0, 3.0, 4.0, 5.0, 6.0]);
+    assert_eq!((m.row_stride(), m.col_stride()), (3, 1));
+
+    let col_major = m.to_layout(Layout::ColMajor);
+    assert_eq!(col_major.layout(), Layout::ColMajor);
+    assert_eq!(col_major.as_slice(), &[1.0, 4.0, 2.0, 5.0, 3.0, 6.0]);
+    assert_eq!((col_major.row_stride(), col_major.col_stride()), (1, 2));
+    assert_eq!(col_major, m);
+    assert_eq!(col_major.get((2, 1)), 4.0);
+    assert_eq!(col_major.get_row(1), vec![1.0, 2.0, 3.0]);
+    assert_eq!(col_major.get_col(3), vec![3.0, 6.0]);
+    assert_eq!(
+        col_major.to_layout(Layout::RowMajor).as_slice(),
+        m.as_slice()
+    );
+}
+
+#[test]
+fn from_slice() {
+    let data = [1.0, 4.0, 2.0, 5.0, 3.0, 6.0];
+    let m = Matrix::from_slice(2, 3, Layout::ColMajor, &data);
+    assert_eq!(
+        m,
+        Matrix::from(vec![vec![1.0, 2.0, 3.0], vec![4.0, 5.0, 6.0]])
+    );
+    assert_eq!(
+        Matrix::try_from_slice(2, 2, Layout::RowMajor, &data),
+        Err(VecxError::InvalidDataLength {
+            len: 6,
+            matrix: (2, 2)
+        })
+    );
+
+    let mut m = Matrix::with_layout(2, 2, Layout::ColMajor);
+    m.set_content(vec![vec![1.0, 2.0], vec![3.0, 4.0]]);
+    assert_eq!(m.as_slice(), &[1.0, 3.0, 2.0, 4.0]);
+    m.as_mut_slice()[1] = 5.0;
+    assert_eq!(m.get((2, 1)), 5.0);
+}
+
+#[test]
+fn row_col_iter() {
+    let m = Matrix::from(vec![vec![1.0, 2.0], vec![3.0, 4.0], vec![5.0, 6.0]]);
+    assert_eq!(m.row_iter(2).collect::<Vec<_>>(), vec![3.0, 4.0]);
+    assert_eq!(m.col_iter(2).sum::<f64>(), 12.0);
+    assert!(m.try_row_iter(4).is_err());
+    assert!(m.try_col_iter(0).is_err());
+}
+
+#[test]
+fn mul_mixed_layouts() {
+    let a = Matrix::from(vec![vec![1.0, 2.0, 3.0], vec![4.0, 5.0, 6.0]]);
+    let b = Matrix::from(vec![vec![7.0, 8.0], vec![9.0, 10.0], vec![11.0, 12.0]]);
+    let expected = Matrix::from(vec![vec![58.0, 64.0], vec![139.0, 154.0]]);
+    for a_layout in [Layout::RowMajor, Layout::ColMajor] {
+        for b_layout in [Layout::RowMajor, Layout::ColMajor] {
+            let product = a.to_layout(a_layout) * b.to_layout(b_layout);
+            assert_eq!(product.layout(), Layout::RowMajor);
+            assert_eq!(product, expected);
+        }
+    }
+    let col_major = a.to_layout(Layout::ColMajor);
+    assert_eq!(&col_major + &a, &a * 2.0);
+    assert_eq!((&col_major * 2.0).layout(), Layout::ColMajor);
+}
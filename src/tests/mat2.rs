@@ -0,0 +1,40 @@
+use std::f64::consts::PI;
+
+use crate::{assert_approx_eq, Mat2, Matrix, Vec2};
+
+#[test]
+fn identity() {
+    assert_eq!(Mat2::default(), Mat2::IDENTITY);
+    assert_eq!(Mat2::IDENTITY * Vec2(1.0, 2.0), Vec2(1.0, 2.0));
+}
+
+#[test]
+fn mul() {
+    let a = Mat2::from_rows(Vec2(1.0, 2.0), Vec2(3.0, 4.0));
+    let b = Mat2::from_rows(Vec2(5.0, 6.0), Vec2(7.0, 8.0));
+    assert_eq!(a * b, Mat2(Vec2(19.0, 22.0), Vec2(43.0, 50.0)));
+    assert_eq!(Matrix::from(a * b), Matrix::from(a) * Matrix::from(b));
+    assert_eq!(Mat2::scale(Vec2(2.0, 3.0)) * Vec2(1.0, 1.0), Vec2(2.0, 3.0));
+}
+
+#[test]
+fn rotate() {
+    let v = Mat2::rotate(PI / 2.0) * Vec2(1.0, 0.0);
+    assert!(v.x().abs() < 1e-12);
+    assert!((v.y() - -1.0).abs() < 1e-12);
+}
+
+#[test]
+fn determinant_and_inverse() {
+    let a = Mat2::from_rows(Vec2(1.0, 2.0), Vec2(3.0, 4.0));
+    assert_eq!(a.determinant(), -2.0);
+    assert_eq!(a.inverse(), Some(Mat2(Vec2(-2.0, 1.0), Vec2(1.5, -0.5))));
+    assert_eq!(Mat2(Vec2(1.0, 2.0), Vec2(2.0, 4.0)).inverse(), None);
+    assert_eq!(Mat2::from(Matrix::from(a)), a);
+}
+
+#[test]
+fn inverse_small_scale() {
+    let m = Mat2::scale(Vec2(1e-7, 1e-7));
+    assert_approx_eq!(m.inverse().unwrap(), Mat2::scale(Vec2(1e7, 1e7)));
+}
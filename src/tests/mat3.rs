@@ -0,0 +1,67 @@
+use crate::{assert_approx_eq, Mat3, Matrix, Vec3};
+
+#[test]
+fn identity() {
+    assert_eq!(Mat3::default(), Mat3::IDENTITY);
+    assert_eq!(Mat3::IDENTITY * Vec3(1.0, 2.0, 3.0), Vec3(1.0, 2.0, 3.0));
+}
+
+#[test]
+fn into_matrix() {
+    let m = Mat3::from_rows(
+        Vec3(1.0, 2.0, 3.0),
+        Vec3(4.0, 5.0, 6.0),
+        Vec3(7.0, 8.0, 10.0),
+    );
+    assert_eq!(Mat3::from(Matrix::from(m)), m);
+    assert_eq!(Mat3::from_cols(m.col(0), m.col(1), m.col(2)), m);
+}
+
+#[test]
+fn mul() {
+    let a = Mat3::from_rows(
+        Vec3(1.0, 2.0, 3.0),
+        Vec3(4.0, 5.0, 6.0),
+        Vec3(7.0, 8.0, 10.0),
+    );
+    let b = Mat3::rotate_y(0.5) * Mat3::scale(Vec3(1.0, 2.0, 3.0));
+    assert_eq!(Matrix::from(a * b), Matrix::from(a) * Matrix::from(b));
+    assert_eq!(a * Vec3(1.0, 0.0, -1.0), Vec3(-2.0, -2.0, -3.0));
+}
+
+#[test]
+fn determinant_and_inverse() {
+    let m = Mat3::from_rows(
+        Vec3(1.0, 2.0, 3.0),
+        Vec3(4.0, 5.0, 6.0),
+        Vec3(7.0, 8.0, 10.0),
+    );
+    assert!((m.determinant() - -3.0).abs() < 1e-12);
+
+    let identity = m * m.inverse().unwrap();
+    for row in 0..3 {
+        assert!((identity[row] - Mat3::IDENTITY[row]).x().abs() < 1e-12);
+        assert!((identity[row] - Mat3::IDENTITY[row]).y().abs() < 1e-12);
+        assert!((identity[row] - Mat3::IDENTITY[row]).z().abs() < 1e-12);
+    }
+
+    let singular = Mat3::from_rows(
+        Vec3(1.0, 2.0, 3.0),
+        Vec3(2.0, 4.0, 6.0),
+        Vec3(7.0, 8.0, 10.0),
+    );
+    assert_eq!(singular.inverse(), None);
+}
+
+#[test]
+fn inverse_small_scale() {
+    let m = Mat3::scale(Vec3(1e-6, 1e-6, 1e-6));
+    assert_approx_eq!(m.inverse().unwrap(), Mat3::scale(Vec3(1e6, 1e6, 1e6)));
+    // rows 1e-6 apart from being parallel relative to their size
+    let almost = Mat3(
+        Vec3(1e-6, 0.0, 0.0),
+        Vec3(1e-6, 1e-20, 0.0),
+        Vec3(0.0, 0.0, 1e-6),
+    );
+    assert_eq!(almost.inverse(), None);
+}
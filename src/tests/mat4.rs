@@ -0,0 +1,147 @@
+use crate::{assert_approx_eq, Mat3, Mat4, Matrix, Vec3, Vec4, VecxError};
+
+#[test]
+fn identity() {
+    assert_eq!(Mat4::default(), Mat4::IDENTITY);
+    assert_eq!(Mat4::identity(), Mat4::from(Matrix::id4()));
+    assert_eq!(Mat4::zero(), Mat4::from(Matrix::sqr4()));
+}
+
+#[test]
+fn rows_and_cols() {
+    let m = Mat4::translate(Vec3(1.0, 2.0, 3.0));
+    assert_eq!(m.row(0), Vec4(1.0, 0.0, 0.0, 1.0));
+    assert_eq!(m.col(3), Vec4(1.0, 2.0, 3.0, 1.0));
+    assert_eq!(Mat4::from_cols(m.col(0), m.col(1), m.col(2), m.col(3)), m);
+    assert_eq!(m.transpose().row(3), m.col(3));
+}
+
+#[test]
+fn constructors_match_matrix() {
+    let v = Vec3(2.0, -3.0, 0.5);
+    assert_eq!(Mat4::scale(v), Mat4::from(Matrix::m4_scale(v)));
+    assert_eq!(Mat4::translate(v), Mat4::from(Matrix::m4_translate(v)));
+    assert_eq!(Mat4::rotate_x(0.3), Mat4::from(Matrix::m4_rotate_x(0.3)));
+    assert_eq!(Mat4::rotate_y(0.3), Mat4::from(Matrix::m4_rotate_y(0.3)));
+    assert_eq!(Mat4::rotate_z(0.3), Mat4::from(Matrix::m4_rotate_z(0.3)));
+}
+
+#[test]
+fn into_matrix() {
+    let m = Mat4::translate(Vec3(1.0, 2.0, 3.0)) * Mat4::rotate_y(1.1);
+    assert_eq!(Mat4::from(Matrix::from(m)), m);
+}
+
+#[test]
+#[should_panic]
+fn from_bad_matrix() {
+    let _ = Mat4::from(Matrix::new(3, 4));
+}
+
+#[test]
+fn mul() {
+    let scale = Mat4::scale(Vec3(2.0, 0.5, 3.0));
+    let translate = Mat4::translate(Vec3(5.0, 10.0, 2.0));
+    let v = Vec4(5.0, 6.0, 7.0, 1.0);
+
+    assert_eq!(translate * scale * v, Vec4(15.0, 13.0, 23.0, 1.0));
+    assert_eq!(
+        Matrix::from(translate * scale),
+        Matrix::m4_translate(Vec3(5.0, 10.0, 2.0)) * Matrix::m4_scale(Vec3(2.0, 0.5, 3.0))
+    );
+    assert_eq!(scale * 2.0, 2.0 * scale);
+    assert_eq!((scale * 2.0)[0], Vec4(4.0, 0.0, 0.0, 0.0));
+
+    let mut m = translate;
+    m *= scale;
+    assert_eq!(m, translate * scale);
+    m *= 0.0;
+    assert_eq!(m, Mat4::ZERO);
+}
+
+#[test]
+fn add_sub_neg() {
+    let a = Mat4::scale(Vec3(1.0, 2.0, 3.0));
+    assert_eq!(a + a, a * 2.0);
+    assert_eq!(a - a, Mat4::ZERO);
+    assert_eq!(-a, a * -1.0);
+
+    let mut b = a;
+    b += a;
+    b -= a;
+    assert_eq!(b, a);
+}
+
+#[test]
+fn determinant() {
+    assert_eq!(Mat4::IDENTITY.determinant(), 1.0);
+    assert_eq!(Mat4::scale(Vec3(2.0, 3.0, 4.0)).determinant(), 24.0);
+
+    let m = Mat4::translate(Vec3(1.0, 2.0, 3.0))
+        * Mat4::rotate_x(0.4)
+        * Mat4::scale(Vec3(2.0, 1.0, 5.0));
+    assert!((m.determinant() - Matrix::from(m).determinant()).abs() < 1e-9);
+}
+
+#[test]
+fn inverse() {
+    assert_eq!(Mat4::IDENTITY.inverse(), Some(Mat4::IDENTITY));
+    assert_eq!(Mat4::ZERO.inverse(), None);
+
+    let m = Mat4::translate(Vec3(1.0, 2.0, 3.0))
+        * Mat4::rotate_z(0.4)
+        * Mat4::scale(Vec3(2.0, 1.0, 5.0));
+    let inverse = m.inverse().unwrap();
+    assert_approx_eq!(m * inverse, Mat4::IDENTITY);
+    assert_approx_eq!(inverse, Mat4::from(Matrix::from(m).inverse().unwrap()));
+}
+
+#[test]
+fn inverse_small_scale() {
+    // det is 1e-15, invertible all the same
+    let m = Mat4::scale(Vec3(1e-5, 1e-5, 1e-5));
+    let inverse = m.inverse().unwrap();
+    assert_approx_eq!(inverse, Mat4::scale(Vec3(1e5, 1e5, 1e5)));
+    assert_approx_eq!(inverse, Mat4::from(Matrix::from(m).inverse().unwrap()));
+
+    let singular = Mat4::scale(Vec3(1e-5, 1e-5, 0.0));
+    assert_eq!(singular.inverse(), None);
+}
+
+#[test]
+fn transform() {
+    let m = Mat4::translate(Vec3(1.0, 2.0, 3.0)) * Mat4::scale(Vec3(2.0, 2.0, 2.0));
+    assert_eq!(m.transform_point(Vec3(1.0, 1.0, 1.0)), Vec3(3.0, 4.0, 5.0));
+    assert_eq!(m.transform_vector(Vec3(1.0, 1.0, 1.0)), Vec3(2.0, 2.0, 2.0));
+    assert_eq!(
+        m.try_transform_point(Vec3(1.0, 1.0, 1.0)),
+        Ok(Vec3(3.0, 4.0, 5.0))
+    );
+
+    // w = z, so a point with z = 0 is at infinity
+    let projective = Mat4::from_rows(
+        Vec4(1.0, 0.0, 0.0, 0.0),
+        Vec4(0.0, 1.0, 0.0, 0.0),
+        Vec4(0.0, 0.0, 1.0, 0.0),
+        Vec4(0.0, 0.0, 1.0, 0.0),
+    );
+    assert_eq!(
+        projective.transform_point(Vec3(2.0, 4.0, 2.0)),
+        Vec3(1.0, 2.0, 1.0)
+    );
+    assert_eq!(
+        projective.transform_point(Vec3(2.0, 4.0, 0.0)),
+        Vec3(2.0, 4.0, 0.0)
+    );
+    assert_eq!(
+        projective.try_transform_point(Vec3(2.0, 4.0, 0.0)),
+        Err(VecxError::PointAtInfinity)
+    );
+}
+
+#[test]
+fn mat3_conversion() {
+    let rotation = Mat4::rotate_x(0.7);
+    assert_eq!(Mat3::from(rotation), Mat3::rotate_x(0.7));
+    assert_eq!(Mat4::from(Mat3::from(rotation)), rotation);
+}
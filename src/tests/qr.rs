@@ -0,0 +1,91 @@
+use crate::{assert_approx_eq, Matrix, Vec2, Vec3, VecxError};
+
+fn tall() -> Matrix {
+    Matrix::from(vec![
+        vec![12.0, -51.0, 4.0],
+        vec![6.0, 167.0, -68.0],
+        vec![-4.0, 24.0, -41.0],
+        vec![1.0, 2.0, 3.0],
+    ])
+}
+
+#[test]
+fn factors() {
+    for m in [
+        tall(),
+        tall().transpose(),
+        Matrix::from(vec![vec![0.0, 1.0], vec![0.0, 2.0]]),
+    ] {
+        let qr = m.qr();
+        let (q, r) = (qr.q(), qr.r());
+        assert_eq!((q.rows(), q.cols()), (m.rows(), m.rows()));
+        assert_eq!((r.rows(), r.cols()), (m.rows(), m.cols()));
+        assert_approx_eq!(&q * &r, m, 1e-9);
+        assert_approx_eq!(q.transpose() * &q, Matrix::identity(m.rows()));
+        for row in 2..=r.rows() {
+            for col in 1..row.min(r.cols() + 1) {
+                assert_eq!(r.get((row, col)), 0.0);
+            }
+        }
+    }
+}
+
+#[test]
+fn least_squares_exact() {
+    // a consistent system is solved exactly, even when over-determined
+    let m = tall();
+    let x = vec![1.0, -2.0, 0.5];
+    let b = (&m * Matrix::from(vec![vec![1.0], vec![-2.0], vec![0.5]])).get_col(1);
+    let solved = m.least_squares(&b);
+    assert_approx_eq!(solved.as_slice(), x.as_slice());
+
+    let square = Matrix::from(vec![vec![0.0, 2.0], vec![3.0, 1.0]]);
+    assert_approx_eq!(square.least_squares(&Vec2(4.0, 5.0)), Vec2(1.0, 2.0));
+}
+
+#[test]
+fn least_squares_normal_equations() {
+    let m = Matrix::from(vec![
+        vec![1.0, 1.0],
+        vec![1.0, 2.0],
+        vec![1.0, 3.0],
+        vec![1.0, 4.0],
+    ]);
+    let b = Matrix::from(vec![
+        vec![6.0, 1.0],
+        vec![5.0, 2.0],
+        vec![7.0, 2.0],
+        vec![10.0, 3.0],
+    ]);
+    let x = m.least_squares(&b);
+    assert_eq!((x.rows(), x.cols()), (2, 2));
+    // normal equations, the residual is orthogonal to the columns of m
+    let mt = m.transpose();
+    assert_approx_eq!(&mt * &(&m * &x), &mt * &b, 1e-9);
+    assert_approx_eq!(x.get_col(1).as_slice(), [3.5, 1.4].as_slice());
+    assert_eq!(m.least_squares(&Matrix::new(4, 0)), Matrix::new(2, 0));
+}
+
+#[test]
+fn least_squares_errors() {
+    let m = Matrix::from(vec![vec![1.0, 2.0, 3.0], vec![4.0, 5.0, 6.0]]);
+    assert_eq!(
+        m.try_least_squares(&Vec2(1.0, 2.0)),
+        Err(VecxError::Underdetermined { rows: 2, cols: 3 })
+    );
+
+    let dependent = Matrix::from(vec![vec![1.0, 2.0], vec![2.0, 4.0], vec![3.0, 6.0]]);
+    assert!(dependent.qr().is_rank_deficient());
+    assert_eq!(
+        dependent.try_least_squares(&Vec3(1.0, 2.0, 3.0)),
+        Err(VecxError::RankDeficient)
+    );
+
+    assert_eq!(
+        tall().try_least_squares(&Vec3(1.0, 2.0, 3.0)),
+        Err(VecxError::InvalidRhs {
+            matrix: (4, 3),
+            rhs: (3, 1)
+        })
+    );
+}
@@ -0,0 +1,149 @@
+use std::f64::consts::PI;
+
+use crate::{assert_approx_eq, ApproxEq, Mat3, Mat4, Matrix, Quat, Vec3, VecX};
+
+fn assert_quat_near(a: Quat, b: Quat) {
+    // q and -q are the same rotation
+    let same = a.approx_eq(&b) || a.approx_eq(&-b);
+    assert!(same, "{} != {}", a, b);
+}
+
+#[test]
+fn identity() {
+    assert_eq!(Quat::default(), Quat::IDENTITY);
+    assert_eq!(Quat::IDENTITY * Vec3(1.0, 2.0, 3.0), Vec3(1.0, 2.0, 3.0));
+    assert_eq!(Quat::IDENTITY.to_mat4(), Mat4::IDENTITY);
+}
+
+#[test]
+fn from_axis_angle() {
+    let q = Quat::from_axis_angle(Vec3(0.0, 0.0, 2.0), PI / 2.0);
+    assert_approx_eq!(q * Vec3(1.0, 0.0, 0.0), Vec3(0.0, 1.0, 0.0));
+
+    let q = Quat::from_axis_angle(Vec3(1.0, 0.0, 0.0), PI / 2.0);
+    assert_approx_eq!(q * Vec3(0.0, 1.0, 0.0), Vec3(0.0, 0.0, 1.0));
+
+    let (axis, angle) = Quat::from_axis_angle(Vec3(1.0, 1.0, 0.0), 0.8).to_axis_angle();
+    assert_approx_eq!(axis, Vec3(1.0, 1.0, 0.0).normalized());
+    assert!((angle - 0.8).abs() < 1e-12);
+}
+
+#[test]
+fn from_euler() {
+    let euler = Vec3(0.3, -0.5, 1.2);
+    let q = Quat::from_euler(euler);
+    let expected = Matrix::m4_rotate_z(-euler.z())
+        * Matrix::m4_rotate_y(euler.y())
+        * Matrix::m4_rotate_x(euler.x());
+    assert_approx_eq!(&Matrix::from(q), &expected);
+}
+
+#[test]
+fn from_euler_handedness() {
+    let euler = Vec3(0.3, -0.5, 1.2);
+    let v = Vec3(1.0, 2.0, -3.0);
+    assert_approx_eq!(Quat::from_euler_lh(euler).rotate(v), v.rot(&euler));
+    assert_approx_eq!(Quat::from_euler(euler).rotate(v), v.rot(&-euler));
+    // a quarter turn around z takes x to y with the right-hand rule, to -y with Vec3::rot
+    let z = Vec3(0.0, 0.0, PI / 2.0);
+    let x = Vec3(1.0, 0.0, 0.0);
+    assert_approx_eq!(Quat::from_euler(z).rotate(x), Vec3(0.0, 1.0, 0.0));
+    assert_approx_eq!(x.rot(&z), Vec3(0.0, -1.0, 0.0));
+}
+
+#[test]
+fn matches_m4_rotate() {
+    let angle = 0.7;
+    assert_quat_near(
+        Quat::from(Matrix::m4_rotate_x(angle)),
+        Quat::from_axis_angle(Vec3(1.0, 0.0, 0.0), angle),
+    );
+    assert_quat_near(
+        Quat::from(Matrix::m4_rotate_y(angle)),
+        Quat::from_axis_angle(Vec3(0.0, 1.0, 0.0), angle),
+    );
+    assert_quat_near(
+        Quat::from(Matrix::m4_rotate_z(angle)),
+        Quat::from_axis_angle(Vec3(0.0, 0.0, 1.0), -angle),
+    );
+
+    assert_approx_eq!(
+        &Matrix::from(Quat::from_axis_angle(Vec3(1.0, 0.0, 0.0), angle)),
+        &Matrix::m4_rotate_x(angle),
+    );
+    assert_approx_eq!(
+        &Matrix::from(Quat::from_axis_angle(Vec3(0.0, 1.0, 0.0), angle)),
+        &Matrix::m4_rotate_y(angle),
+    );
+}
+
+#[test]
+fn matrix_round_trip() {
+    // Exercise every branch of the matrix to quaternion conversion
+    for (axis, angle) in [
+        (Vec3(1.0, 2.0, 3.0), 0.5),
+        (Vec3(1.0, 0.1, 0.1), 3.0),
+        (Vec3(0.1, 1.0, 0.1), 3.0),
+        (Vec3(0.1, 0.1, 1.0), 3.0),
+    ] {
+        let q = Quat::from_axis_angle(axis, angle);
+        assert_quat_near(Quat::from(Matrix::from(q)), q);
+        assert_quat_near(Quat::from(q.to_mat4()), q);
+        assert_quat_near(Quat::from(Mat3::from(q.to_mat4())), q);
+
+        let v = Vec3(0.3, -2.0, 1.5);
+        assert_approx_eq!(q.to_mat3() * v, q * v);
+    }
+}
+
+#[test]
+fn compose() {
+    let a = Quat::from_axis_angle(Vec3(0.0, 1.0, 0.0), 0.4);
+    let b = Quat::from_axis_angle(Vec3(1.0, 0.0, 1.0), 1.1);
+    let v = Vec3(1.0, 2.0, 3.0);
+    assert_approx_eq!((a * b) * v, a * (b * v));
+    assert_approx_eq!(&Matrix::from(a * b), &(Matrix::from(a) * Matrix::from(b)));
+
+    let mut c = a;
+    c *= b;
+    assert_eq!(c, a * b);
+}
+
+#[test]
+fn conjugate_inverse() {
+    let q = Quat::from_axis_angle(Vec3(1.0, 2.0, 0.5), 1.3);
+    assert_quat_near(q * q.conjugate(), Quat::IDENTITY);
+    assert_quat_near(q.inverse().unwrap(), q.conjugate());
+
+    let scaled = q * 3.0;
+    assert_quat_near(scaled * scaled.inverse().unwrap(), Quat::IDENTITY);
+    assert_eq!(Quat(0.0, 0.0, 0.0, 0.0).inverse(), None);
+}
+
+#[test]
+fn slerp() {
+    let a = Quat::IDENTITY;
+    let b = Quat::from_axis_angle(Vec3(0.0, 1.0, 0.0), PI / 2.0);
+    assert_quat_near(a.slerp(&b, 0.0), a);
+    assert_quat_near(a.slerp(&b, 1.0), b);
+    assert_quat_near(
+        a.slerp(&b, 0.5),
+        Quat::from_axis_angle(Vec3(0.0, 1.0, 0.0), PI / 4.0),
+    );
+
+    // Takes the shortest path when the quaternions are in opposite hemispheres
+    assert_quat_near(
+        a.slerp(&-b, 0.5),
+        Quat::from_axis_angle(Vec3(0.0, 1.0, 0.0), PI / 4.0),
+    );
+}
+
+#[test]
+fn nlerp() {
+    let a = Quat::IDENTITY;
+    let b = Quat::from_axis_angle(Vec3(0.0, 1.0, 0.0), PI / 2.0);
+    let half = a.nlerp(&b, 0.5);
+    assert!((half.magnitude() - 1.0).abs() < 1e-12);
+    assert_quat_near(half, Quat::from_axis_angle(Vec3(0.0, 1.0, 0.0), PI / 4.0));
+    assert_quat_near(a.nlerp(&b, 1.0), b);
+}
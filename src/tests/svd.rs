@@ -0,0 +1,162 @@
+use crate::{assert_approx_eq, ApproxEq, Matrix, VecxError};
+
+fn sample() -> Matrix {
+    Matrix::from(vec![vec![3.0, 2.0, 2.0], vec![2.0, 3.0, -2.0]])
+}
+
+fn diagonal(values: &[f64]) -> Matrix {
+    let mut m = Matrix::new(values.len(), values.len());
+    for (i, value) in values.iter().enumerate() {
+        m.set((i + 1, i + 1), *value);
+    }
+    m
+}
+
+fn assert_orthonormal_cols(m: &Matrix) {
+    assert_approx_eq!(m.transpose() * m, Matrix::identity(m.cols()), 1e-9);
+}
+
+#[test]
+fn symmetric_eigen() {
+    let m = Matrix::from(vec![
+        vec![2.0, -1.0, 0.0],
+        vec![-1.0, 2.0, -1.0],
+        vec![0.0, -1.0, 2.0],
+    ]);
+    let (values, vectors) = m.symmetric_eigen();
+    let sqrt2 = f64::sqrt(2.0);
+    assert_approx_eq!(
+        values.as_slice(),
+        [2.0 + sqrt2, 2.0, 2.0 - sqrt2].as_slice()
+    );
+    assert_orthonormal_cols(&vectors);
+    assert_approx_eq!(&m * &vectors, &vectors * diagonal(&values), 1e-9);
+    assert_approx_eq!(&vectors * diagonal(&values) * vectors.transpose(), m, 1e-9);
+}
+
+#[test]
+fn symmetric_eigen_principal_axes() {
+    // covariance of a point cloud stretched along (1, 1, 0)
+    let points = [
+        (-2.0, -2.1, 0.1),
+        (-1.0, -0.9, -0.1),
+        (1.0, 1.1, 0.0),
+        (2.0, 1.9, 0.0),
+    ];
+    let mut covariance = Matrix::new(3, 3);
+    for row in 0..3 {
+        for col in 0..3 {
+            let value: f64 = points
+                .iter()
+                .map(|p| [p.0, p.1, p.2][row] * [p.0, p.1, p.2][col])
+                .sum();
+            covariance.set((row + 1, col + 1), value / points.len() as f64);
+        }
+    }
+    let (values, vectors) = covariance.symmetric_eigen();
+    assert!(values[0] > 100.0 * values[1]);
+    let axis = vectors.get_col(1);
+    let expected = 1.0 / f64::sqrt(2.0);
+    assert_approx_eq!(axis[0].abs(), expected, 1e-2);
+    assert_approx_eq!(axis[1].abs(), expected, 1e-2);
+}
+
+#[test]
+fn symmetric_eigen_errors() {
+    assert_eq!(
+        sample().try_symmetric_eigen().map(|_| ()),
+        Err(VecxError::NotSquare { rows: 2, cols: 3 })
+    );
+    let m = Matrix::from(vec![vec![1.0, 2.0], vec![0.0, 1.0]]);
+    assert_eq!(
+        m.try_symmetric_eigen().map(|_| ()),
+        Err(VecxError::NotSymmetric)
+    );
+    let m = Matrix::from(vec![vec![f64::NAN, 1.0], vec![1.0, 2.0]]);
+    assert_eq!(
+        m.try_symmetric_eigen().map(|_| ()),
+        Err(VecxError::NotConverged { sweeps: 100 })
+    );
+}
+
+#[test]
+fn svd() {
+    for m in [sample(), sample().transpose()] {
+        let (u, sigma, vt) = m.svd();
+        assert_eq!((u.rows(), u.cols()), (m.rows(), 2));
+        assert_eq!((sigma.rows(), sigma.cols()), (2, 2));
+        assert_eq!((vt.rows(), vt.cols()), (2, m.cols()));
+        assert_orthonormal_cols(&u);
+        assert_orthonormal_cols(&vt.transpose());
+        assert_approx_eq!(&u * &sigma * &vt, m, 1e-9);
+        assert_approx_eq!(m.singular_values().as_slice(), [5.0, 3.0].as_slice());
+    }
+}
+
+#[test]
+fn svd_rank_deficient() {
+    let m = Matrix::from(vec![
+        vec![1.0, 2.0, 3.0],
+        vec![2.0, 4.0, 6.0],
+        vec![1.0, 1.0, 1.0],
+        vec![0.0, 1.0, 2.0],
+    ]);
+    let (u, sigma, vt) = m.svd();
+    assert_orthonormal_cols(&u);
+    assert_orthonormal_cols(&vt.transpose());
+    assert_approx_eq!(&u * &sigma * &vt, m, 1e-9);
+    assert_eq!(m.rank(), 2);
+    assert_eq!(m.condition_number(), f64::INFINITY);
+    assert_eq!(Matrix::new(3, 2).rank(), 0);
+}
+
+#[test]
+fn svd_empty() {
+    assert_eq!(Matrix::from(Vec::<Vec<f64>>::new()), Matrix::new(0, 0));
+    for (rows, cols) in [(0, 0), (3, 0), (0, 3)] {
+        let m = Matrix::new(rows, cols);
+        let (u, sigma, vt) = m.svd();
+        assert_eq!((u.rows(), u.cols()), (rows, 0));
+        assert_eq!((sigma.rows(), sigma.cols()), (0, 0));
+        assert_eq!((vt.rows(), vt.cols()), (0, cols));
+        assert_eq!(m.rank(), 0);
+        let pseudo_inverse = m.pseudo_inverse();
+        assert_eq!((pseudo_inverse.rows(), pseudo_inverse.cols()), (cols, rows));
+    }
+}
+
+#[test]
+fn rank_and_condition_number() {
+    assert_eq!(sample().rank(), 2);
+    assert_eq!(Matrix::identity(4).rank(), 4);
+    assert_approx_eq!(sample().condition_number(), 5.0 / 3.0);
+    assert_approx_eq!(diagonal(&[4.0, -0.5, 2.0]).condition_number(), 8.0);
+}
+
+#[test]
+fn pseudo_inverse() {
+    let square = Matrix::from(vec![vec![4.0, 7.0], vec![2.0, 6.0]]);
+    assert_approx_eq!(square.pseudo_inverse(), square.inverse().unwrap(), 1e-9);
+
+    let tall = Matrix::from(vec![
+        vec![1.0, 1.0],
+        vec![1.0, 2.0],
+        vec![1.0, 3.0],
+        vec![1.0, 4.0],
+    ]);
+    let b = vec![6.0, 5.0, 7.0, 10.0];
+    let x = tall.pseudo_inverse() * Matrix::from(b.iter().map(|v| vec![*v]).collect::<Vec<_>>());
+    assert_approx_eq!(
+        x.get_col(1).as_slice(),
+        tall.least_squares(&b).as_slice(),
+        1e-9
+    );
+
+    // Moore-Penrose conditions hold even without full rank
+    let m = Matrix::from(vec![vec![1.0, 2.0], vec![2.0, 4.0], vec![3.0, 6.0]]);
+    let pinv = m.pseudo_inverse();
+    assert_eq!((pinv.rows(), pinv.cols()), (2, 3));
+    assert_approx_eq!(&m * &pinv * &m, m, 1e-9);
+    assert_approx_eq!(&pinv * &m * &pinv, pinv, 1e-9);
+    assert!((&m * &pinv).approx_eq(&(&m * &pinv).transpose()));
+}
@@ -0,0 +1,123 @@
+use crate::{Vec2, Vec3, Vec4, VecN, VecX, VecxError};
+
+#[test]
+fn vec2() {
+    let v = Vec2(1.0, 2.0);
+    assert_eq!(v.yx(), Vec2(2.0, 1.0));
+    assert_eq!(v.xxy(), Vec3(1.0, 1.0, 2.0));
+    assert_eq!(v.xxyy(), Vec4(1.0, 1.0, 2.0, 2.0));
+}
+
+#[test]
+fn vec3() {
+    let v = Vec3(1.0, 2.0, 3.0);
+    assert_eq!(v.xzy(), Vec3(1.0, 3.0, 2.0));
+    assert_eq!(v.zx(), Vec2(3.0, 1.0));
+    assert_eq!(v.bgr(), Vec3(3.0, 2.0, 1.0));
+    assert_eq!(v.rrgb(), Vec4(1.0, 1.0, 2.0, 3.0));
+}
+
+#[test]
+fn vec4() {
+    let v = Vec4(1.0, 2.0, 3.0, 4.0);
+    assert_eq!(v.wzyx(), Vec4(4.0, 3.0, 2.0, 1.0));
+    assert_eq!(v.rgba(), v);
+    assert_eq!(v.xyz(), Vec3(1.0, 2.0, 3.0));
+    assert_eq!(v.aa(), Vec2(4.0, 4.0));
+}
+
+#[test]
+fn matches_runtime_swizzle() {
+    let v = Vec4(1.0, 2.0, 3.0, 4.0);
+    assert_eq!(v.xwzy(), v.s4("xwzy"));
+    assert_eq!(v.gab(), v.s3("gab"));
+    assert_eq!(Vec3(1, 2, 3).zy(), Vec3(1, 2, 3).s2("zy"));
+}
+
+#[test]
+fn generated_setters() {
+    let mut v = Vec3(0.0, 0.0, 0.0);
+    v.set_zx(Vec2(1.0, 2.0));
+    assert_eq!(v, Vec3(2.0, 0.0, 1.0));
+    v.set_bgr(Vec3(1.0, 2.0, 3.0));
+    assert_eq!(v, Vec3(3.0, 2.0, 1.0));
+
+    let mut v = Vec4(0.0, 0.0, 0.0, 0.0);
+    v.set_wy(Vec2(4.0, 2.0));
+    assert_eq!(v.s4("xyzw"), Vec4(0.0, 2.0, 0.0, 4.0));
+    v.set_rgba(Vec4(1.0, 2.0, 3.0, 4.0));
+    assert_eq!(v.wzyx(), Vec4(4.0, 3.0, 2.0, 1.0));
+
+    let mut v = Vec2(1, 2);
+    v.set_yx(v);
+    assert_eq!(v, Vec2(2, 1));
+}
+
+#[test]
+fn set_s() {
+    let mut v = Vec4(0.0, 0.0, 0.0, 0.0);
+    v.set_s("xz", Vec2(1.0, 2.0));
+    assert_eq!(v.s4("xyzw"), Vec4(1.0, 0.0, 2.0, 0.0));
+    v.set_s("agb", Vec3(3.0, 4.0, 5.0));
+    assert_eq!(v.s4("xyzw"), Vec4(1.0, 4.0, 5.0, 3.0));
+}
+
+#[test]
+fn try_set_s() {
+    let mut v = Vec3(0.0, 0.0, 0.0);
+    assert_eq!(
+        v.try_set_s("xx", Vec2(1.0, 2.0)),
+        Err(VecxError::DuplicateComponent('x'))
+    );
+    assert_eq!(
+        v.try_set_s("xr", Vec2(1.0, 2.0)),
+        Err(VecxError::DuplicateComponent('r'))
+    );
+    assert_eq!(
+        v.try_set_s("zw", Vec2(1.0, 2.0)),
+        Err(VecxError::InvalidComponent('w'))
+    );
+    assert_eq!(
+        v.try_set_s("xyz", Vec2(1.0, 2.0)),
+        Err(VecxError::InvalidSwizzleLength {
+            swizzle: "xyz".to_string(),
+            expected: 2
+        })
+    );
+    assert_eq!(v, Vec3(0.0, 0.0, 0.0));
+    assert_eq!(v.try_set_s("yz", Vec2(1.0, 2.0)), Ok(()));
+    assert_eq!(v, Vec3(0.0, 1.0, 2.0));
+}
+
+#[test]
+#[should_panic]
+fn set_s_duplicate() {
+    Vec2(0.0, 0.0).set_s("yy", Vec2(1.0, 2.0));
+}
+
+#[test]
+fn index_mut() {
+    let mut v2 = Vec2(0.0, 0.0);
+    v2[1] = 2.0;
+    assert_eq!(v2, Vec2(0.0, 2.0));
+
+    let mut v3 = Vec3(0, 0, 0);
+    v3[2] += 3;
+    assert_eq!(v3, Vec3(0, 0, 3));
+
+    let mut v4 = Vec4(0.0, 0.0, 0.0, 0.0);
+    v4[3] = 4.0;
+    assert_eq!(v4.w(), 4.0);
+
+    let mut vn = VecN([0.0; 5]);
+    vn[4] = 5.0;
+    vn.set_s("yx", Vec2(2.0, 1.0));
+    assert_eq!(vn, VecN([1.0, 2.0, 0.0, 0.0, 5.0]));
+}
+
+#[test]
+#[should_panic]
+fn index_mut_out_of_range() {
+    let mut v = Vec3(0.0, 0.0, 0.0);
+    v[3] = 1.0;
+}
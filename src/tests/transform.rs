@@ -0,0 +1,181 @@
+use std::f64::consts::PI;
+
+use crate::{assert_approx_eq, ApproxEq, Mat4, Matrix, Quat, Transform, Vec3};
+
+fn sample() -> Transform {
+    Transform::new(
+        Vec3(1.0, -2.0, 3.0),
+        Quat::from_axis_angle(Vec3(1.0, 2.0, -1.0), 0.7),
+        Vec3(2.0, 0.5, 3.0),
+    )
+}
+
+fn assert_transform_near(a: Transform, b: Transform) {
+    // q and -q are the same rotation
+    let rotation = a.rotation.approx_eq(&b.rotation) || a.rotation.approx_eq(&-b.rotation);
+    assert!(
+        rotation && a.translation.approx_eq(&b.translation) && a.scale.approx_eq(&b.scale),
+        "{} != {}",
+        a,
+        b
+    );
+}
+
+#[test]
+fn identity() {
+    assert_eq!(Transform::default(), Transform::IDENTITY);
+    assert_eq!(Transform::identity().to_mat4(), Mat4::IDENTITY);
+    let p = Vec3(1.0, 2.0, 3.0);
+    assert_eq!(Transform::IDENTITY.transform_point(p), p);
+}
+
+#[test]
+fn to_matrix() {
+    let rotation = Quat::from_axis_angle(Vec3(1.0, 0.0, 0.0), 0.4);
+    let t = Transform::new(Vec3(1.0, 2.0, 3.0), rotation, Vec3(2.0, 3.0, 4.0));
+    let expected = Matrix::m4_translate(Vec3(1.0, 2.0, 3.0))
+        * Matrix::m4_rotate_x(0.4)
+        * Matrix::m4_scale(Vec3(2.0, 3.0, 4.0));
+    assert_approx_eq!(t.to_matrix(), expected);
+    assert_approx_eq!(Matrix::from(t), expected);
+}
+
+#[test]
+fn transform_point_and_vector() {
+    let t = sample();
+    let m = t.to_mat4();
+    let p = Vec3(0.3, -1.0, 2.0);
+    assert_approx_eq!(t.transform_point(p), m.transform_point(p));
+    assert_approx_eq!(t.transform_vector(p), m.transform_vector(p));
+
+    let t = Transform::from_rotation(Quat::from_axis_angle(Vec3(0.0, 0.0, 1.0), PI / 2.0));
+    assert_approx_eq!(t.transform_vector(Vec3(1.0, 0.0, 0.0)), Vec3(0.0, 1.0, 0.0));
+    assert_approx_eq!(
+        Transform::from_translation(Vec3(1.0, 1.0, 1.0)).transform_vector(Vec3(1.0, 0.0, 0.0)),
+        Vec3(1.0, 0.0, 0.0)
+    );
+}
+
+#[test]
+fn compose() {
+    let parent = Transform::new(
+        Vec3(5.0, 0.0, -1.0),
+        Quat::from_axis_angle(Vec3(0.0, 1.0, 0.0), 1.1),
+        Vec3(2.0, 2.0, 2.0),
+    );
+    let child = sample();
+    let composed = parent * child;
+    assert_approx_eq!(composed.to_mat4(), parent.to_mat4() * child.to_mat4());
+
+    let p = Vec3(1.0, 2.0, 3.0);
+    assert_approx_eq!(
+        composed.transform_point(p),
+        parent.transform_point(child.transform_point(p))
+    );
+
+    let mut t = parent;
+    t *= child;
+    assert_eq!(t, composed);
+}
+
+#[test]
+fn compose_non_uniform_scale() {
+    // a non-uniform parent scale is exact on an unrotated child
+    let parent = sample();
+    let child = Transform::new(Vec3(1.0, 1.0, -2.0), Quat::IDENTITY, Vec3(0.5, 3.0, 1.0));
+    let composed = parent.compose(&child).unwrap();
+    assert_approx_eq!(composed.to_matrix(), parent.to_matrix() * child.to_matrix());
+
+    // rotated, the product has shear and isn't a Transform
+    let rotated = Transform::from_rotation(Quat::from_axis_angle(Vec3(0.0, 0.0, 1.0), 0.5));
+    assert_eq!(parent.compose(&rotated), None);
+    assert!(Transform::from_scale(Vec3(2.0, 2.0, 2.0))
+        .compose(&rotated)
+        .is_some());
+}
+
+#[test]
+#[should_panic]
+fn mul_non_uniform_scale() {
+    let _ = sample() * sample();
+}
+
+#[test]
+fn inverse() {
+    let t = Transform::new(
+        Vec3(1.0, -2.0, 3.0),
+        Quat::from_axis_angle(Vec3(1.0, 2.0, -1.0), 0.7),
+        Vec3(3.0, 3.0, 3.0),
+    );
+    let inverse = t.inverse().unwrap();
+    assert_transform_near(t * inverse, Transform::IDENTITY);
+    assert_transform_near(inverse * t, Transform::IDENTITY);
+    assert_approx_eq!(inverse.to_mat4(), t.to_mat4().inverse().unwrap());
+
+    let p = Vec3(0.5, 4.0, -2.0);
+    assert_approx_eq!(inverse.transform_point(t.transform_point(p)), p);
+
+    assert_eq!(Transform::from_scale(Vec3(1.0, 0.0, 1.0)).inverse(), None);
+}
+
+#[test]
+fn inverse_non_uniform_scale() {
+    // a non-uniform scale without rotation is exactly invertible
+    let t = Transform::new(Vec3(1.0, -2.0, 3.0), Quat::IDENTITY, Vec3(2.0, 0.5, -3.0));
+    let inverse = t.inverse().unwrap();
+    assert_approx_eq!(inverse.to_matrix() * t.to_matrix(), Matrix::id4());
+    assert_approx_eq!(t.to_matrix() * inverse.to_matrix(), Matrix::id4());
+
+    // rotated, the inverse has shear and isn't a Transform
+    assert_eq!(sample().inverse(), None);
+    let inverse = sample().to_matrix().inverse().unwrap();
+    assert_approx_eq!(inverse * sample().to_matrix(), Matrix::id4());
+
+    let tiny = Transform::from_scale(Vec3(1e-8, 1e-8, 1e-8));
+    assert_approx_eq!(
+        tiny.inverse().unwrap().to_matrix() * tiny.to_matrix(),
+        Matrix::id4()
+    );
+}
+
+#[test]
+fn interpolate() {
+    let a = Transform::from_translation(Vec3(0.0, 0.0, 0.0));
+    let b = Transform::new(
+        Vec3(2.0, 4.0, 6.0),
+        Quat::from_axis_angle(Vec3(0.0, 0.0, 1.0), PI / 2.0),
+        Vec3(3.0, 3.0, 3.0),
+    );
+    let mid = a.interpolate(&b, 0.5);
+    assert_approx_eq!(mid.translation, Vec3(1.0, 2.0, 3.0));
+    assert_approx_eq!(mid.scale, Vec3(2.0, 2.0, 2.0));
+    assert_approx_eq!(
+        mid.rotation,
+        Quat::from_axis_angle(Vec3(0.0, 0.0, 1.0), PI / 4.0)
+    );
+    assert_transform_near(a.interpolate(&b, 0.0), a);
+    assert_transform_near(a.interpolate(&b, 1.0), b);
+}
+
+#[test]
+fn decompose() {
+    let t = sample();
+    assert_transform_near(Transform::decompose(&t.to_matrix()).unwrap(), t);
+    assert_transform_near(Transform::from(t.to_matrix()), t);
+
+    let mirrored = Transform::new(Vec3(1.0, 0.0, 0.0), t.rotation, Vec3(-2.0, 1.0, 1.0));
+    let decomposed = Transform::decompose(&mirrored.to_matrix()).unwrap();
+    assert_approx_eq!(decomposed.to_matrix(), mirrored.to_matrix());
+    assert!(decomposed.scale.0 < 0.0);
+
+    // tolerances are relative, a small uniform scale decomposes
+    let tiny = Transform::new(Vec3(1e-13, 0.0, 0.0), t.rotation, Vec3(1e-13, 1e-13, 1e-13));
+    let decomposed = Transform::decompose(&tiny.to_matrix()).unwrap();
+    assert_approx_eq!(decomposed.scale / 1e-13, Vec3(1.0, 1.0, 1.0));
+    assert_approx_eq!(decomposed.to_matrix(), tiny.to_matrix());
+    assert!(Transform::decompose(&Matrix::m4_scale(Vec3(1.0, 1e-14, 1.0))).is_none());
+
+    assert!(Transform::decompose(&Matrix::m4_scale(Vec3(1.0, 0.0, 1.0))).is_none());
+    assert!(Transform::decompose(&Matrix::identity(3)).is_none());
+    assert!(Transform::decompose(&Matrix::m4_perspective(PI / 2.0, 1.0, 0.1, 10.0)).is_none());
+}
@@ -1,6 +1,6 @@
-use std::f64::{consts::PI, INFINITY};
+use std::f64::consts::PI;
 
-use crate::{Vec2, Vec3, VecX};
+use crate::{Vec2, Vec3, VecX, VecxError};
 
 #[test]
 fn from_f64() {
@@ -8,6 +8,7 @@ fn from_f64() {
 }
 
 #[test]
+#[allow(clippy::useless_conversion)]
 fn from_vec() {
     assert_eq!(Vec2::from(Vec2(1.0, 2.0)), Vec2(1.0, 2.0));
     assert_eq!(Vec2::from(Vec3(1.0, 2.0, 3.0)), Vec2(1.0, 2.0));
@@ -89,8 +90,8 @@ fn div() {
     assert_eq!(v / 2.0, Vec2(0.5, 1.0));
     assert_eq!(v / Vec2(1.0, 1.0), v);
     assert_eq!(v / Vec2(2.0, 4.0), Vec2(0.5, 0.5));
-    assert_eq!(v / 0.0, Vec2(INFINITY, INFINITY));
-    assert_eq!(v / Vec2(0.0, 0.0), Vec2(INFINITY, INFINITY));
+    assert_eq!(v / 0.0, Vec2(f64::INFINITY, f64::INFINITY));
+    assert_eq!(v / Vec2(0.0, 0.0), Vec2(f64::INFINITY, f64::INFINITY));
 }
 
 #[test]
@@ -291,3 +292,221 @@ fn bad_swz_invalid_component_s3() {
 fn bad_swz_invalid_component_s2() {
     Vec2(1.0, 2.0).s2("xc");
 }
+
+#[test]
+fn try_comp() {
+    let v = Vec2(1.0, 2.0);
+    assert_eq!(v.try_comp('y'), Ok(2.0));
+    assert_eq!(v.try_comp('g'), Ok(2.0));
+    assert_eq!(v.try_comp('z'), Err(VecxError::InvalidComponent('z')));
+    assert_eq!(v.try_comp('b'), Err(VecxError::InvalidComponent('b')));
+}
+
+#[test]
+fn try_s() {
+    let v = Vec2(1.0, 2.0);
+    assert_eq!(v.try_s::<Vec3>("yxy"), Ok(Vec3(2.0, 1.0, 2.0)));
+    assert_eq!(
+        v.try_s::<Vec3>("yx"),
+        Err(VecxError::InvalidSwizzleLength {
+            swizzle: "yx".to_string(),
+            expected: 3
+        })
+    );
+    assert_eq!(v.try_s::<Vec2>("xq"), Err(VecxError::InvalidComponent('q')));
+}
+
+#[test]
+fn try_normalized() {
+    assert_eq!(Vec2(3.0, 0.0).try_normalized(), Ok(Vec2(1.0, 0.0)));
+    assert_eq!(
+        Vec2(0.0, 0.0).try_normalized(),
+        Err(VecxError::ZeroMagnitude)
+    );
+}
+
+#[test]
+fn checked_div_rem() {
+    let v = Vec2(2.0, 0.0);
+    assert_eq!(v.checked_div(&Vec2(2.0, 1.0)), Ok(Vec2(1.0, 0.0)));
+    assert_eq!(
+        v.checked_div(&Vec2(2.0, 0.0)),
+        Err(VecxError::ZeroByZeroDivision)
+    );
+    assert_eq!(v.checked_div_scalar(2.0), Ok(Vec2(1.0, 0.0)));
+    assert_eq!(
+        v.checked_div_scalar(0.0),
+        Err(VecxError::ZeroByZeroDivision)
+    );
+    assert_eq!(
+        Vec2(1.0, 1.0).checked_div_scalar(0.0),
+        Ok(Vec2(f64::INFINITY, f64::INFINITY))
+    );
+
+    assert_eq!(
+        Vec2(5.0, 7.0).checked_rem(&Vec2(2.0, 4.0)),
+        Ok(Vec2(1.0, 3.0))
+    );
+    assert_eq!(
+        Vec2(5.0, 7.0).checked_rem(&Vec2(2.0, 0.0)),
+        Err(VecxError::ZeroDivisor)
+    );
+    assert_eq!(Vec2(5.0, 7.0).checked_rem_scalar(5.0), Ok(Vec2(0.0, 2.0)));
+    assert_eq!(
+        Vec2(5.0, 7.0).checked_rem_scalar(0.0),
+        Err(VecxError::ZeroDivisor)
+    );
+}
+
+#[test]
+fn integer_components() {
+    let v = Vec2(3, 4);
+    assert_eq!(v + Vec2(1, 2), Vec2(4, 6));
+    assert_eq!(2 * v, Vec2(6, 8));
+    assert_eq!(v.dot_product(&Vec2(2, 1)), 10);
+    assert_eq!(v.magnitude(), 5.0);
+    assert_eq!(Vec2(7, 9) / 2, Vec2(3, 4));
+    assert_eq!(Vec2(7, 9) % 4, Vec2(3, 1));
+    assert_eq!(v.s::<Vec2<i32>>("yx"), Vec2(4, 3));
+    assert_eq!(v.s3("xyx"), Vec3(3, 4, 3));
+    assert_eq!(vec![1, 2].into_iter().collect::<Vec2<i32>>(), Vec2(1, 2));
+}
+
+#[test]
+fn integer_division_by_zero() {
+    assert_eq!(
+        Vec2(1, 2).checked_div_scalar(0),
+        Err(VecxError::IntegerDivisionByZero)
+    );
+    assert_eq!(
+        Vec2(0, 2).checked_div(&Vec2(0, 1)),
+        Err(VecxError::ZeroByZeroDivision)
+    );
+    assert_eq!(
+        Vec2(1, 2).checked_rem(&Vec2(1, 0)),
+        Err(VecxError::ZeroDivisor)
+    );
+}
+
+#[test]
+#[should_panic]
+fn integer_div_panic() {
+    let _ = Vec2(1, 2) / Vec2(1, 0);
+}
+
+#[test]
+fn f32_components() {
+    let v = Vec2(3.0f32, 4.0);
+    assert_eq!(v.normalized(), Vec2(0.6, 0.8));
+    assert_eq!(v * 0.5, Vec2(1.5, 2.0));
+    assert_eq!(0.5f32 * v, Vec2(1.5, 2.0));
+}
+
+#[test]
+fn scalar_conversions() {
+    assert_eq!(Vec2::<f64>::from(Vec2(1.5f32, -2.0)), Vec2(1.5, -2.0));
+    assert_eq!(Vec2::<f64>::from(Vec2(1, -2)), Vec2(1.0, -2.0));
+    assert_eq!(Vec2::<i64>::from(Vec2(1i32, -2)), Vec2(1i64, -2));
+    assert_eq!(Vec2(1.9, -1.9).cast::<i32>(), Vec2(1, -1));
+    assert_eq!(Vec2(0.1, 2.0).cast::<f32>(), Vec2(0.1f32, 2.0));
+}
+
+#[test]
+fn reflect() {
+    let n = Vec2(0.0, 1.0);
+    assert_eq!(Vec2(1.0, -1.0).reflect(&n), Vec2(1.0, 1.0));
+    assert_eq!(Vec2(3.0, 0.0).reflect(&n), Vec2(3.0, 0.0));
+    assert_eq!(Vec2(2, -3).reflect(&Vec2(0, 1)), Vec2(2, 3));
+}
+
+#[test]
+fn refract() {
+    let n = Vec2(0.0, 1.0);
+    let v: Vec2 = Vec2(1.0, -1.0).normalized();
+    let refracted = v.refract(&n, 1.0 / 1.5).unwrap();
+    // Snell's law, n1 * sin(i) = n2 * sin(t)
+    assert!((v.x() - 1.5 * refracted.x()).abs() < 1e-12);
+    assert!((refracted.magnitude() - 1.0).abs() < 1e-12);
+    assert!(refracted.y() < 0.0);
+
+    assert_eq!(v.refract(&n, 1.5), None);
+    // just above the critical angle
+    let critical = f64::asin(1.0 / 1.5);
+    let grazing = Vec2(f64::sin(critical + 1e-6), -f64::cos(critical + 1e-6));
+    assert_eq!(grazing.refract(&n, 1.5), None);
+}
+
+#[test]
+fn project_and_reject() {
+    let v = Vec2(2.0, 3.0);
+    let onto = Vec2(1.0, 1.0);
+    assert_eq!(v.project_onto(&onto), Vec2(2.5, 2.5));
+    assert_eq!(v.reject_from(&onto), Vec2(-0.5, 0.5));
+    assert_eq!(v.project_onto(&onto) + v.reject_from(&onto), v);
+    assert_eq!(
+        v.try_reject_from(&Vec2(0.0, 0.0)),
+        Err(VecxError::ZeroMagnitude)
+    );
+}
+
+#[test]
+#[should_panic]
+fn project_onto_zero() {
+    Vec2(2.0, 3.0).project_onto(&Vec2(0.0, 0.0));
+}
+
+#[test]
+fn lerp() {
+    let a = Vec2(0.0, 10.0);
+    let b = Vec2(4.0, 0.0);
+    assert_eq!(a.lerp(&b, 0.0), a);
+    assert_eq!(a.lerp(&b, 1.0), b);
+    assert_eq!(a.lerp(&b, 0.25), Vec2(1.0, 7.5));
+    assert_eq!(a.lerp(&b, 2.0), Vec2(8.0, -10.0));
+    assert_eq!(Vec2(0, 10).lerp(&Vec2(4, 0), 0.5), Vec2(2, 5));
+}
+
+#[test]
+fn component_wise() {
+    let v = Vec2(-1.5, 2.5);
+    assert_eq!(v.abs(), Vec2(1.5, 2.5));
+    assert_eq!(v.floor(), Vec2(-2.0, 2.0));
+    assert_eq!(v.ceil(), Vec2(-1.0, 3.0));
+    assert_eq!(v.min(&Vec2(0.0, 0.0)), Vec2(-1.5, 0.0));
+    assert_eq!(v.max(&Vec2(0.0, 0.0)), Vec2(0.0, 2.5));
+    assert_eq!(Vec2(-3, 4).abs(), Vec2(3, 4));
+    assert_eq!(Vec2(-3, 4).floor(), Vec2(-3, 4));
+}
+
+#[test]
+fn signed_angle() {
+    let x = Vec2(2.0, 0.0);
+    assert_eq!(x.signed_angle(&Vec2(0.0, 3.0)), PI / 2.0);
+    assert_eq!(x.signed_angle(&Vec2(0.0, -3.0)), -PI / 2.0);
+    assert_eq!(Vec2(0.0, 3.0).signed_angle(&x), -PI / 2.0);
+    assert_eq!(x.signed_angle(&Vec2(-1.0, 0.0)), PI);
+    assert!((x.signed_angle(&Vec2(-1.0, -1.0)) + 3.0 * PI / 4.0).abs() < 1e-15);
+    assert_eq!(Vec2(1, 1).signed_angle(&Vec2(-1, 1)), PI / 2.0);
+
+    assert_eq!(x.try_signed_angle(&Vec2(0.0, 0.0)), None);
+    assert_eq!(Vec2(0.0, 0.0).try_angle(&x), None);
+}
+
+#[test]
+#[should_panic]
+fn signed_angle_zero() {
+    Vec2(0.0, 0.0).signed_angle(&Vec2(1.0, 0.0));
+}
+
+#[test]
+fn angle_precision() {
+    // the normalized dot product rounds above 1.0, where acos returns NaN
+    let v = Vec2(1.0, 1e-9);
+    let w = Vec2(3.0, 3e-9 + 3e-17);
+    assert!(v.angle(&w).is_finite());
+    assert!(v.angle(&w) < 1e-15);
+
+    let tiny = 1e-10;
+    let angle = Vec2(1.0, 0.0).angle(&Vec2(f64::cos(tiny), f64::sin(tiny)));
+    assert!((angle - tiny).abs() < 1e-20);
+}
@@ -1,10 +1,8 @@
 use std::f64::consts::PI;
 
-use crate::{Matrix, Vec3, Vec4};
+use crate::{assert_approx_eq, Matrix, Vec3, Vec4};
 
-use std::f64::INFINITY;
-
-use crate::{Vec2, VecX};
+use crate::{Vec2, VecX, VecxError};
 
 #[test]
 fn from_f64() {
@@ -12,6 +10,7 @@ fn from_f64() {
 }
 
 #[test]
+#[allow(clippy::useless_conversion)]
 fn from_vecx() {
     assert_eq!(Vec3::from(Vec2(1.0, 2.0)), Vec3(1.0, 2.0, 0.0));
     assert_eq!(Vec3::from(Vec3(1.0, 2.0, 3.0)), Vec3(1.0, 2.0, 3.0));
@@ -26,6 +25,14 @@ fn from_vec64() {
     assert_eq!(Vec3::from(vec![1.0, 2.0, 3.0, 4.0]), Vec3(1.0, 2.0, 3.0));
 }
 
+#[test]
+fn as_homogenous() {
+    let v = Vec3(1.0, 2.0, 3.0);
+    assert_eq!(v.as_homogenous(2.0), Vec4(1.0, 2.0, 3.0, 2.0));
+    assert_eq!(v.as_homogenous_vector(), Vec4(1.0, 2.0, 3.0, 0.0));
+    assert_eq!(v.as_homogenous_point(), Vec4(1.0, 2.0, 3.0, 1.0));
+}
+
 #[test]
 fn is_indexable() {
     let v = Vec3(0.0, 1.0, 2.0);
@@ -34,6 +41,19 @@ fn is_indexable() {
     assert_eq!(v[2], 2.0);
 }
 
+#[test]
+#[should_panic(expected = "by invalid index 3")]
+fn invalid_index() {
+    let _ = Vec3(0.0, 1.0, 2.0)[3];
+}
+
+#[test]
+#[should_panic(expected = "by invalid index 3")]
+fn invalid_index_mut() {
+    let mut v = Vec3(0.0, 1.0, 2.0);
+    v[3] = 1.0;
+}
+
 #[test]
 fn add() {
     let v = Vec3(1.0, 2.0, 3.0);
@@ -101,8 +121,11 @@ fn div() {
     assert_eq!(v / 2.0, Vec3(0.5, 1.0, 1.5));
     assert_eq!(v / Vec3(1.0, 1.0, 1.0), v);
     assert_eq!(v / Vec3(2.0, 4.0, 6.0), Vec3(0.5, 0.5, 0.5));
-    assert_eq!(v / 0.0, Vec3(INFINITY, INFINITY, INFINITY));
-    assert_eq!(v / Vec3(0.0, 0.0, 0.0), Vec3(INFINITY, INFINITY, INFINITY));
+    assert_eq!(v / 0.0, Vec3(f64::INFINITY, f64::INFINITY, f64::INFINITY));
+    assert_eq!(
+        v / Vec3(0.0, 0.0, 0.0),
+        Vec3(f64::INFINITY, f64::INFINITY, f64::INFINITY)
+    );
 }
 
 #[test]
@@ -322,3 +345,217 @@ fn from_matrix() {
     let mat_1x3 = Matrix::from(vec![vec![1.1, 1.2, 1.3]]);
     assert_eq!(Vec3::from(mat_1x3), Vec3(1.1, 1.2, 1.3));
 }
+
+#[test]
+fn look_basis_lh() {
+    let (forward, right, up) = Vec3(0.0, 0.0, 5.0).look_basis_lh(&Vec3(0.0, 1.0, 0.0));
+    assert_eq!(forward, Vec3(0.0, 0.0, 1.0));
+    assert_eq!(right, Vec3(1.0, 0.0, 0.0));
+    assert_eq!(up, Vec3(0.0, 1.0, 0.0));
+
+    // Non orthogonal up gets corrected
+    let (forward, right, up) = Vec3(1.0, 0.0, 1.0).look_basis_lh(&Vec3(0.0, 1.0, 0.2));
+    assert!(forward.dot_product(&right).abs() < 1e-12);
+    assert!(forward.dot_product(&up).abs() < 1e-12);
+    assert!(right.dot_product(&up).abs() < 1e-12);
+    assert!((right.magnitude() - 1.0).abs() < 1e-12);
+    assert!((up.magnitude() - 1.0).abs() < 1e-12);
+    assert!(up.y() > 0.0);
+}
+
+#[test]
+fn look_basis_rh() {
+    let (forward, right, up) = Vec3(0.0, 0.0, -5.0).look_basis_rh(&Vec3(0.0, 1.0, 0.0));
+    assert_eq!(forward, Vec3(0.0, 0.0, -1.0));
+    assert_eq!(right, Vec3(1.0, 0.0, 0.0));
+    assert_eq!(up, Vec3(0.0, 1.0, 0.0));
+}
+
+#[test]
+#[should_panic]
+fn look_basis_parallel_up() {
+    Vec3(0.0, 2.0, 0.0).look_basis_lh(&Vec3(0.0, 1.0, 0.0));
+}
+
+#[test]
+fn generic_components() {
+    let a = Vec3(1, 0, 0);
+    let b = Vec3(0, 1, 0);
+    assert_eq!(a.cross(&b), Vec3(0, 0, 1));
+    assert_eq!(Vec3::<i32>::one() - a, b + Vec3(0, 0, 1));
+    assert_eq!(Vec3::<i32>::ZERO, Vec3(0, 0, 0));
+    assert_eq!(Vec4::from((Vec3(1, 2, 3), 4)), Vec4(1, 2, 3, 4));
+
+    let f = Vec3(0.0f32, 3.0, 4.0);
+    assert_eq!(f.magnitude(), 5.0);
+    assert_eq!(f.normalized(), Vec3(0.0, 0.6, 0.8));
+    assert_eq!(Vec3::<f64>::from(f), Vec3(0.0, 3.0, 4.0));
+    assert_eq!(Vec3(2.5, -0.5, 7.9).cast::<i32>(), Vec3(2, 0, 7));
+}
+
+#[test]
+fn rotate_around() {
+    let v = Vec3(1.0, 2.0, 3.0);
+    let x = Vec3(1.0, 0.0, 0.0);
+    let z = Vec3(0.0, 0.0, 2.0);
+    assert_approx_eq!(Vec3(0.0, 1.0, 0.0).rotate_around(&z, PI / 2.0), -x);
+    assert_approx_eq!(v.rotate_around(&x, 0.7), v.rot_x(-0.7));
+    assert_approx_eq!(v.rotate_around(&Vec3(0.0, 1.0, 0.0), 0.7), v.rot_y(-0.7));
+    assert_approx_eq!(v.rotate_around(&Vec3(0.0, 0.0, 1.0), 0.7), v.rot_z(-0.7));
+
+    let axis = Vec3(1.0, 1.0, 1.0);
+    assert_approx_eq!(axis.rotate_around(&axis, 1.3), axis);
+    // a third of a turn around the diagonal cycles the axes
+    assert_approx_eq!(x.rotate_around(&axis, 2.0 * PI / 3.0), Vec3(0.0, 1.0, 0.0));
+    assert_approx_eq!(v.rotate_around(&axis, 0.4).magnitude(), v.magnitude());
+}
+
+#[test]
+#[should_panic]
+fn rotate_around_zero_axis() {
+    Vec3(1.0, 2.0, 3.0).rotate_around(&Vec3(0.0, 0.0, 0.0), 1.0);
+}
+
+#[test]
+fn reflect_and_refract() {
+    let n = Vec3(0.0, 0.0, 1.0);
+    let v = Vec3(1.0, 2.0, -3.0);
+    assert_eq!(v.reflect(&n), Vec3(1.0, 2.0, 3.0));
+    assert_approx_eq!(v.reflect(&n).reflect(&n), v);
+
+    let unit = v.normalized();
+    assert_approx_eq!(unit.refract(&n, 1.0).unwrap(), unit);
+    let refracted = unit.refract(&n, 1.0 / 1.33).unwrap();
+    // the refracted ray stays in the plane of incidence
+    assert_approx_eq!(refracted.dot_product(&unit.cross(&n)), 0.0);
+    assert_approx_eq!(refracted.magnitude(), 1.0);
+    assert_eq!(Vec3(0.0, 1.0, -0.1).normalized().refract(&n, 1.33), None);
+}
+
+#[test]
+fn project_and_reject() {
+    let v = Vec3(1.0, 2.0, 3.0);
+    let axis = Vec3(0.0, 2.0, 0.0);
+    assert_eq!(v.project_onto(&axis), Vec3(0.0, 2.0, 0.0));
+    assert_eq!(v.reject_from(&axis), Vec3(1.0, 0.0, 3.0));
+
+    let other = Vec3(-1.0, 0.5, 2.0);
+    assert_approx_eq!(v.reject_from(&other).dot_product(&other), 0.0);
+    assert_approx_eq!(v.project_onto(&other).cross(&other), Vec3(0.0, 0.0, 0.0));
+}
+
+#[test]
+fn lerp_and_clamp() {
+    let a = Vec3(0.0, 1.0, 2.0);
+    let b = Vec3(2.0, 1.0, -2.0);
+    assert_eq!(a.lerp(&b, 0.5), Vec3(1.0, 1.0, 0.0));
+    assert_eq!(
+        Vec3(-1.0, 0.5, 3.0).clamp(&Vec3(0.0, 0.0, 0.0), &Vec3(1.0, 1.0, 2.0)),
+        Vec3(0.0, 0.5, 2.0)
+    );
+    assert_eq!(Vec3(-1.2, 0.2, 1.7).floor(), Vec3(-2.0, 0.0, 1.0));
+    assert_eq!(Vec3(-1.2, 0.2, 1.7).ceil(), Vec3(-1.0, 1.0, 2.0));
+}
+
+#[test]
+fn slerp() {
+    let x = Vec3(1.0, 0.0, 0.0);
+    let y = Vec3(0.0, 1.0, 0.0);
+    assert_approx_eq!(x.slerp(&y, 0.0), x);
+    assert_approx_eq!(x.slerp(&y, 1.0), y);
+    assert_approx_eq!(x.slerp(&y, 0.5), Vec3(1.0, 1.0, 0.0) / f64::sqrt(2.0));
+    // constant angular speed
+    assert_approx_eq!(x.slerp(&y, 1.0 / 3.0).angle(&x), PI / 6.0);
+    assert_approx_eq!(x.slerp(&x, 0.7), x);
+}
+
+#[test]
+fn slerp_antiparallel() {
+    for v in [
+        Vec3(1.0, 0.0, 0.0),
+        Vec3(0.0, 0.0, -1.0),
+        Vec3(1.0, 2.0, 3.0).normalized(),
+    ] {
+        let half = v.slerp(&-v, 0.5);
+        assert_approx_eq!(half.magnitude(), 1.0);
+        assert_approx_eq!(half.dot_product(&v), 0.0);
+        assert_approx_eq!(v.slerp(&-v, 1.0), -v);
+    }
+}
+
+#[test]
+fn nlerp_and_orthogonal() {
+    let x = Vec3(1.0, 0.0, 0.0);
+    let y = Vec3(0.0, 1.0, 0.0);
+    assert_approx_eq!(x.nlerp(&y, 0.5), x.slerp(&y, 0.5));
+    assert_approx_eq!(x.nlerp(&y, 0.2).magnitude(), 1.0);
+
+    assert_eq!(x.try_nlerp(&-x, 0.5), Err(VecxError::ZeroMagnitude));
+    assert_approx_eq!(x.try_nlerp(&-x, 0.25).unwrap(), x);
+    // slerp picks a direction orthogonal to both instead
+    assert_approx_eq!(x.slerp(&-x, 0.5).dot_product(&x), 0.0);
+
+    for v in [x, y, Vec3(0.0, 0.0, 3.0), Vec3(1.0, -2.0, 0.5)] {
+        let o = v.orthogonal();
+        assert_approx_eq!(o.dot_product(&v), 0.0);
+        assert!(o.magnitude() >= v.magnitude() / f64::sqrt(3.0));
+    }
+}
+
+#[test]
+fn angle_precision() {
+    let tiny = 1e-10;
+    let x = Vec3(1.0, 0.0, 0.0);
+    let v = Vec3(f64::cos(tiny), 0.0, f64::sin(tiny));
+    assert!((x.angle(&v) - tiny).abs() < 1e-20);
+    assert!((x.angle(&-v) - (PI - tiny)).abs() < 1e-15);
+    assert!(Vec3(1.0, 2.0, 3.0).angle(&Vec3(2.0, 4.0, 6.0)) < 1e-15);
+    assert_eq!(Vec3(1, 0, 0).angle(&Vec3(0, 0, 5)), PI / 2.0);
+
+    assert_eq!(x.try_angle(&Vec3(0.0, 0.0, 0.0)), None);
+    assert_eq!(x.try_angle(&v), Some(x.angle(&v)));
+}
+
+#[test]
+#[should_panic]
+fn angle_zero() {
+    Vec3(0.0, 0.0, 0.0).angle(&Vec3(1.0, 0.0, 0.0));
+}
+
+#[test]
+fn signed_angle_around() {
+    let x = Vec3(1.0, 0.0, 0.0);
+    let y = Vec3(0.0, 1.0, 0.0);
+    let z = Vec3(0.0, 0.0, 1.0);
+    assert_approx_eq!(x.signed_angle_around(&y, &z), PI / 2.0);
+    assert_approx_eq!(x.signed_angle_around(&y, &-z), -PI / 2.0);
+    assert_approx_eq!(y.signed_angle_around(&x, &(z * 4.0)), -PI / 2.0);
+    // only the part orthogonal to the axis counts
+    assert_approx_eq!((x + z * 3.0).signed_angle_around(&(y - z), &z), PI / 2.0);
+
+    let v = Vec3(1.0, -2.0, 0.5);
+    let axis = Vec3(0.3, 1.0, -0.7);
+    for angle in [-3.0, -1.0, 0.2, 2.5] {
+        assert_approx_eq!(
+            v.signed_angle_around(&v.rotate_around(&axis, angle), &axis),
+            angle
+        );
+    }
+
+    assert_eq!(x.try_signed_angle_around(&y, &Vec3(0.0, 0.0, 0.0)), None);
+    assert_eq!(x.try_signed_angle_around(&y, &(x * 2.0)), None);
+    assert_eq!(z.try_signed_angle_around(&Vec3(0.0, 0.0, 0.0), &x), None);
+}
+
+#[test]
+#[should_panic]
+fn signed_angle_around_parallel() {
+    Vec3(1.0, 0.0, 0.0).signed_angle_around(&Vec3(0.0, 1.0, 0.0), &Vec3(0.0, 1.0, 0.0));
+}
+
+#[test]
+#[should_panic]
+fn nlerp_antiparallel() {
+    let x = Vec3(1.0, 0.0, 0.0);
+    x.nlerp(&-x, 0.5);
+}
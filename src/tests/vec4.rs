@@ -1,8 +1,6 @@
 use std::f64::consts::PI;
 
-use std::f64::INFINITY;
-
-use crate::{Vec2, Vec3, Vec4, VecX};
+use crate::{Vec2, Vec3, Vec4, VecX, VecxError};
 
 #[test]
 fn from_f64() {
@@ -39,6 +37,19 @@ fn is_indexable() {
     assert_eq!(v[3], 3.0);
 }
 
+#[test]
+#[should_panic(expected = "by invalid index 4")]
+fn invalid_index() {
+    let _ = Vec4(0.0, 1.0, 2.0, 3.0)[4];
+}
+
+#[test]
+#[should_panic(expected = "by invalid index 4")]
+fn invalid_index_mut() {
+    let mut v = Vec4(0.0, 1.0, 2.0, 3.0);
+    v[4] = 1.0;
+}
+
 #[test]
 fn add() {
     let v = Vec4(1.0, 2.0, 3.0, 4.0);
@@ -99,10 +110,13 @@ fn div() {
     let v = Vec4(1.0, 2.0, 3.0, 4.0);
     assert_eq!(v / 1.0, v);
     assert_eq!(v / 2.0, Vec4(0.5, 1.0, 1.5, 2.0));
-    assert_eq!(v / 0.0, Vec4(INFINITY, INFINITY, INFINITY, INFINITY));
+    assert_eq!(
+        v / 0.0,
+        Vec4(f64::INFINITY, f64::INFINITY, f64::INFINITY, f64::INFINITY)
+    );
     assert_eq!(
         v / Vec4(0.0, 0.0, 0.0, 0.0),
-        Vec4(INFINITY, INFINITY, INFINITY, INFINITY)
+        Vec4(f64::INFINITY, f64::INFINITY, f64::INFINITY, f64::INFINITY)
     );
 }
 
@@ -267,6 +281,19 @@ fn swizzle() {
 
     assert_eq!(v.s4("xxxx"), Vec4(1.0, 1.0, 1.0, 1.0));
     assert_eq!(v.s4("rrrr"), Vec4(1.0, 1.0, 1.0, 1.0));
+    assert_eq!(v.s4("wwww"), Vec4(4.0, 4.0, 4.0, 4.0));
+    assert_eq!(v.s4("aaaa"), Vec4(4.0, 4.0, 4.0, 4.0));
+    assert_eq!(v.s4("wzyx"), Vec4(4.0, 3.0, 2.0, 1.0));
+}
+
+#[test]
+fn comp_and_at() {
+    let v = Vec4(1.0, 2.0, 3.0, 4.0);
+    assert_eq!(v.comp('w'), 4.0);
+    assert_eq!(v.comp('a'), 4.0);
+    assert_eq!(v.at(3), 4.0);
+    assert_eq!(v.at(4), 0.0);
+    assert_eq!(v.as_values_of::<Vec4>(), vec![1.0, 2.0, 3.0, 4.0]);
 }
 
 #[test]
@@ -292,3 +319,66 @@ fn bad_swz_1_into_4() {
 fn bad_swz_invalid_component_s4() {
     Vec4(1.0, 2.0, 3.0, 4.0).s4("xxcx");
 }
+
+#[test]
+fn perspective_divide() {
+    assert_eq!(
+        Vec4(2.0, 4.0, 6.0, 2.0).perspective_divide(),
+        Vec3(1.0, 2.0, 3.0)
+    );
+    assert_eq!(
+        Vec4(1.0, -1.0, 0.5, 1.0).perspective_divide(),
+        Vec3(1.0, -1.0, 0.5)
+    );
+}
+
+#[test]
+fn try_comp() {
+    let v = Vec4(1.0, 2.0, 3.0, 4.0);
+    assert_eq!(v.try_comp('w'), Ok(4.0));
+    assert_eq!(v.try_comp('a'), Ok(4.0));
+    assert_eq!(v.try_comp('q'), Err(VecxError::InvalidComponent('q')));
+    assert_eq!(v.try_s::<Vec2>("wz"), Ok(Vec2(4.0, 3.0)));
+}
+
+#[test]
+fn component_wise() {
+    let v = Vec4(-1.5, 0.5, 2.5, -3.0);
+    let w = Vec4(1.0, 1.0, 1.0, 1.0);
+    assert_eq!(v.abs(), Vec4(1.5, 0.5, 2.5, 3.0));
+    assert_eq!(v.floor(), Vec4(-2.0, 0.0, 2.0, -3.0));
+    assert_eq!(v.ceil(), Vec4(-1.0, 1.0, 3.0, -3.0));
+    assert_eq!(v.min(&w), Vec4(-1.5, 0.5, 1.0, -3.0));
+    assert_eq!(v.max(&w), Vec4(1.0, 1.0, 2.5, 1.0));
+    assert_eq!(v.clamp(&-w, &w), Vec4(-1.0, 0.5, 1.0, -1.0));
+    assert_eq!(v.lerp(&w, 1.0), w);
+    assert_eq!(Vec4(1, -2, 3, -4).abs(), Vec4(1, 2, 3, 4));
+}
+
+#[test]
+fn reflect_and_project() {
+    let n = Vec4(0.0, 1.0, 0.0, 0.0);
+    assert_eq!(
+        Vec4(1.0, -2.0, 3.0, 4.0).reflect(&n),
+        Vec4(1.0, 2.0, 3.0, 4.0)
+    );
+    assert_eq!(
+        Vec4(1.0, -2.0, 3.0, 4.0).project_onto(&(n * 3.0)),
+        Vec4(0.0, -2.0, 0.0, 0.0)
+    );
+    assert_eq!(
+        Vec4(1.0, -2.0, 3.0, 4.0).reject_from(&n),
+        Vec4(1.0, 0.0, 3.0, 4.0)
+    );
+}
+
+#[test]
+fn angle_precision() {
+    let tiny = 1e-10;
+    let v = Vec4(1.0, 0.0, 0.0, 0.0);
+    let w = Vec4(f64::cos(tiny), 0.0, 0.0, f64::sin(tiny)) * 3.0;
+    assert!((v.angle(&w) - tiny).abs() < 1e-20);
+    assert!((v.angle(&-w) - (PI - tiny)).abs() < 1e-15);
+    assert!((v.angle(&Vec4(1.0, 1.0, 1.0, 1.0)) - PI / 3.0).abs() < 1e-15);
+    assert_eq!(v.try_angle(&Vec4(0.0, 0.0, 0.0, 0.0)), None);
+}
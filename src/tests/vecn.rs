@@ -0,0 +1,122 @@
+use crate::{assert_approx_eq, Vec2, Vec3, Vec4, VecN, VecX, VecxError};
+
+#[test]
+fn size() {
+    assert_eq!(VecN::<5>::size(), 5);
+    assert_eq!(VecN::<9>::size(), 9);
+}
+
+#[test]
+fn from() {
+    assert_eq!(VecN::<3>::from(2.0), VecN([2.0, 2.0, 2.0]));
+    assert_eq!(
+        VecN::<5>::from(vec![1.0, 2.0]),
+        VecN([1.0, 2.0, 0.0, 0.0, 0.0])
+    );
+    assert_eq!(VecN::<2>::from(vec![1.0, 2.0, 3.0]), VecN([1.0, 2.0]));
+    assert_eq!(
+        (1..=6).map(f64::from).collect::<VecN<6>>(),
+        VecN([1.0, 2.0, 3.0, 4.0, 5.0, 6.0])
+    );
+    assert_eq!(<[f64; 2]>::from(VecN([1.0, 2.0])), [1.0, 2.0]);
+}
+
+#[test]
+fn fixed_size_conversions() {
+    assert_eq!(Vec2::from(VecN([1.0, 2.0])), Vec2(1.0, 2.0));
+    assert_eq!(Vec3::from(VecN([1.0, 2.0, 3.0])), Vec3(1.0, 2.0, 3.0));
+    assert_eq!(
+        Vec4::from(VecN([1.0, 2.0, 3.0, 4.0])),
+        Vec4(1.0, 2.0, 3.0, 4.0)
+    );
+
+    assert_eq!(VecN::from(Vec2(1.0, 2.0)), VecN([1.0, 2.0]));
+    assert_eq!(VecN::from(Vec3(1.0, 2.0, 3.0)), VecN([1.0, 2.0, 3.0]));
+    assert_eq!(
+        VecN::from(Vec4(1.0, 2.0, 3.0, 4.0)),
+        VecN([1.0, 2.0, 3.0, 4.0])
+    );
+
+    // changing the size is explicit
+    assert_eq!(
+        VecN::<5>::resized(Vec3(1.0, 2.0, 3.0)),
+        VecN([1.0, 2.0, 3.0, 0.0, 0.0])
+    );
+    assert_eq!(
+        VecN::<3>::resized(Vec4(1.0, 2.0, 3.0, 4.0)),
+        VecN([1.0, 2.0, 3.0])
+    );
+}
+
+#[test]
+fn ops() {
+    let a = VecN([1.0, 2.0, 3.0, 4.0, 5.0]);
+    let b = VecN([5.0, 4.0, 3.0, 2.0, 1.0]);
+    assert_eq!(a + b, VecN::from(6.0));
+    assert_eq!(a - a, VecN::zero());
+    assert_eq!(-a + a, VecN::zero());
+    assert_eq!(a * b, VecN([5.0, 8.0, 9.0, 8.0, 5.0]));
+    assert_eq!(a * 2.0, 2.0 * a);
+    assert_eq!(a / 2.0, VecN([0.5, 1.0, 1.5, 2.0, 2.5]));
+    assert_eq!(a / a, VecN::one());
+    assert_eq!(a % 2.0, VecN([1.0, 0.0, 1.0, 0.0, 1.0]));
+    assert_eq!(b % a, VecN([0.0, 0.0, 0.0, 2.0, 1.0]));
+
+    let mut c = a;
+    c += b;
+    c -= b;
+    c *= 2.0;
+    c /= VecN::from(2.0);
+    c %= 10.0;
+    assert_eq!(c, a);
+}
+
+#[test]
+fn checked_ops() {
+    let a = VecN([0.0, 1.0, 2.0, 3.0, 4.0]);
+    assert_eq!(
+        a.checked_div_scalar(0.0),
+        Err(VecxError::ZeroByZeroDivision)
+    );
+    assert_eq!(a.checked_rem(&VecN::zero()), Err(VecxError::ZeroDivisor));
+    assert_eq!(
+        VecN([1, 2, 3, 4, 5]).checked_div_scalar(0),
+        Err(VecxError::IntegerDivisionByZero)
+    );
+}
+
+#[test]
+fn maths() {
+    let a = VecN([1.0, 2.0, 2.0, 4.0, 0.0, 0.0]);
+    assert_eq!(a.magnitude(), 5.0);
+    assert_eq!(a.dot_product(&VecN::one()), 9.0);
+    assert_approx_eq!(a.normalized(), a / 5.0);
+    assert_approx_eq!(a.normalized().magnitude(), 1.0);
+    assert_eq!(
+        VecN::<6>::zero().try_normalized(),
+        Err(VecxError::ZeroMagnitude)
+    );
+    assert_approx_eq!(a.dot(&(a * 3.0)), 1.0);
+}
+
+#[test]
+fn components() {
+    let v = VecN([1.0, 2.0, 3.0, 4.0, 5.0]);
+    assert_eq!(v[4], 5.0);
+    assert_eq!(v.at(4), 5.0);
+    assert_eq!(v.at(5), 0.0);
+    assert_eq!(v.comp('w'), 4.0);
+    assert_eq!(v.s3("zyx"), Vec3(3.0, 2.0, 1.0));
+    assert_eq!(v.s::<VecN<5>>("xxyya"), VecN([1.0, 1.0, 2.0, 2.0, 4.0]));
+    assert_eq!(
+        VecN([1.0, 2.0]).try_comp('z'),
+        Err(VecxError::InvalidComponent('z'))
+    );
+    assert_eq!(v.to_string(), "(1, 2, 3, 4, 5)");
+}
+
+#[test]
+#[should_panic]
+fn invalid_index() {
+    let _ = VecN([1.0, 2.0])[2];
+}
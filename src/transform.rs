@@ -0,0 +1,271 @@
+use crate::mat::SINGULAR_EPSILON;
+use crate::{Mat3, Mat4, Matrix, Quat, Vec3, Vec4, VecX};
+use std::fmt;
+use std::ops;
+
+/*
+    Definition
+*/
+/// Affine transform applying `scale`, then `rotation`, then `translation`
+///
+/// Its matrix is `translate * rotate * scale`, the same order used to build model matrices by hand
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Transform {
+    pub translation: Vec3,
+    pub rotation: Quat,
+    pub scale: Vec3,
+}
+
+impl Default for Transform {
+    fn default() -> Self {
+        Transform::IDENTITY
+    }
+}
+
+impl Transform {
+    pub const IDENTITY: Transform = Transform {
+        translation: Vec3(0.0, 0.0, 0.0),
+        rotation: Quat::IDENTITY,
+        scale: Vec3(1.0, 1.0, 1.0),
+    };
+
+    pub fn new(translation: Vec3, rotation: Quat, scale: Vec3) -> Self {
+        Transform {
+            translation,
+            rotation,
+            scale,
+        }
+    }
+
+    pub fn identity() -> Self {
+        Transform::IDENTITY
+    }
+
+    pub fn from_translation(translation: Vec3) -> Self {
+        Transform {
+            translation,
+            ..Transform::IDENTITY
+        }
+    }
+
+    pub fn from_rotation(rotation: Quat) -> Self {
+        Transform {
+            rotation,
+            ..Transform::IDENTITY
+        }
+    }
+
+    pub fn from_scale(scale: Vec3) -> Self {
+        Transform {
+            scale,
+            ..Transform::IDENTITY
+        }
+    }
+
+    /// Applies scale, rotation and translation to the point `p`
+    pub fn transform_point(&self, p: Vec3) -> Vec3 {
+        self.transform_vector(p) + self.translation
+    }
+
+    /// Applies scale and rotation to the direction `v`, translation is ignored
+    pub fn transform_vector(&self, v: Vec3) -> Vec3 {
+        self.rotation.rotate(self.scale * v)
+    }
+
+    /// Returns the transform applying `other` then `self`, like `self * other`
+    ///
+    /// `None` if `self` has a non-uniform scale and `other` is rotated, scaling a rotated shape
+    /// along other axes introduces shear TRS can't represent, multiply `to_matrix()` instead
+    pub fn compose(&self, other: &Transform) -> Option<Transform> {
+        if !self.has_uniform_scale() && other.is_rotated() {
+            return None;
+        }
+        Some(Transform {
+            translation: self.transform_point(other.translation),
+            rotation: self.rotation * other.rotation,
+            scale: self.scale * other.scale,
+        })
+    }
+
+    /// Returns the transform undoing this one, or `None` if a scale component is 0
+    ///
+    /// Also `None` for a non-uniform scale with a rotation, whose inverse scales after rotating
+    /// and has shear TRS can't represent, use `to_matrix().inverse()` instead
+    pub fn inverse(&self) -> Option<Transform> {
+        let scales = [self.scale.0, self.scale.1, self.scale.2];
+        let largest = scales.iter().fold(0.0, |max: f64, s| max.max(s.abs()));
+        if scales.iter().any(|s| s.abs() <= SINGULAR_EPSILON * largest) {
+            return None;
+        }
+        if self.is_rotated() && !self.has_uniform_scale() {
+            return None;
+        }
+
+        let rotation = self.rotation.conjugate();
+        let scale = Vec3(1.0 / self.scale.0, 1.0 / self.scale.1, 1.0 / self.scale.2);
+        Some(Transform {
+            translation: scale * rotation.rotate(-self.translation),
+            rotation,
+            scale,
+        })
+    }
+
+    /// Whether the scale is the same along every axis, relative to its largest component
+    fn has_uniform_scale(&self) -> bool {
+        let scales = [self.scale.0, self.scale.1, self.scale.2];
+        let largest = scales.iter().fold(0.0, |max: f64, s| max.max(s.abs()));
+        scales
+            .iter()
+            .all(|s| (s - scales[0]).abs() <= SINGULAR_EPSILON * largest)
+    }
+
+    fn is_rotated(&self) -> bool {
+        // q and -q are both the identity
+        self.rotation.vector().magnitude() > SINGULAR_EPSILON
+    }
+
+    /// Interpolates translation and scale linearly and rotation along the shortest arc
+    pub fn interpolate(&self, other: &Transform, t: f64) -> Transform {
+        Transform {
+            translation: self.translation + (other.translation - self.translation) * t,
+            rotation: self.rotation.slerp(&other.rotation, t),
+            scale: self.scale + (other.scale - self.scale) * t,
+        }
+    }
+
+    pub fn to_mat4(&self) -> Mat4 {
+        let rotation = self.rotation.to_mat3();
+        let col = |idx: usize, translation: f64| {
+            Vec4::from((rotation.col(idx) * self.scale[idx], translation))
+        };
+        Mat4::from_cols(
+            col(0, 0.0),
+            col(1, 0.0),
+            col(2, 0.0),
+            Vec4::from((self.translation, 1.0)),
+        )
+    }
+
+    pub fn to_matrix(&self) -> Matrix {
+        Matrix::from(self.to_mat4())
+    }
+
+    /// Decomposes an affine 4x4 matrix into translation, rotation and scale
+    ///
+    /// Returns `None` if the matrix isn't 4x4, has a projective last row or a zero scale, both
+    /// relative to the largest value of the matrix. A negative determinant is stored as a
+    /// negative x scale, shear is discarded.
+    pub fn decompose(mat: &Matrix) -> Option<Transform> {
+        if mat.rows() != 4 || mat.cols() != 4 {
+            return None;
+        }
+        let m = Mat4::from(mat.clone());
+        let largest = mat
+            .as_slice()
+            .iter()
+            .fold(0.0, |max: f64, v| max.max(v.abs()));
+        let last_row = m.row(3) - Vec4(0.0, 0.0, 0.0, 1.0);
+        if last_row.magnitude() > SINGULAR_EPSILON * largest {
+            return None;
+        }
+
+        let mut cols = [
+            Vec3::from(m.col(0)),
+            Vec3::from(m.col(1)),
+            Vec3::from(m.col(2)),
+        ];
+        let mut scale = Vec3(
+            cols[0].magnitude(),
+            cols[1].magnitude(),
+            cols[2].magnitude(),
+        );
+        let largest_scale = scale.0.max(scale.1).max(scale.2);
+        if [scale.0, scale.1, scale.2]
+            .iter()
+            .any(|&s| s <= SINGULAR_EPSILON * largest_scale)
+        {
+            return None;
+        }
+
+        if Mat3::from_cols(cols[0], cols[1], cols[2]).determinant() < 0.0 {
+            scale.0 = -scale.0;
+        }
+        for (idx, col) in cols.iter_mut().enumerate() {
+            *col /= scale[idx];
+        }
+
+        Some(Transform {
+            translation: Vec3::from(m.col(3)),
+            rotation: Quat::from_mat3(Mat3::from_cols(cols[0], cols[1], cols[2])),
+            scale,
+        })
+    }
+}
+
+/*
+    Ops
+*/
+/*
+    Mul
+*/
+/// Composition, `a * b` applies `b` then `a`
+///
+/// Panics if `a` has a non-uniform scale and `b` is rotated, see `compose`
+impl ops::Mul<Transform> for Transform {
+    type Output = Transform;
+
+    fn mul(self, rhs: Transform) -> Self::Output {
+        self.compose(&rhs).unwrap_or_else(|| {
+            panic!(
+                "Composing {} with {} introduces shear, multiply their matrices instead",
+                self, rhs
+            )
+        })
+    }
+}
+
+impl ops::MulAssign<Transform> for Transform {
+    fn mul_assign(&mut self, rhs: Transform) {
+        *self = *self * rhs;
+    }
+}
+
+/*
+    Display
+*/
+impl fmt::Display for Transform {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "[translation: {}, rotation: {}, scale: {}]",
+            self.translation, self.rotation, self.scale
+        )
+    }
+}
+
+/*
+    From
+*/
+impl From<Transform> for Mat4 {
+    fn from(t: Transform) -> Self {
+        t.to_mat4()
+    }
+}
+
+impl From<Transform> for Matrix {
+    fn from(t: Transform) -> Self {
+        t.to_matrix()
+    }
+}
+
+impl From<Matrix> for Transform {
+    fn from(mat: Matrix) -> Self {
+        Transform::decompose(&mat).unwrap_or_else(|| {
+            panic!(
+                "Invalid matrix ({},{}) supplied to Transform::from, provide an invertible (4,4) affine matrix",
+                mat.rows(),
+                mat.cols()
+            )
+        })
+    }
+}
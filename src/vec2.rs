@@ -1,79 +1,106 @@
 use core::fmt;
-use std::ops::{self, Index};
+use std::ops::{self, Index, IndexMut};
 
 use crate::vec3::Vec3;
 use crate::vecx::VecX;
+use crate::{Scalar, VecxError};
 
 /*
     Definition
 */
 #[derive(Debug, Clone, Copy)]
-pub struct Vec2(pub f64, pub f64);
+pub struct Vec2<T: Scalar = f64>(pub T, pub T);
 
-impl Default for Vec2 {
+impl<T: Scalar> Default for Vec2<T> {
     fn default() -> Self {
-        Vec2(0.0, 0.0)
+        Vec2(T::ZERO, T::ZERO)
     }
 }
 
-impl Vec2 {
-    pub fn x(&self) -> f64 {
+impl<T: Scalar> Vec2<T> {
+    pub fn x(&self) -> T {
         self.0
     }
 
-    pub fn y(&self) -> f64 {
+    pub fn y(&self) -> T {
         self.1
     }
+
+    /// Converts every component to `U`, integers are truncated toward zero
+    ///
+    /// Use `From` for lossless conversions
+    pub fn cast<U: Scalar>(&self) -> Vec2<U> {
+        Vec2(U::from_f64(self.0.to_f64()), U::from_f64(self.1.to_f64()))
+    }
+
+    /// Angle in radians from `self` to `other`, positive counterclockwise, from -PI to PI
+    ///
+    /// Panics if either vector is zero, see `try_signed_angle`
+    pub fn signed_angle(&self, other: &Self) -> f64 {
+        self.try_signed_angle(other)
+            .unwrap_or_else(|| panic!("{}, {}: {}", self, other, VecxError::ZeroMagnitude))
+    }
+
+    /// Angle in radians from `self` to `other`, positive counterclockwise, from -PI to PI,
+    /// or `None` if either vector is zero
+    pub fn try_signed_angle(&self, other: &Self) -> Option<f64> {
+        if self.magnitude() == 0.0 || other.magnitude() == 0.0 {
+            return None;
+        }
+        let (a, b) = (self.cast::<f64>(), other.cast::<f64>());
+        Some(f64::atan2(a.0 * b.1 - a.1 * b.0, a.dot_product(&b)))
+    }
 }
 
-impl FromIterator<f64> for Vec2 {
-    fn from_iter<T: IntoIterator<Item = f64>>(iter: T) -> Self {
-        let values: Vec<f64> = iter.into_iter().collect();
+impl<T: Scalar> FromIterator<T> for Vec2<T> {
+    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
+        let values: Vec<T> = iter.into_iter().collect();
         Vec2::from(values)
     }
 }
 
-impl VecX for Vec2 {
+impl<T: Scalar> VecX<T> for Vec2<T> {
     /*
         Maths
     */
-    fn dot_product(&self, other: &Self) -> f64 {
-        return self.0 * other.0 + self.1 * other.1;
+    fn dot_product(&self, other: &Self) -> T {
+        self.0 * other.0 + self.1 * other.1
     }
 
-    fn normalized(&self) -> Vec2 {
+    fn try_angle(&self, other: &Self) -> Option<f64> {
+        self.try_signed_angle(other).map(f64::abs)
+    }
+
+    fn try_normalized(&self) -> Result<Vec2<T>, VecxError> {
         let m = self.magnitude();
         if m == 0.0 {
-            panic!("{}: Can't be normalized because its magnitude is 0", self);
+            return Err(VecxError::ZeroMagnitude);
         }
-        *self / m
+        Ok(Vec2(
+            T::from_f64(self.0.to_f64() / m),
+            T::from_f64(self.1.to_f64() / m),
+        ))
     }
-    fn magnitude(&self) -> f64 {
-        let x = self.x();
-        let y = self.y();
-        f64::sqrt(x * x + y * y)
+
+    fn checked_div(&self, rhs: &Self) -> Result<Vec2<T>, VecxError> {
+        Ok(Vec2(self.0.try_div(rhs.0)?, self.1.try_div(rhs.1)?))
     }
 
-    /*
-        Accessors
-    */
-    fn comp(&self, component: char) -> f64 {
-        match component {
-            'x' => self.0,
-            'y' => self.1,
-            _ => panic!(
-                "Attempt to access invalid component '{}' of {:?}",
-                component, self
-            ),
-        }
+    fn checked_div_scalar(&self, rhs: T) -> Result<Vec2<T>, VecxError> {
+        Ok(Vec2(self.0.try_div(rhs)?, self.1.try_div(rhs)?))
     }
 
-    fn at(&self, idx: usize) -> f64 {
-        if idx < 2 {
-            self[idx]
-        } else {
-            0.0
-        }
+    fn checked_rem(&self, rhs: &Self) -> Result<Vec2<T>, VecxError> {
+        Ok(Vec2(self.0.try_rem(rhs.0)?, self.1.try_rem(rhs.1)?))
+    }
+
+    fn checked_rem_scalar(&self, rhs: T) -> Result<Vec2<T>, VecxError> {
+        Ok(Vec2(self.0.try_rem(rhs)?, self.1.try_rem(rhs)?))
+    }
+    fn magnitude(&self) -> f64 {
+        let x = self.x().to_f64();
+        let y = self.y().to_f64();
+        f64::sqrt(x * x + y * y)
     }
 
     /*
@@ -90,7 +117,7 @@ impl VecX for Vec2 {
 /*
     Neg
 */
-impl ops::Neg for Vec2 {
+impl<T: Scalar> ops::Neg for Vec2<T> {
     type Output = Self;
 
     fn neg(self) -> Self::Output {
@@ -101,16 +128,16 @@ impl ops::Neg for Vec2 {
 /*
     Add
 */
-impl ops::Add<Vec2> for Vec2 {
-    type Output = Vec2;
+impl<T: Scalar> ops::Add<Vec2<T>> for Vec2<T> {
+    type Output = Vec2<T>;
 
-    fn add(self, rhs: Vec2) -> Vec2 {
+    fn add(self, rhs: Vec2<T>) -> Vec2<T> {
         Vec2(self.0 + rhs.0, self.1 + rhs.1)
     }
 }
 
-impl ops::AddAssign<Vec2> for Vec2 {
-    fn add_assign(&mut self, rhs: Vec2) {
+impl<T: Scalar> ops::AddAssign<Vec2<T>> for Vec2<T> {
+    fn add_assign(&mut self, rhs: Vec2<T>) {
         self.0 += rhs.0;
         self.1 += rhs.1;
     }
@@ -119,15 +146,15 @@ impl ops::AddAssign<Vec2> for Vec2 {
 /*
     Sub
 */
-impl ops::Sub<Self> for Vec2 {
-    type Output = Vec2;
+impl<T: Scalar> ops::Sub<Self> for Vec2<T> {
+    type Output = Vec2<T>;
 
-    fn sub(self, rhs: Vec2) -> Self::Output {
+    fn sub(self, rhs: Vec2<T>) -> Self::Output {
         Vec2(self.0 - rhs.0, self.1 - rhs.1)
     }
 }
 
-impl ops::SubAssign<Vec2> for Vec2 {
+impl<T: Scalar> ops::SubAssign<Vec2<T>> for Vec2<T> {
     fn sub_assign(&mut self, rhs: Self) {
         self.0 -= rhs.0;
         self.1 -= rhs.1;
@@ -137,39 +164,45 @@ impl ops::SubAssign<Vec2> for Vec2 {
 /*
     Mul
 */
-impl ops::Mul<f64> for Vec2 {
-    type Output = Vec2;
+impl<T: Scalar> ops::Mul<T> for Vec2<T> {
+    type Output = Vec2<T>;
 
-    fn mul(self, rhs: f64) -> Self::Output {
+    fn mul(self, rhs: T) -> Self::Output {
         Vec2(self.0 * rhs, self.1 * rhs)
     }
 }
 
-impl ops::Mul<Vec2> for f64 {
-    type Output = Vec2;
+macro_rules! impl_scalar_mul {
+    ($($t:ty),*) => {$(
+        impl ops::Mul<Vec2<$t>> for $t {
+            type Output = Vec2<$t>;
 
-    fn mul(self, rhs: Vec2) -> Self::Output {
-        Vec2(self * rhs.0, self * rhs.1)
-    }
+            fn mul(self, rhs: Vec2<$t>) -> Self::Output {
+                Vec2(self * rhs.0, self * rhs.1)
+            }
+        }
+    )*};
 }
 
-impl ops::Mul<Vec2> for Vec2 {
-    type Output = Vec2;
+impl_scalar_mul!(f32, f64, i32, i64);
+
+impl<T: Scalar> ops::Mul<Vec2<T>> for Vec2<T> {
+    type Output = Vec2<T>;
 
-    fn mul(self, rhs: Vec2) -> Self::Output {
+    fn mul(self, rhs: Vec2<T>) -> Self::Output {
         Vec2(self.0 * rhs.0, self.1 * rhs.1)
     }
 }
 
-impl ops::MulAssign<f64> for Vec2 {
-    fn mul_assign(&mut self, rhs: f64) {
+impl<T: Scalar> ops::MulAssign<T> for Vec2<T> {
+    fn mul_assign(&mut self, rhs: T) {
         self.0 *= rhs;
         self.1 *= rhs;
     }
 }
 
-impl ops::MulAssign<Vec2> for Vec2 {
-    fn mul_assign(&mut self, rhs: Vec2) {
+impl<T: Scalar> ops::MulAssign<Vec2<T>> for Vec2<T> {
+    fn mul_assign(&mut self, rhs: Vec2<T>) {
         self.0 *= rhs.0;
         self.1 *= rhs.1;
     }
@@ -178,125 +211,82 @@ impl ops::MulAssign<Vec2> for Vec2 {
 /*
     Div
 */
-impl ops::Div<f64> for Vec2 {
-    type Output = Vec2;
+impl<T: Scalar> ops::Div<T> for Vec2<T> {
+    type Output = Vec2<T>;
 
-    fn div(self, rhs: f64) -> Self::Output {
-        if (self.0 == 0.0 || self.1 == 0.0) && rhs == 0.0 {
-            panic!("Division of 0.0 by 0.0: {} / {}", self, rhs);
-        }
-
-        Vec2(self.0 / rhs, self.1 / rhs)
+    fn div(self, rhs: T) -> Self::Output {
+        self.checked_div_scalar(rhs)
+            .unwrap_or_else(|e| panic!("{}: {} / {}", e, self, rhs))
     }
 }
 
-impl ops::Div<Vec2> for Vec2 {
-    type Output = Vec2;
+impl<T: Scalar> ops::Div<Vec2<T>> for Vec2<T> {
+    type Output = Vec2<T>;
 
-    fn div(self, rhs: Vec2) -> Self::Output {
-        if self.0 == 0.0 && rhs.0 == 0.0 || self.1 == 0.0 && rhs.1 == 0.0 {
-            panic!("Division of 0.0 by 0.0: {} / {}", self, rhs);
-        }
-        Vec2(self.0 / rhs.0, self.1 / rhs.1)
+    fn div(self, rhs: Vec2<T>) -> Self::Output {
+        self.checked_div(&rhs)
+            .unwrap_or_else(|e| panic!("{}: {} / {}", e, self, rhs))
     }
 }
 
-impl ops::DivAssign<f64> for Vec2 {
-    fn div_assign(&mut self, rhs: f64) {
-        if (self.0 == 0.0 || self.1 == 0.0) && rhs == 0.0 {
-            panic!("Division of 0.0 by 0.0: {} / {}", self, rhs);
-        }
-
-        self.0 /= rhs;
-        self.1 /= rhs;
+impl<T: Scalar> ops::DivAssign<T> for Vec2<T> {
+    fn div_assign(&mut self, rhs: T) {
+        *self = *self / rhs;
     }
 }
 
-impl ops::DivAssign<Vec2> for Vec2 {
-    fn div_assign(&mut self, rhs: Vec2) {
-        if self.0 == 0.0 && rhs.0 == 0.0 || self.1 == 0.0 && rhs.1 == 0.0 {
-            panic!("Division of 0.0 by 0.0: {} / {}", self, rhs);
-        }
-
-        self.0 /= rhs.0;
-        self.1 /= rhs.1;
+impl<T: Scalar> ops::DivAssign<Vec2<T>> for Vec2<T> {
+    fn div_assign(&mut self, rhs: Vec2<T>) {
+        *self = *self / rhs;
     }
 }
 
 /*
     Rem
 */
-impl ops::Rem<f64> for Vec2 {
-    type Output = Vec2;
-
-    fn rem(self, rhs: f64) -> Self::Output {
-        if rhs == 0.0 {
-            panic!(
-                "{:?} % {:?}: Attempt to find remainder with a divisor of 0",
-                self, rhs
-            );
-        }
-        Vec2(self.0 % rhs, self.1 % rhs)
+impl<T: Scalar> ops::Rem<T> for Vec2<T> {
+    type Output = Vec2<T>;
+
+    fn rem(self, rhs: T) -> Self::Output {
+        self.checked_rem_scalar(rhs)
+            .unwrap_or_else(|e| panic!("{:?} % {:?}: {}", self, rhs, e))
     }
 }
 
-impl ops::Rem<Vec2> for Vec2 {
-    type Output = Vec2;
+impl<T: Scalar> ops::Rem<Vec2<T>> for Vec2<T> {
+    type Output = Vec2<T>;
 
-    fn rem(self, rhs: Vec2) -> Self::Output {
-        if rhs.0 == 0.0 || rhs.1 == 0.0 {
-            panic!(
-                "{:?} % {:?}: Attempt to find remainder with a divisor of 0",
-                self, rhs
-            );
-        }
-        Vec2(self.0 % rhs.0, self.1 % rhs.1)
+    fn rem(self, rhs: Vec2<T>) -> Self::Output {
+        self.checked_rem(&rhs)
+            .unwrap_or_else(|e| panic!("{:?} % {:?}: {}", self, rhs, e))
     }
 }
 
-impl ops::RemAssign<f64> for Vec2 {
-    fn rem_assign(&mut self, rhs: f64) {
-        if rhs == 0.0 {
-            panic!(
-                "Attempt to assign remainder with zero divisor: {:?} % {:?}",
-                &self, &rhs
-            );
-        }
-
-        self.0 %= rhs;
-        self.1 %= rhs;
+impl<T: Scalar> ops::RemAssign<T> for Vec2<T> {
+    fn rem_assign(&mut self, rhs: T) {
+        *self = *self % rhs;
     }
 }
 
-impl ops::RemAssign<Vec2> for Vec2 {
-    fn rem_assign(&mut self, rhs: Vec2) {
-        if rhs.0 == 0.0 || rhs.1 == 0.0 {
-            panic!(
-                "Attempt to assign remainder with zero divisor: {:?} % {:?}",
-                &self, &rhs
-            );
-        }
-
-        self.0 %= rhs.0;
-        self.1 %= rhs.1;
+impl<T: Scalar> ops::RemAssign<Vec2<T>> for Vec2<T> {
+    fn rem_assign(&mut self, rhs: Vec2<T>) {
+        *self = *self % rhs;
     }
 }
 
 /*
     Equality
 */
-impl PartialEq for Vec2 {
+impl<T: Scalar> PartialEq for Vec2<T> {
     fn eq(&self, other: &Self) -> bool {
         self.0 == other.0 && self.1 == other.1
     }
 }
 
-impl Eq for Vec2 {}
-
 /*
     Display
 */
-impl fmt::Display for Vec2 {
+impl<T: Scalar> fmt::Display for Vec2<T> {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         write!(f, "({}, {})", self.0, self.1)
     }
@@ -305,10 +295,10 @@ impl fmt::Display for Vec2 {
 /*
     Index
 */
-impl Index<usize> for Vec2 {
-    type Output = f64;
+impl<T: Scalar> Index<usize> for Vec2<T> {
+    type Output = T;
 
-    fn index(&self, idx: usize) -> &f64 {
+    fn index(&self, idx: usize) -> &T {
         match idx {
             0 => &self.0,
             1 => &self.1,
@@ -317,26 +307,48 @@ impl Index<usize> for Vec2 {
     }
 }
 
+impl<T: Scalar> IndexMut<usize> for Vec2<T> {
+    fn index_mut(&mut self, idx: usize) -> &mut T {
+        match idx {
+            0 => &mut self.0,
+            1 => &mut self.1,
+            _ => panic!("Attempt to access vector {} by invalid index {}", self, idx),
+        }
+    }
+}
+
 /*
     From
 */
-impl From<f64> for Vec2 {
-    fn from(f: f64) -> Self {
+impl<T: Scalar> From<T> for Vec2<T> {
+    fn from(f: T) -> Self {
         Vec2(f, f)
     }
 }
 
-impl From<Vec3> for Vec2 {
-    fn from(v: Vec3) -> Self {
+impl<T: Scalar> From<Vec3<T>> for Vec2<T> {
+    fn from(v: Vec3<T>) -> Self {
         Vec2(v.0, v.1)
     }
 }
 
-impl From<Vec<f64>> for Vec2 {
-    fn from(values: Vec<f64>) -> Self {
+impl<T: Scalar> From<Vec<T>> for Vec2<T> {
+    fn from(values: Vec<T>) -> Self {
         Vec2(
-            *values.get(0).unwrap_or(&0.0),
-            *values.get(1).unwrap_or(&0.0),
+            *values.first().unwrap_or(&T::ZERO),
+            *values.get(1).unwrap_or(&T::ZERO),
         )
     }
 }
+
+macro_rules! impl_lossless_from {
+    ($(($from:ty, $to:ty)),*) => {$(
+        impl From<Vec2<$from>> for Vec2<$to> {
+            fn from(v: Vec2<$from>) -> Self {
+                Vec2(<$to>::from(v.0), <$to>::from(v.1))
+            }
+        }
+    )*};
+}
+
+impl_lossless_from!((f32, f64), (i32, f64), (i32, i64));
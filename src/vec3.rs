@@ -1,39 +1,40 @@
-use crate::{Matrix, Vec2, Vec4, VecX};
+use crate::mat::SINGULAR_EPSILON;
+use crate::{Matrix, Scalar, Vec2, Vec4, VecX, VecxError};
 use std::fmt;
-use std::ops::{self, Index};
+use std::ops::{self, Index, IndexMut};
 
 /*
     Definition
 */
 #[derive(Debug, Clone, Copy)]
-pub struct Vec3(pub f64, pub f64, pub f64);
+pub struct Vec3<T: Scalar = f64>(pub T, pub T, pub T);
 
-impl Default for Vec3 {
+impl<T: Scalar> Default for Vec3<T> {
     fn default() -> Self {
-        Vec3(0.0, 0.0, 0.0)
+        Vec3(T::ZERO, T::ZERO, T::ZERO)
     }
 }
 
-impl Vec3 {
-    pub const ZERO: Vec3 = Vec3(0.0, 0.0, 0.0);
+impl<T: Scalar> Vec3<T> {
+    pub const ZERO: Vec3<T> = Vec3(T::ZERO, T::ZERO, T::ZERO);
 
     pub fn zero() -> Self {
-        Vec3(0.0, 0.0, 0.0)
+        Vec3(T::ZERO, T::ZERO, T::ZERO)
     }
 
     pub fn one() -> Self {
-        Vec3(1.0, 1.0, 1.0)
+        Vec3(T::ONE, T::ONE, T::ONE)
     }
 
-    pub fn x(&self) -> f64 {
+    pub fn x(&self) -> T {
         self.0
     }
 
-    pub fn y(&self) -> f64 {
+    pub fn y(&self) -> T {
         self.1
     }
 
-    pub fn z(&self) -> f64 {
+    pub fn z(&self) -> T {
         self.2
     }
 
@@ -50,6 +51,41 @@ impl Vec3 {
         )
     }
 
+    /// Converts every component to `U`, integers are truncated toward zero
+    ///
+    /// Use `From` for lossless conversions
+    pub fn cast<U: Scalar>(&self) -> Vec3<U> {
+        Vec3(
+            U::from_f64(self.0.to_f64()),
+            U::from_f64(self.1.to_f64()),
+            U::from_f64(self.2.to_f64()),
+        )
+    }
+}
+
+impl Vec3 {
+    /// Returns the orthonormal `(forward, right, up)` basis of a left-handed camera
+    /// looking along `self`, with `up` as the approximate up direction.
+    ///
+    /// Panics if `self` is zero or parallel to `up`.
+    pub fn look_basis_lh(&self, up: &Vec3) -> (Vec3, Vec3, Vec3) {
+        let forward = self.normalized();
+        let right = up.cross(&forward).normalized();
+        let up = forward.cross(&right);
+        (forward, right, up)
+    }
+
+    /// Returns the orthonormal `(forward, right, up)` basis of a right-handed camera
+    /// looking along `self`, with `up` as the approximate up direction.
+    ///
+    /// Panics if `self` is zero or parallel to `up`.
+    pub fn look_basis_rh(&self, up: &Vec3) -> (Vec3, Vec3, Vec3) {
+        let forward = self.normalized();
+        let right = forward.cross(up).normalized();
+        let up = right.cross(&forward);
+        (forward, right, up)
+    }
+
     pub fn rot(&self, rot: &Vec3) -> Vec3 {
         let mut rotated = self.rot_x(rot.x());
         rotated = rotated.rot_y(rot.y());
@@ -60,48 +96,119 @@ impl Vec3 {
     pub fn rot_x(&self, angle: f64) -> Self {
         let cos = f64::cos(angle);
         let sin = f64::sin(angle);
-        let rot = Vec3(
+        Vec3(
             self.x(),
             self.y() * cos + self.z() * sin,
             self.y() * -sin + self.z() * cos,
-        );
-        rot
+        )
     }
 
     pub fn rot_y(&self, angle: f64) -> Self {
         let cos = f64::cos(angle);
         let sin = f64::sin(angle);
-        let rot = Vec3(
+        Vec3(
             self.x() * cos + self.z() * -sin,
             self.y(),
             self.x() * sin + self.z() * cos,
-        );
-
-        rot
+        )
     }
 
     pub fn rot_z(&self, angle: f64) -> Self {
         let cos = f64::cos(angle);
         let sin = f64::sin(angle);
-        let rot_z = Vec3(
+        Vec3(
             self.x() * cos + self.y() * sin,
             self.x() * -sin + self.y() * cos,
             self.z(),
-        );
+        )
+    }
+
+    /// Rotates `self` by `angle` radians around `axis` using Rodrigues' formula
+    ///
+    /// Same direction as `Matrix::m4_rotate_x/y` and `Quat::from_axis_angle`, so `rot_x(a)` is
+    /// `rotate_around(&Vec3(1.0, 0.0, 0.0), -a)`. Panics if `axis` is zero.
+    pub fn rotate_around(&self, axis: &Vec3, angle: f64) -> Self {
+        let k = axis.normalized();
+        let cos = f64::cos(angle);
+        let sin = f64::sin(angle);
+        *self * cos + k.cross(self) * sin + k * (k.dot_product(self) * (1.0 - cos))
+    }
 
-        rot_z
+    /// Angle in radians of the rotation around `axis` that brings `self` onto `other`, once both
+    /// are projected on the plane orthogonal to `axis`, from -PI to PI
+    ///
+    /// Positive following the right-hand rule like `rotate_around`, so
+    /// `v.signed_angle_around(&v.rotate_around(&axis, a), &axis)` is `a` for `a` from -PI to PI.
+    /// Panics if `axis` is zero or either vector is parallel to it, see `try_signed_angle_around`
+    pub fn signed_angle_around(&self, other: &Vec3, axis: &Vec3) -> f64 {
+        self.try_signed_angle_around(other, axis)
+            .unwrap_or_else(|| {
+                panic!(
+                    "{}, {} around {}: {}",
+                    self,
+                    other,
+                    axis,
+                    VecxError::ZeroMagnitude
+                )
+            })
+    }
+
+    /// Same as `signed_angle_around`, or `None` if `axis` is zero or either vector is zero
+    /// or parallel to it
+    pub fn try_signed_angle_around(&self, other: &Vec3, axis: &Vec3) -> Option<f64> {
+        let axis = axis.try_normalized().ok()?;
+        let (a, b) = (self.reject_from(&axis), other.reject_from(&axis));
+        let parallel =
+            |v: Vec3, projected: Vec3| projected.magnitude() <= SINGULAR_EPSILON * v.magnitude();
+        if parallel(*self, a) || parallel(*other, b) {
+            return None;
+        }
+        Some(f64::atan2(
+            a.cross(&b).dot_product(&axis),
+            a.dot_product(&b),
+        ))
+    }
+
+    /// Spherical linear interpolation between unit vectors, at constant angular speed
+    ///
+    /// Antiparallel vectors have no single shortest arc, they're rotated around an arbitrary
+    /// axis orthogonal to `self`. Falls back to `nlerp` when both are almost identical.
+    pub fn slerp(&self, other: &Vec3, t: f64) -> Vec3 {
+        let axis = self.cross(other);
+        let cos = self.dot_product(other);
+        let sin = axis.magnitude();
+        if sin <= SINGULAR_EPSILON {
+            if cos > 0.0 {
+                return self.nlerp(other, t);
+            }
+            return self.rotate_around(&self.orthogonal(), std::f64::consts::PI * t);
+        }
+        self.rotate_around(&axis, f64::atan2(sin, cos) * t)
+    }
+
+    /// Some vector orthogonal to `self`, zero if `self` is zero
+    pub fn orthogonal(&self) -> Vec3 {
+        // avoiding the axis of the largest component keeps the result at least |self| / √3 long
+        let axis = if self.0.abs() < self.1.abs().max(self.2.abs()) {
+            Vec3(1.0, 0.0, 0.0)
+        } else {
+            Vec3(0.0, 1.0, 0.0)
+        };
+        self.cross(&axis)
     }
 
     pub fn as_homogenous(&self, w: f64) -> Vec4 {
         Vec4::from((*self, w))
     }
 
+    /// Direction with `w = 0.0`, so translations don't apply to it
     pub fn as_homogenous_vector(&self) -> Vec4 {
-        self.as_homogenous(1.0)
+        self.as_homogenous(0.0)
     }
 
+    /// Position with `w = 1.0`, so translations apply to it
     pub fn as_homogenous_point(&self) -> Vec4 {
-        self.as_homogenous(0.0)
+        self.as_homogenous(1.0)
     }
 
     pub fn as_mat4(&self, w: f64) -> Matrix {
@@ -109,64 +216,80 @@ impl Vec3 {
     }
 }
 
-impl FromIterator<f64> for Vec3 {
-    fn from_iter<T: IntoIterator<Item = f64>>(iter: T) -> Self {
-        let values: Vec<f64> = iter.into_iter().collect();
+impl<T: Scalar> FromIterator<T> for Vec3<T> {
+    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
+        let values: Vec<T> = iter.into_iter().collect();
         Vec3::from(values)
     }
 }
 
-impl VecX for Vec3 {
+impl<T: Scalar> VecX<T> for Vec3<T> {
     /*
         Maths
     */
 
-    fn dot_product(&self, other: &Self) -> f64 {
-        return self.0 * other.0 + self.1 * other.1 + self.2 * other.2;
+    fn dot_product(&self, other: &Self) -> T {
+        self.0 * other.0 + self.1 * other.1 + self.2 * other.2
+    }
+
+    fn try_angle(&self, other: &Self) -> Option<f64> {
+        if self.magnitude() == 0.0 || other.magnitude() == 0.0 {
+            return None;
+        }
+        let (a, b) = (self.cast::<f64>(), other.cast::<f64>());
+        Some(f64::atan2(a.cross(&b).magnitude(), a.dot_product(&b)))
     }
 
-    fn normalized(&self) -> Vec3 {
+    fn try_normalized(&self) -> Result<Vec3<T>, VecxError> {
         let m = self.magnitude();
         if m == 0.0 {
-            panic!("{}: Can't be normalized because its magnitude is 0", self);
+            return Err(VecxError::ZeroMagnitude);
         }
-        Vec3(self.x() / m, self.y() / m, self.z() / m)
+        Ok(Vec3(
+            T::from_f64(self.x().to_f64() / m),
+            T::from_f64(self.y().to_f64() / m),
+            T::from_f64(self.z().to_f64() / m),
+        ))
     }
 
-    fn magnitude(&self) -> f64 {
-        let x = self.x();
-        let y = self.y();
-        let z = self.z();
+    fn checked_div(&self, rhs: &Self) -> Result<Vec3<T>, VecxError> {
+        Ok(Vec3(
+            self.0.try_div(rhs.0)?,
+            self.1.try_div(rhs.1)?,
+            self.2.try_div(rhs.2)?,
+        ))
+    }
 
-        f64::sqrt(x * x + y * y + z * z)
+    fn checked_div_scalar(&self, rhs: T) -> Result<Vec3<T>, VecxError> {
+        Ok(Vec3(
+            self.0.try_div(rhs)?,
+            self.1.try_div(rhs)?,
+            self.2.try_div(rhs)?,
+        ))
     }
 
-    /*
-        Accessors
-    */
-    fn comp(&self, component: char) -> f64 {
-        match component {
-            'x' => self.0,
-            'y' => self.1,
-            'z' => self.2,
-            'r' => self.0,
-            'g' => self.1,
-            'b' => self.2,
-            _ => {
-                panic!(
-                    "Attempt to access invalid component '{}' of {:?}",
-                    component, self
-                )
-            }
-        }
+    fn checked_rem(&self, rhs: &Self) -> Result<Vec3<T>, VecxError> {
+        Ok(Vec3(
+            self.0.try_rem(rhs.0)?,
+            self.1.try_rem(rhs.1)?,
+            self.2.try_rem(rhs.2)?,
+        ))
     }
 
-    fn at(&self, idx: usize) -> f64 {
-        if idx < 3 {
-            self[idx]
-        } else {
-            0.0
-        }
+    fn checked_rem_scalar(&self, rhs: T) -> Result<Vec3<T>, VecxError> {
+        Ok(Vec3(
+            self.0.try_rem(rhs)?,
+            self.1.try_rem(rhs)?,
+            self.2.try_rem(rhs)?,
+        ))
+    }
+
+    fn magnitude(&self) -> f64 {
+        let x = self.x().to_f64();
+        let y = self.y().to_f64();
+        let z = self.z().to_f64();
+
+        f64::sqrt(x * x + y * y + z * z)
     }
 
     /*
@@ -183,7 +306,7 @@ impl VecX for Vec3 {
 /*
     Neg
 */
-impl ops::Neg for Vec3 {
+impl<T: Scalar> ops::Neg for Vec3<T> {
     type Output = Self;
 
     fn neg(self) -> Self::Output {
@@ -194,16 +317,16 @@ impl ops::Neg for Vec3 {
 /*
     Add
 */
-impl ops::Add<Vec3> for Vec3 {
-    type Output = Vec3;
+impl<T: Scalar> ops::Add<Vec3<T>> for Vec3<T> {
+    type Output = Vec3<T>;
 
-    fn add(self, rhs: Vec3) -> Vec3 {
+    fn add(self, rhs: Vec3<T>) -> Vec3<T> {
         Vec3(self.0 + rhs.0, self.1 + rhs.1, self.2 + rhs.2)
     }
 }
 
-impl ops::AddAssign<Vec3> for Vec3 {
-    fn add_assign(&mut self, rhs: Vec3) {
+impl<T: Scalar> ops::AddAssign<Vec3<T>> for Vec3<T> {
+    fn add_assign(&mut self, rhs: Vec3<T>) {
         self.0 += rhs.0;
         self.1 += rhs.1;
         self.2 += rhs.2;
@@ -213,16 +336,16 @@ impl ops::AddAssign<Vec3> for Vec3 {
 /*
     Sub
 */
-impl ops::Sub<Self> for Vec3 {
+impl<T: Scalar> ops::Sub<Self> for Vec3<T> {
     type Output = Self;
 
-    fn sub(self, rhs: Vec3) -> Self::Output {
+    fn sub(self, rhs: Vec3<T>) -> Self::Output {
         Vec3(self.0 - rhs.0, self.1 - rhs.1, self.2 - rhs.2)
     }
 }
 
-impl ops::SubAssign<Vec3> for Vec3 {
-    fn sub_assign(&mut self, rhs: Vec3) {
+impl<T: Scalar> ops::SubAssign<Vec3<T>> for Vec3<T> {
+    fn sub_assign(&mut self, rhs: Vec3<T>) {
         self.0 -= rhs.0;
         self.1 -= rhs.1;
         self.2 -= rhs.2;
@@ -232,40 +355,46 @@ impl ops::SubAssign<Vec3> for Vec3 {
 /*
     Mul
 */
-impl ops::Mul<f64> for Vec3 {
-    type Output = Vec3;
+impl<T: Scalar> ops::Mul<T> for Vec3<T> {
+    type Output = Vec3<T>;
 
-    fn mul(self, rhs: f64) -> Self::Output {
+    fn mul(self, rhs: T) -> Self::Output {
         Vec3(self.0 * rhs, self.1 * rhs, self.2 * rhs)
     }
 }
 
-impl ops::Mul<Vec3> for f64 {
-    type Output = Vec3;
+macro_rules! impl_scalar_mul {
+    ($($t:ty),*) => {$(
+        impl ops::Mul<Vec3<$t>> for $t {
+            type Output = Vec3<$t>;
 
-    fn mul(self, rhs: Vec3) -> Self::Output {
-        Vec3(self * rhs.0, self * rhs.1, self * rhs.2)
-    }
+            fn mul(self, rhs: Vec3<$t>) -> Self::Output {
+                Vec3(self * rhs.0, self * rhs.1, self * rhs.2)
+            }
+        }
+    )*};
 }
 
-impl ops::Mul<Vec3> for Vec3 {
-    type Output = Vec3;
+impl_scalar_mul!(f32, f64, i32, i64);
+
+impl<T: Scalar> ops::Mul<Vec3<T>> for Vec3<T> {
+    type Output = Vec3<T>;
 
-    fn mul(self, rhs: Vec3) -> Self::Output {
+    fn mul(self, rhs: Vec3<T>) -> Self::Output {
         Vec3(self.0 * rhs.0, self.1 * rhs.1, self.2 * rhs.2)
     }
 }
 
-impl ops::MulAssign<f64> for Vec3 {
-    fn mul_assign(&mut self, rhs: f64) {
+impl<T: Scalar> ops::MulAssign<T> for Vec3<T> {
+    fn mul_assign(&mut self, rhs: T) {
         self.0 *= rhs;
         self.1 *= rhs;
         self.2 *= rhs;
     }
 }
 
-impl ops::MulAssign<Vec3> for Vec3 {
-    fn mul_assign(&mut self, rhs: Vec3) {
+impl<T: Scalar> ops::MulAssign<Vec3<T>> for Vec3<T> {
+    fn mul_assign(&mut self, rhs: Vec3<T>) {
         self.0 *= rhs.0;
         self.1 *= rhs.1;
         self.2 *= rhs.2;
@@ -275,136 +404,82 @@ impl ops::MulAssign<Vec3> for Vec3 {
 /*
     Div
 */
-impl ops::Div<f64> for Vec3 {
-    type Output = Vec3;
+impl<T: Scalar> ops::Div<T> for Vec3<T> {
+    type Output = Vec3<T>;
 
-    fn div(self, rhs: f64) -> Self::Output {
-        if (self.0 == 0.0 || self.1 == 0.0 || self.2 == 0.0) && rhs == 0.0 {
-            panic!("Division of 0.0 by 0.0: {} / {}", self, rhs);
-        }
-
-        Vec3(self.0 / rhs, self.1 / rhs, self.2 / rhs)
+    fn div(self, rhs: T) -> Self::Output {
+        self.checked_div_scalar(rhs)
+            .unwrap_or_else(|e| panic!("{}: {} / {}", e, self, rhs))
     }
 }
 
-impl ops::Div<Vec3> for Vec3 {
-    type Output = Vec3;
-
-    fn div(self, rhs: Vec3) -> Self::Output {
-        if self.0 == 0.0 && rhs.0 == 0.0
-            || self.1 == 0.0 && rhs.1 == 0.0
-            || self.2 == 0.0 && rhs.2 == 0.0
-        {
-            panic!("Division of 0.0 by 0.0: {} / {}", self, rhs);
-        }
+impl<T: Scalar> ops::Div<Vec3<T>> for Vec3<T> {
+    type Output = Vec3<T>;
 
-        Vec3(self.0 / rhs.0, self.1 / rhs.1, self.2 / rhs.2)
+    fn div(self, rhs: Vec3<T>) -> Self::Output {
+        self.checked_div(&rhs)
+            .unwrap_or_else(|e| panic!("{}: {} / {}", e, self, rhs))
     }
 }
 
-impl ops::DivAssign<f64> for Vec3 {
-    fn div_assign(&mut self, rhs: f64) {
-        if (self.0 == 0.0 || self.1 == 0.0 || self.2 == 0.0) && rhs == 0.0 {
-            panic!("Division of 0.0 by 0.0: {} / {}", self, rhs);
-        }
-
-        self.0 /= rhs;
-        self.1 /= rhs;
-        self.2 /= rhs;
+impl<T: Scalar> ops::DivAssign<T> for Vec3<T> {
+    fn div_assign(&mut self, rhs: T) {
+        *self = *self / rhs;
     }
 }
 
-impl ops::DivAssign<Vec3> for Vec3 {
-    fn div_assign(&mut self, rhs: Vec3) {
-        if self.0 == 0.0 && rhs.0 == 0.0
-            || self.1 == 0.0 && rhs.1 == 0.0
-            || self.2 == 0.0 && rhs.2 == 0.0
-        {
-            panic!("Division of 0.0 by 0.0: {} / {}", self, rhs);
-        }
-
-        self.0 /= rhs.0;
-        self.1 /= rhs.1;
-        self.2 /= rhs.2;
+impl<T: Scalar> ops::DivAssign<Vec3<T>> for Vec3<T> {
+    fn div_assign(&mut self, rhs: Vec3<T>) {
+        *self = *self / rhs;
     }
 }
 
 /*
     Rem
 */
-impl ops::Rem<f64> for Vec3 {
-    type Output = Vec3;
-
-    fn rem(self, rhs: f64) -> Self::Output {
-        if rhs == 0.0 {
-            panic!(
-                "{:?} % {:?}: Attempt to find remainder with a divisor of 0",
-                self, rhs
-            );
-        }
-        Vec3(self.0 % rhs, self.1 % rhs, self.2 % rhs)
+impl<T: Scalar> ops::Rem<T> for Vec3<T> {
+    type Output = Vec3<T>;
+
+    fn rem(self, rhs: T) -> Self::Output {
+        self.checked_rem_scalar(rhs)
+            .unwrap_or_else(|e| panic!("{:?} % {:?}: {}", self, rhs, e))
     }
 }
 
-impl ops::Rem<Vec3> for Vec3 {
-    type Output = Vec3;
+impl<T: Scalar> ops::Rem<Vec3<T>> for Vec3<T> {
+    type Output = Vec3<T>;
 
-    fn rem(self, rhs: Self) -> Self::Output {
-        if rhs.0 == 0.0 || rhs.1 == 0.0 || rhs.2 == 0.0 {
-            panic!(
-                "{:?} % {:?}: Attempt to find remainder with a divisor of 0",
-                self, rhs
-            );
-        }
-        Vec3(self.0 % rhs.0, self.1 % rhs.1, self.2 % rhs.2)
+    fn rem(self, rhs: Vec3<T>) -> Self::Output {
+        self.checked_rem(&rhs)
+            .unwrap_or_else(|e| panic!("{:?} % {:?}: {}", self, rhs, e))
     }
 }
 
-impl ops::RemAssign<f64> for Vec3 {
-    fn rem_assign(&mut self, rhs: f64) {
-        if rhs == 0.0 {
-            panic!(
-                "{:?} % {:?}: Attempt to find remainder with a divisor of 0",
-                self, rhs
-            );
-        }
-
-        self.0 %= rhs;
-        self.1 %= rhs;
-        self.2 %= rhs;
+impl<T: Scalar> ops::RemAssign<T> for Vec3<T> {
+    fn rem_assign(&mut self, rhs: T) {
+        *self = *self % rhs;
     }
 }
 
-impl ops::RemAssign<Vec3> for Vec3 {
-    fn rem_assign(&mut self, rhs: Vec3) {
-        if rhs.0 == 0.0 || rhs.1 == 0.0 || rhs.2 == 0.0 {
-            panic!(
-                "{:?} % {:?}: Attempt to find remainder with a divisor of 0",
-                self, rhs
-            );
-        }
-
-        self.0 %= rhs.0;
-        self.1 %= rhs.1;
-        self.2 %= rhs.2;
+impl<T: Scalar> ops::RemAssign<Vec3<T>> for Vec3<T> {
+    fn rem_assign(&mut self, rhs: Vec3<T>) {
+        *self = *self % rhs;
     }
 }
 
 /*
     Equality
 */
-impl PartialEq for Vec3 {
+impl<T: Scalar> PartialEq for Vec3<T> {
     fn eq(&self, other: &Self) -> bool {
         self.0 == other.0 && self.1 == other.1 && self.2 == other.2
     }
 }
 
-impl Eq for Vec3 {}
-
 /*
     Display
 */
-impl fmt::Display for Vec3 {
+impl<T: Scalar> fmt::Display for Vec3<T> {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         write!(f, "({}, {}, {})", self.0, self.1, self.2)
     }
@@ -413,42 +488,42 @@ impl fmt::Display for Vec3 {
 /*
     From
 */
-impl From<Vec<f64>> for Vec3 {
-    fn from(values: Vec<f64>) -> Self {
+impl<T: Scalar> From<Vec<T>> for Vec3<T> {
+    fn from(values: Vec<T>) -> Self {
         Vec3(
-            *values.get(0).unwrap_or(&0.0),
-            *values.get(1).unwrap_or(&0.0),
-            *values.get(2).unwrap_or(&0.0),
+            *values.first().unwrap_or(&T::ZERO),
+            *values.get(1).unwrap_or(&T::ZERO),
+            *values.get(2).unwrap_or(&T::ZERO),
         )
     }
 }
 
-impl From<f64> for Vec3 {
-    fn from(f: f64) -> Self {
+impl<T: Scalar> From<T> for Vec3<T> {
+    fn from(f: T) -> Self {
         Vec3(f, f, f)
     }
 }
 
-impl From<Vec2> for Vec3 {
-    fn from(v: Vec2) -> Self {
-        Vec3(v.x(), v.y(), 0.0)
+impl<T: Scalar> From<Vec2<T>> for Vec3<T> {
+    fn from(v: Vec2<T>) -> Self {
+        Vec3(v.x(), v.y(), T::ZERO)
     }
 }
 
-impl From<(Vec2, f64)> for Vec3 {
-    fn from(t: (Vec2, f64)) -> Self {
+impl<T: Scalar> From<(Vec2<T>, T)> for Vec3<T> {
+    fn from(t: (Vec2<T>, T)) -> Self {
         Vec3(t.0.x(), t.0.y(), t.1)
     }
 }
 
-impl From<(f64, Vec2)> for Vec3 {
-    fn from(t: (f64, Vec2)) -> Self {
+impl<T: Scalar> From<(T, Vec2<T>)> for Vec3<T> {
+    fn from(t: (T, Vec2<T>)) -> Self {
         Vec3(t.0, t.1.x(), t.1.y())
     }
 }
 
-impl From<Vec4> for Vec3 {
-    fn from(v: Vec4) -> Self {
+impl<T: Scalar> From<Vec4<T>> for Vec3<T> {
+    fn from(v: Vec4<T>) -> Self {
         Vec3(v.0, v.1, v.2)
     }
 }
@@ -470,18 +545,38 @@ impl From<Matrix> for Vec3 {
 /*
     Index
 */
-impl Index<usize> for Vec3 {
-    type Output = f64;
+impl<T: Scalar> Index<usize> for Vec3<T> {
+    type Output = T;
 
     fn index(&self, idx: usize) -> &Self::Output {
         match idx {
             0 => &self.0,
             1 => &self.1,
             2 => &self.2,
-            _ => panic!(
-                "Warning: accessing vector {} by invalid index {}",
-                idx, self
-            ),
+            _ => panic!("Attempt to access vector {} by invalid index {}", self, idx),
+        }
+    }
+}
+
+impl<T: Scalar> IndexMut<usize> for Vec3<T> {
+    fn index_mut(&mut self, idx: usize) -> &mut T {
+        match idx {
+            0 => &mut self.0,
+            1 => &mut self.1,
+            2 => &mut self.2,
+            _ => panic!("Attempt to access vector {} by invalid index {}", self, idx),
         }
     }
 }
+
+macro_rules! impl_lossless_from {
+    ($(($from:ty, $to:ty)),*) => {$(
+        impl From<Vec3<$from>> for Vec3<$to> {
+            fn from(v: Vec3<$from>) -> Self {
+                Vec3(<$to>::from(v.0), <$to>::from(v.1), <$to>::from(v.2))
+            }
+        }
+    )*};
+}
+
+impl_lossless_from!((f32, f64), (i32, f64), (i32, i64));
@@ -1,54 +1,73 @@
-use crate::{Matrix, Vec2, Vec3, VecX};
+use crate::{Matrix, Scalar, Vec2, Vec3, VecX, VecxError};
 use std::fmt;
-use std::ops::{self, Index};
+use std::ops::{self, Index, IndexMut};
 
 /*
     Definition
 */
 #[derive(Debug, Clone, Copy)]
-pub struct Vec4(pub f64, pub f64, pub f64, pub f64);
+pub struct Vec4<T: Scalar = f64>(pub T, pub T, pub T, pub T);
 
-impl Default for Vec4 {
+impl<T: Scalar> Default for Vec4<T> {
     fn default() -> Self {
-        Vec4(0.0, 0.0, 0.0, 0.0)
+        Vec4(T::ZERO, T::ZERO, T::ZERO, T::ZERO)
     }
 }
 
-impl Vec4 {
+impl<T: Scalar> Vec4<T> {
     // xyzw
-    pub fn x(&self) -> f64 {
+    pub fn x(&self) -> T {
         self.0
     }
 
-    pub fn y(&self) -> f64 {
+    pub fn y(&self) -> T {
         self.1
     }
 
-    pub fn z(&self) -> f64 {
+    pub fn z(&self) -> T {
         self.2
     }
 
-    pub fn w(&self) -> f64 {
+    pub fn w(&self) -> T {
         self.3
     }
 
     // rgba
-    pub fn r(&self) -> f64 {
+    pub fn r(&self) -> T {
         self.0
     }
 
-    pub fn g(&self) -> f64 {
+    pub fn g(&self) -> T {
         self.1
     }
 
-    pub fn b(&self) -> f64 {
+    pub fn b(&self) -> T {
         self.2
     }
 
-    pub fn a(&self) -> f64 {
+    pub fn a(&self) -> T {
         self.3
     }
 
+    /// Converts every component to `U`, integers are truncated toward zero
+    ///
+    /// Use `From` for lossless conversions
+    pub fn cast<U: Scalar>(&self) -> Vec4<U> {
+        Vec4(
+            U::from_f64(self.0.to_f64()),
+            U::from_f64(self.1.to_f64()),
+            U::from_f64(self.2.to_f64()),
+            U::from_f64(self.3.to_f64()),
+        )
+    }
+}
+
+impl Vec4 {
+    /// Divides x, y and z by w, turning clip space coordinates into NDC
+    pub fn perspective_divide(&self) -> Vec3 {
+        Vec3::from(*self) / self.w()
+    }
+
     pub fn rot(&self, rot: &Vec4) -> Vec4 {
         let mut rotated = self.rot_x(rot.x());
         rotated = rotated.rot_y(rot.y());
@@ -84,65 +103,78 @@ impl Vec4 {
     }
 }
 
-impl FromIterator<f64> for Vec4 {
-    fn from_iter<T: IntoIterator<Item = f64>>(iter: T) -> Self {
-        let values: Vec<f64> = iter.into_iter().collect();
+impl<T: Scalar> FromIterator<T> for Vec4<T> {
+    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
+        let values: Vec<T> = iter.into_iter().collect();
         Vec4::from(values)
     }
 }
 
-impl VecX for Vec4 {
+impl<T: Scalar> VecX<T> for Vec4<T> {
     /*
         Maths
     */
 
-    fn dot_product(&self, other: &Self) -> f64 {
-        return self.0 * other.0 + self.1 * other.1 + self.2 * other.2 + self.3 * other.3;
+    fn dot_product(&self, other: &Self) -> T {
+        self.0 * other.0 + self.1 * other.1 + self.2 * other.2 + self.3 * other.3
     }
 
-    fn normalized(&self) -> Vec4 {
+    fn try_normalized(&self) -> Result<Vec4<T>, VecxError> {
         let m = self.magnitude();
         if m == 0.0 {
-            panic!("{}: Can't be normalized because its magnitude is 0", self);
+            return Err(VecxError::ZeroMagnitude);
         }
-        Vec4(self.x() / m, self.y() / m, self.z() / m, self.w())
+        Ok(Vec4(
+            T::from_f64(self.x().to_f64() / m),
+            T::from_f64(self.y().to_f64() / m),
+            T::from_f64(self.z().to_f64() / m),
+            self.w(),
+        ))
     }
 
-    fn magnitude(&self) -> f64 {
-        let x = self.x();
-        let y = self.y();
-        let z = self.z();
-        let w = self.w();
+    fn checked_div(&self, rhs: &Self) -> Result<Vec4<T>, VecxError> {
+        Ok(Vec4(
+            self.0.try_div(rhs.0)?,
+            self.1.try_div(rhs.1)?,
+            self.2.try_div(rhs.2)?,
+            self.3.try_div(rhs.3)?,
+        ))
+    }
 
-        f64::sqrt(x * x + y * y + z * z + w * w)
+    fn checked_div_scalar(&self, rhs: T) -> Result<Vec4<T>, VecxError> {
+        Ok(Vec4(
+            self.0.try_div(rhs)?,
+            self.1.try_div(rhs)?,
+            self.2.try_div(rhs)?,
+            self.3.try_div(rhs)?,
+        ))
     }
 
-    /*
-        Accessors
-    */
-    fn comp(&self, component: char) -> f64 {
-        match component {
-            'x' => self.0,
-            'y' => self.1,
-            'z' => self.2,
-            'r' => self.0,
-            'g' => self.1,
-            'b' => self.2,
-            _ => {
-                panic!(
-                    "Attempt to access invalid component '{}' of {:?}",
-                    component, self
-                )
-            }
-        }
+    fn checked_rem(&self, rhs: &Self) -> Result<Vec4<T>, VecxError> {
+        Ok(Vec4(
+            self.0.try_rem(rhs.0)?,
+            self.1.try_rem(rhs.1)?,
+            self.2.try_rem(rhs.2)?,
+            self.3.try_rem(rhs.3)?,
+        ))
     }
 
-    fn at(&self, idx: usize) -> f64 {
-        if idx < 3 {
-            self[idx]
-        } else {
-            0.0
-        }
+    fn checked_rem_scalar(&self, rhs: T) -> Result<Vec4<T>, VecxError> {
+        Ok(Vec4(
+            self.0.try_rem(rhs)?,
+            self.1.try_rem(rhs)?,
+            self.2.try_rem(rhs)?,
+            self.3.try_rem(rhs)?,
+        ))
+    }
+
+    fn magnitude(&self) -> f64 {
+        let x = self.x().to_f64();
+        let y = self.y().to_f64();
+        let z = self.z().to_f64();
+        let w = self.w().to_f64();
+
+        f64::sqrt(x * x + y * y + z * z + w * w)
     }
 
     /*
@@ -159,7 +191,7 @@ impl VecX for Vec4 {
 /*
     Neg
 */
-impl ops::Neg for Vec4 {
+impl<T: Scalar> ops::Neg for Vec4<T> {
     type Output = Self;
 
     fn neg(self) -> Self::Output {
@@ -170,10 +202,10 @@ impl ops::Neg for Vec4 {
 /*
     Add
 */
-impl ops::Add<Vec4> for Vec4 {
-    type Output = Vec4;
+impl<T: Scalar> ops::Add<Vec4<T>> for Vec4<T> {
+    type Output = Vec4<T>;
 
-    fn add(self, rhs: Vec4) -> Vec4 {
+    fn add(self, rhs: Vec4<T>) -> Vec4<T> {
         Vec4(
             self.0 + rhs.0,
             self.1 + rhs.1,
@@ -183,8 +215,8 @@ impl ops::Add<Vec4> for Vec4 {
     }
 }
 
-impl ops::AddAssign<Vec4> for Vec4 {
-    fn add_assign(&mut self, rhs: Vec4) {
+impl<T: Scalar> ops::AddAssign<Vec4<T>> for Vec4<T> {
+    fn add_assign(&mut self, rhs: Vec4<T>) {
         self.0 += rhs.0;
         self.1 += rhs.1;
         self.2 += rhs.2;
@@ -195,10 +227,10 @@ impl ops::AddAssign<Vec4> for Vec4 {
 /*
     Sub
 */
-impl ops::Sub<Self> for Vec4 {
+impl<T: Scalar> ops::Sub<Self> for Vec4<T> {
     type Output = Self;
 
-    fn sub(self, rhs: Vec4) -> Self::Output {
+    fn sub(self, rhs: Vec4<T>) -> Self::Output {
         Vec4(
             self.0 - rhs.0,
             self.1 - rhs.1,
@@ -208,8 +240,8 @@ impl ops::Sub<Self> for Vec4 {
     }
 }
 
-impl ops::SubAssign<Vec4> for Vec4 {
-    fn sub_assign(&mut self, rhs: Vec4) {
+impl<T: Scalar> ops::SubAssign<Vec4<T>> for Vec4<T> {
+    fn sub_assign(&mut self, rhs: Vec4<T>) {
         self.0 -= rhs.0;
         self.1 -= rhs.1;
         self.2 -= rhs.2;
@@ -220,26 +252,32 @@ impl ops::SubAssign<Vec4> for Vec4 {
 /*
     Mul
 */
-impl ops::Mul<f64> for Vec4 {
-    type Output = Vec4;
+impl<T: Scalar> ops::Mul<T> for Vec4<T> {
+    type Output = Vec4<T>;
 
-    fn mul(self, rhs: f64) -> Self::Output {
+    fn mul(self, rhs: T) -> Self::Output {
         Vec4(self.0 * rhs, self.1 * rhs, self.2 * rhs, self.3 * rhs)
     }
 }
 
-impl ops::Mul<Vec4> for f64 {
-    type Output = Vec4;
+macro_rules! impl_scalar_mul {
+    ($($t:ty),*) => {$(
+        impl ops::Mul<Vec4<$t>> for $t {
+            type Output = Vec4<$t>;
 
-    fn mul(self, rhs: Vec4) -> Self::Output {
-        Vec4(self * rhs.0, self * rhs.1, self * rhs.2, self * rhs.3)
-    }
+            fn mul(self, rhs: Vec4<$t>) -> Self::Output {
+                Vec4(self * rhs.0, self * rhs.1, self * rhs.2, self * rhs.3)
+            }
+        }
+    )*};
 }
 
-impl ops::Mul<Vec4> for Vec4 {
-    type Output = Vec4;
+impl_scalar_mul!(f32, f64, i32, i64);
 
-    fn mul(self, rhs: Vec4) -> Self::Output {
+impl<T: Scalar> ops::Mul<Vec4<T>> for Vec4<T> {
+    type Output = Vec4<T>;
+
+    fn mul(self, rhs: Vec4<T>) -> Self::Output {
         Vec4(
             self.0 * rhs.0,
             self.1 * rhs.1,
@@ -249,8 +287,8 @@ impl ops::Mul<Vec4> for Vec4 {
     }
 }
 
-impl ops::MulAssign<f64> for Vec4 {
-    fn mul_assign(&mut self, rhs: f64) {
+impl<T: Scalar> ops::MulAssign<T> for Vec4<T> {
+    fn mul_assign(&mut self, rhs: T) {
         self.0 *= rhs;
         self.1 *= rhs;
         self.2 *= rhs;
@@ -258,8 +296,8 @@ impl ops::MulAssign<f64> for Vec4 {
     }
 }
 
-impl ops::MulAssign<Vec4> for Vec4 {
-    fn mul_assign(&mut self, rhs: Vec4) {
+impl<T: Scalar> ops::MulAssign<Vec4<T>> for Vec4<T> {
+    fn mul_assign(&mut self, rhs: Vec4<T>) {
         self.0 *= rhs.0;
         self.1 *= rhs.1;
         self.2 *= rhs.2;
@@ -270,152 +308,82 @@ impl ops::MulAssign<Vec4> for Vec4 {
 /*
     Div
 */
-impl ops::Div<f64> for Vec4 {
-    type Output = Vec4;
-
-    fn div(self, rhs: f64) -> Self::Output {
-        if (self.0 == 0.0 || self.1 == 0.0 || self.2 == 0.0 || self.3 == 0.0) && rhs == 0.0 {
-            panic!("Division of 0.0 by 0.0: {} / {}", self, rhs);
-        }
+impl<T: Scalar> ops::Div<T> for Vec4<T> {
+    type Output = Vec4<T>;
 
-        Vec4(self.0 / rhs, self.1 / rhs, self.2 / rhs, self.3 / rhs)
+    fn div(self, rhs: T) -> Self::Output {
+        self.checked_div_scalar(rhs)
+            .unwrap_or_else(|e| panic!("{}: {} / {}", e, self, rhs))
     }
 }
 
-impl ops::Div<Vec4> for Vec4 {
-    type Output = Vec4;
+impl<T: Scalar> ops::Div<Vec4<T>> for Vec4<T> {
+    type Output = Vec4<T>;
 
-    fn div(self, rhs: Vec4) -> Self::Output {
-        if self.0 == 0.0 && rhs.0 == 0.0
-            || self.1 == 0.0 && rhs.1 == 0.0
-            || self.2 == 0.0 && rhs.2 == 0.0
-            || self.3 == 0.0 && rhs.3 == 0.0
-        {
-            panic!("Division of 0.0 by 0.0: {} / {}", self, rhs);
-        }
-
-        Vec4(
-            self.0 / rhs.0,
-            self.1 / rhs.1,
-            self.2 / rhs.2,
-            self.3 / rhs.3,
-        )
+    fn div(self, rhs: Vec4<T>) -> Self::Output {
+        self.checked_div(&rhs)
+            .unwrap_or_else(|e| panic!("{}: {} / {}", e, self, rhs))
     }
 }
 
-impl ops::DivAssign<f64> for Vec4 {
-    fn div_assign(&mut self, rhs: f64) {
-        if (self.0 == 0.0 || self.1 == 0.0 || self.2 == 0.0 || self.3 == 0.0) && rhs == 0.0 {
-            panic!("Division of 0.0 by 0.0: {} / {}", self, rhs);
-        }
-
-        self.0 /= rhs;
-        self.1 /= rhs;
-        self.2 /= rhs;
-        self.3 /= rhs;
+impl<T: Scalar> ops::DivAssign<T> for Vec4<T> {
+    fn div_assign(&mut self, rhs: T) {
+        *self = *self / rhs;
     }
 }
 
-impl ops::DivAssign<Vec4> for Vec4 {
-    fn div_assign(&mut self, rhs: Vec4) {
-        if self.0 == 0.0 && rhs.0 == 0.0
-            || self.1 == 0.0 && rhs.1 == 0.0
-            || self.2 == 0.0 && rhs.2 == 0.0
-            || self.3 == 0.0 && rhs.3 == 0.0
-        {
-            panic!("Division of 0.0 by 0.0: {} / {}", self, rhs);
-        }
-
-        self.0 /= rhs.0;
-        self.1 /= rhs.1;
-        self.2 /= rhs.2;
-        self.3 /= rhs.3;
+impl<T: Scalar> ops::DivAssign<Vec4<T>> for Vec4<T> {
+    fn div_assign(&mut self, rhs: Vec4<T>) {
+        *self = *self / rhs;
     }
 }
 
 /*
     Rem
 */
-impl ops::Rem<f64> for Vec4 {
-    type Output = Vec4;
-
-    fn rem(self, rhs: f64) -> Self::Output {
-        if rhs == 0.0 {
-            panic!(
-                "{:?} % {:?}: Attempt to find remainder with a divisor of 0",
-                self, rhs
-            );
-        }
-        Vec4(self.0 % rhs, self.1 % rhs, self.2 % rhs, self.3 % rhs)
+impl<T: Scalar> ops::Rem<T> for Vec4<T> {
+    type Output = Vec4<T>;
+
+    fn rem(self, rhs: T) -> Self::Output {
+        self.checked_rem_scalar(rhs)
+            .unwrap_or_else(|e| panic!("{:?} % {:?}: {}", self, rhs, e))
     }
 }
 
-impl ops::Rem<Vec4> for Vec4 {
-    type Output = Vec4;
+impl<T: Scalar> ops::Rem<Vec4<T>> for Vec4<T> {
+    type Output = Vec4<T>;
 
-    fn rem(self, rhs: Self) -> Self::Output {
-        if rhs.0 == 0.0 || rhs.1 == 0.0 || rhs.2 == 0.0 || rhs.3 == 0.0 {
-            panic!(
-                "{:?} % {:?}: Attempt to find remainder with a divisor of 0",
-                self, rhs
-            );
-        }
-        Vec4(
-            self.0 % rhs.0,
-            self.1 % rhs.1,
-            self.2 % rhs.2,
-            self.3 % rhs.3,
-        )
+    fn rem(self, rhs: Vec4<T>) -> Self::Output {
+        self.checked_rem(&rhs)
+            .unwrap_or_else(|e| panic!("{:?} % {:?}: {}", self, rhs, e))
     }
 }
 
-impl ops::RemAssign<f64> for Vec4 {
-    fn rem_assign(&mut self, rhs: f64) {
-        if rhs == 0.0 {
-            panic!(
-                "{:?} % {:?}: Attempt to find remainder with a divisor of 0",
-                self, rhs
-            );
-        }
-
-        self.0 %= rhs;
-        self.1 %= rhs;
-        self.2 %= rhs;
-        self.3 %= rhs;
+impl<T: Scalar> ops::RemAssign<T> for Vec4<T> {
+    fn rem_assign(&mut self, rhs: T) {
+        *self = *self % rhs;
     }
 }
 
-impl ops::RemAssign<Vec4> for Vec4 {
-    fn rem_assign(&mut self, rhs: Vec4) {
-        if rhs.0 == 0.0 || rhs.1 == 0.0 || rhs.2 == 0.0 || rhs.3 == 0.0 {
-            panic!(
-                "{:?} % {:?}: Attempt to find remainder with a divisor of 0",
-                self, rhs
-            );
-        }
-
-        self.0 %= rhs.0;
-        self.1 %= rhs.1;
-        self.2 %= rhs.2;
-        self.3 %= rhs.3;
+impl<T: Scalar> ops::RemAssign<Vec4<T>> for Vec4<T> {
+    fn rem_assign(&mut self, rhs: Vec4<T>) {
+        *self = *self % rhs;
     }
 }
 
 /*
     Equality
 */
-impl PartialEq for Vec4 {
+impl<T: Scalar> PartialEq for Vec4<T> {
     fn eq(&self, other: &Self) -> bool {
         self.0 == other.0 && self.1 == other.1 && self.2 == other.2
     }
 }
 
-impl Eq for Vec4 {}
-
 /*
     Display
 */
-impl fmt::Display for Vec4 {
+impl<T: Scalar> fmt::Display for Vec4<T> {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         write!(f, "({}, {}, {})", self.0, self.1, self.2)
     }
@@ -424,43 +392,43 @@ impl fmt::Display for Vec4 {
 /*
     From
 */
-impl From<Vec<f64>> for Vec4 {
-    fn from(values: Vec<f64>) -> Self {
+impl<T: Scalar> From<Vec<T>> for Vec4<T> {
+    fn from(values: Vec<T>) -> Self {
         Vec4(
-            *values.get(0).unwrap_or(&0.0),
-            *values.get(1).unwrap_or(&0.0),
-            *values.get(2).unwrap_or(&0.0),
-            *values.get(3).unwrap_or(&0.0),
+            *values.first().unwrap_or(&T::ZERO),
+            *values.get(1).unwrap_or(&T::ZERO),
+            *values.get(2).unwrap_or(&T::ZERO),
+            *values.get(3).unwrap_or(&T::ZERO),
         )
     }
 }
 
-impl From<f64> for Vec4 {
-    fn from(f: f64) -> Self {
+impl<T: Scalar> From<T> for Vec4<T> {
+    fn from(f: T) -> Self {
         Vec4(f, f, f, f)
     }
 }
 
-impl From<Vec2> for Vec4 {
-    fn from(v: Vec2) -> Self {
-        Vec4(v.x(), v.y(), 0.0, 0.0)
+impl<T: Scalar> From<Vec2<T>> for Vec4<T> {
+    fn from(v: Vec2<T>) -> Self {
+        Vec4(v.x(), v.y(), T::ZERO, T::ZERO)
     }
 }
 
-impl From<Vec3> for Vec4 {
-    fn from(v: Vec3) -> Self {
-        Vec4(v.0, v.1, v.2, 0.0)
+impl<T: Scalar> From<Vec3<T>> for Vec4<T> {
+    fn from(v: Vec3<T>) -> Self {
+        Vec4(v.0, v.1, v.2, T::ZERO)
     }
 }
 
-impl From<(Vec3, f64)> for Vec4 {
-    fn from(t: (Vec3, f64)) -> Self {
+impl<T: Scalar> From<(Vec3<T>, T)> for Vec4<T> {
+    fn from(t: (Vec3<T>, T)) -> Self {
         Vec4(t.0.x(), t.0.y(), t.0.z(), t.1)
     }
 }
 
-impl From<(f64, Vec3)> for Vec4 {
-    fn from(t: (f64, Vec3)) -> Self {
+impl<T: Scalar> From<(T, Vec3<T>)> for Vec4<T> {
+    fn from(t: (T, Vec3<T>)) -> Self {
         Vec4(t.0, t.1.x(), t.1.y(), t.1.z())
     }
 }
@@ -486,8 +454,8 @@ impl From<Matrix> for Vec4 {
 /*
     Index
 */
-impl Index<usize> for Vec4 {
-    type Output = f64;
+impl<T: Scalar> Index<usize> for Vec4<T> {
+    type Output = T;
 
     fn index(&self, idx: usize) -> &Self::Output {
         match idx {
@@ -495,10 +463,31 @@ impl Index<usize> for Vec4 {
             1 => &self.1,
             2 => &self.2,
             3 => &self.3,
-            _ => panic!(
-                "Warning: accessing vector {} by invalid index {}",
-                idx, self
-            ),
+            _ => panic!("Attempt to access vector {} by invalid index {}", self, idx),
+        }
+    }
+}
+
+impl<T: Scalar> IndexMut<usize> for Vec4<T> {
+    fn index_mut(&mut self, idx: usize) -> &mut T {
+        match idx {
+            0 => &mut self.0,
+            1 => &mut self.1,
+            2 => &mut self.2,
+            3 => &mut self.3,
+            _ => panic!("Attempt to access vector {} by invalid index {}", self, idx),
         }
     }
 }
+
+macro_rules! impl_lossless_from {
+    ($(($from:ty, $to:ty)),*) => {$(
+        impl From<Vec4<$from>> for Vec4<$to> {
+            fn from(v: Vec4<$from>) -> Self {
+                Vec4(<$to>::from(v.0), <$to>::from(v.1), <$to>::from(v.2), <$to>::from(v.3))
+            }
+        }
+    )*};
+}
+
+impl_lossless_from!((f32, f64), (i32, f64), (i32, i64));
@@ -0,0 +1,388 @@
+use std::array;
+use std::fmt;
+use std::ops::{self, Index, IndexMut};
+
+use crate::{Scalar, Vec2, Vec3, Vec4, VecX, VecxError};
+
+/*
+    Definition
+*/
+/// Vector of `N` components, for sizes not covered by `Vec2`, `Vec3` and `Vec4`
+///
+/// The first four components can be accessed as x, y, z, w or r, g, b, a
+#[derive(Debug, Clone, Copy)]
+pub struct VecN<const N: usize, T: Scalar = f64>(pub [T; N]);
+
+impl<const N: usize, T: Scalar> Default for VecN<N, T> {
+    fn default() -> Self {
+        VecN([T::ZERO; N])
+    }
+}
+
+impl<const N: usize, T: Scalar> VecN<N, T> {
+    pub fn zero() -> Self {
+        VecN([T::ZERO; N])
+    }
+
+    pub fn one() -> Self {
+        VecN([T::ONE; N])
+    }
+
+    pub fn as_array(&self) -> &[T; N] {
+        &self.0
+    }
+
+    /// Copies the components of a vector of any size, missing components are set to 0 and
+    /// extra components are dropped
+    ///
+    /// `From` only converts between vectors of the same size.
+    pub fn resized<V: VecX<T>>(v: V) -> Self {
+        VecN(array::from_fn(|i| v.at(i)))
+    }
+
+    /// Converts every component to `U`, integers are truncated toward zero
+    pub fn cast<U: Scalar>(&self) -> VecN<N, U> {
+        VecN(self.0.map(|c| U::from_f64(c.to_f64())))
+    }
+
+    fn map(self, f: impl Fn(T) -> T) -> Self {
+        VecN(self.0.map(f))
+    }
+
+    fn zip_map(self, rhs: Self, f: impl Fn(T, T) -> T) -> Self {
+        VecN(array::from_fn(|i| f(self.0[i], rhs.0[i])))
+    }
+
+    fn try_zip_map(
+        self,
+        rhs: Self,
+        f: impl Fn(T, T) -> Result<T, VecxError>,
+    ) -> Result<Self, VecxError> {
+        let mut values = self.0;
+        for (value, r) in values.iter_mut().zip(rhs.0) {
+            *value = f(*value, r)?;
+        }
+        Ok(VecN(values))
+    }
+}
+
+impl<const N: usize, T: Scalar> FromIterator<T> for VecN<N, T> {
+    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
+        let values: Vec<T> = iter.into_iter().collect();
+        VecN::from(values)
+    }
+}
+
+impl<const N: usize, T: Scalar> VecX<T> for VecN<N, T> {
+    /*
+        Maths
+    */
+    fn dot_product(&self, other: &Self) -> T {
+        self.0
+            .iter()
+            .zip(other.0)
+            .fold(T::ZERO, |sum, (&a, b)| sum + a * b)
+    }
+
+    fn try_normalized(&self) -> Result<Self, VecxError> {
+        let m = self.magnitude();
+        if m == 0.0 {
+            return Err(VecxError::ZeroMagnitude);
+        }
+        Ok(self.map(|c| T::from_f64(c.to_f64() / m)))
+    }
+
+    fn checked_div(&self, rhs: &Self) -> Result<Self, VecxError> {
+        self.try_zip_map(*rhs, T::try_div)
+    }
+
+    fn checked_div_scalar(&self, rhs: T) -> Result<Self, VecxError> {
+        self.try_zip_map(VecN::from(rhs), T::try_div)
+    }
+
+    fn checked_rem(&self, rhs: &Self) -> Result<Self, VecxError> {
+        self.try_zip_map(*rhs, T::try_rem)
+    }
+
+    fn checked_rem_scalar(&self, rhs: T) -> Result<Self, VecxError> {
+        self.try_zip_map(VecN::from(rhs), T::try_rem)
+    }
+
+    fn magnitude(&self) -> f64 {
+        f64::sqrt(self.0.iter().map(|c| c.to_f64() * c.to_f64()).sum())
+    }
+
+    /*
+        Type functions
+    */
+    fn size() -> usize {
+        N
+    }
+}
+
+/*
+    Ops
+*/
+/*
+    Neg
+*/
+impl<const N: usize, T: Scalar> ops::Neg for VecN<N, T> {
+    type Output = Self;
+
+    fn neg(self) -> Self::Output {
+        self.map(|c| -c)
+    }
+}
+
+/*
+    Add
+*/
+impl<const N: usize, T: Scalar> ops::Add<VecN<N, T>> for VecN<N, T> {
+    type Output = VecN<N, T>;
+
+    fn add(self, rhs: VecN<N, T>) -> Self::Output {
+        self.zip_map(rhs, |a, b| a + b)
+    }
+}
+
+impl<const N: usize, T: Scalar> ops::AddAssign<VecN<N, T>> for VecN<N, T> {
+    fn add_assign(&mut self, rhs: VecN<N, T>) {
+        *self = *self + rhs;
+    }
+}
+
+/*
+    Sub
+*/
+impl<const N: usize, T: Scalar> ops::Sub<VecN<N, T>> for VecN<N, T> {
+    type Output = VecN<N, T>;
+
+    fn sub(self, rhs: VecN<N, T>) -> Self::Output {
+        self.zip_map(rhs, |a, b| a - b)
+    }
+}
+
+impl<const N: usize, T: Scalar> ops::SubAssign<VecN<N, T>> for VecN<N, T> {
+    fn sub_assign(&mut self, rhs: VecN<N, T>) {
+        *self = *self - rhs;
+    }
+}
+
+/*
+    Mul
+*/
+impl<const N: usize, T: Scalar> ops::Mul<T> for VecN<N, T> {
+    type Output = VecN<N, T>;
+
+    fn mul(self, rhs: T) -> Self::Output {
+        self.map(|c| c * rhs)
+    }
+}
+
+macro_rules! impl_scalar_mul {
+    ($($t:ty),*) => {$(
+        impl<const N: usize> ops::Mul<VecN<N, $t>> for $t {
+            type Output = VecN<N, $t>;
+
+            fn mul(self, rhs: VecN<N, $t>) -> Self::Output {
+                rhs * self
+            }
+        }
+    )*};
+}
+
+impl_scalar_mul!(f32, f64, i32, i64);
+
+impl<const N: usize, T: Scalar> ops::Mul<VecN<N, T>> for VecN<N, T> {
+    type Output = VecN<N, T>;
+
+    fn mul(self, rhs: VecN<N, T>) -> Self::Output {
+        self.zip_map(rhs, |a, b| a * b)
+    }
+}
+
+impl<const N: usize, T: Scalar> ops::MulAssign<T> for VecN<N, T> {
+    fn mul_assign(&mut self, rhs: T) {
+        *self = *self * rhs;
+    }
+}
+
+impl<const N: usize, T: Scalar> ops::MulAssign<VecN<N, T>> for VecN<N, T> {
+    fn mul_assign(&mut self, rhs: VecN<N, T>) {
+        *self = *self * rhs;
+    }
+}
+
+/*
+    Div
+*/
+impl<const N: usize, T: Scalar> ops::Div<T> for VecN<N, T> {
+    type Output = VecN<N, T>;
+
+    fn div(self, rhs: T) -> Self::Output {
+        self.checked_div_scalar(rhs)
+            .unwrap_or_else(|e| panic!("{}: {} / {}", e, self, rhs))
+    }
+}
+
+impl<const N: usize, T: Scalar> ops::Div<VecN<N, T>> for VecN<N, T> {
+    type Output = VecN<N, T>;
+
+    fn div(self, rhs: VecN<N, T>) -> Self::Output {
+        self.checked_div(&rhs)
+            .unwrap_or_else(|e| panic!("{}: {} / {}", e, self, rhs))
+    }
+}
+
+impl<const N: usize, T: Scalar> ops::DivAssign<T> for VecN<N, T> {
+    fn div_assign(&mut self, rhs: T) {
+        *self = *self / rhs;
+    }
+}
+
+impl<const N: usize, T: Scalar> ops::DivAssign<VecN<N, T>> for VecN<N, T> {
+    fn div_assign(&mut self, rhs: VecN<N, T>) {
+        *self = *self / rhs;
+    }
+}
+
+/*
+    Rem
+*/
+impl<const N: usize, T: Scalar> ops::Rem<T> for VecN<N, T> {
+    type Output = VecN<N, T>;
+
+    fn rem(self, rhs: T) -> Self::Output {
+        self.checked_rem_scalar(rhs)
+            .unwrap_or_else(|e| panic!("{:?} % {:?}: {}", self, rhs, e))
+    }
+}
+
+impl<const N: usize, T: Scalar> ops::Rem<VecN<N, T>> for VecN<N, T> {
+    type Output = VecN<N, T>;
+
+    fn rem(self, rhs: VecN<N, T>) -> Self::Output {
+        self.checked_rem(&rhs)
+            .unwrap_or_else(|e| panic!("{:?} % {:?}: {}", self, rhs, e))
+    }
+}
+
+impl<const N: usize, T: Scalar> ops::RemAssign<T> for VecN<N, T> {
+    fn rem_assign(&mut self, rhs: T) {
+        *self = *self % rhs;
+    }
+}
+
+impl<const N: usize, T: Scalar> ops::RemAssign<VecN<N, T>> for VecN<N, T> {
+    fn rem_assign(&mut self, rhs: VecN<N, T>) {
+        *self = *self % rhs;
+    }
+}
+
+/*
+    Equality
+*/
+impl<const N: usize, T: Scalar> PartialEq for VecN<N, T> {
+    fn eq(&self, other: &Self) -> bool {
+        self.0 == other.0
+    }
+}
+
+/*
+    Display
+*/
+impl<const N: usize, T: Scalar> fmt::Display for VecN<N, T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let components: Vec<String> = self.0.iter().map(|c| c.to_string()).collect();
+        write!(f, "({})", components.join(", "))
+    }
+}
+
+/*
+    Index
+*/
+impl<const N: usize, T: Scalar> Index<usize> for VecN<N, T> {
+    type Output = T;
+
+    fn index(&self, idx: usize) -> &T {
+        self.0
+            .get(idx)
+            .unwrap_or_else(|| panic!("Attempt to access vector {} by invalid index {}", self, idx))
+    }
+}
+
+impl<const N: usize, T: Scalar> IndexMut<usize> for VecN<N, T> {
+    fn index_mut(&mut self, idx: usize) -> &mut T {
+        if idx >= N {
+            panic!("Attempt to access vector {} by invalid index {}", self, idx);
+        }
+        &mut self.0[idx]
+    }
+}
+
+/*
+    From
+*/
+impl<const N: usize, T: Scalar> From<T> for VecN<N, T> {
+    fn from(f: T) -> Self {
+        VecN([f; N])
+    }
+}
+
+impl<const N: usize, T: Scalar> From<[T; N]> for VecN<N, T> {
+    fn from(values: [T; N]) -> Self {
+        VecN(values)
+    }
+}
+
+impl<const N: usize, T: Scalar> From<VecN<N, T>> for [T; N] {
+    fn from(v: VecN<N, T>) -> Self {
+        v.0
+    }
+}
+
+/// Missing values are set to 0, extra values are ignored
+impl<const N: usize, T: Scalar> From<Vec<T>> for VecN<N, T> {
+    fn from(values: Vec<T>) -> Self {
+        VecN(array::from_fn(|i| *values.get(i).unwrap_or(&T::ZERO)))
+    }
+}
+
+impl<T: Scalar> From<Vec2<T>> for VecN<2, T> {
+    fn from(v: Vec2<T>) -> Self {
+        VecN([v.0, v.1])
+    }
+}
+
+impl<T: Scalar> From<Vec3<T>> for VecN<3, T> {
+    fn from(v: Vec3<T>) -> Self {
+        VecN([v.0, v.1, v.2])
+    }
+}
+
+impl<T: Scalar> From<Vec4<T>> for VecN<4, T> {
+    fn from(v: Vec4<T>) -> Self {
+        VecN([v.0, v.1, v.2, v.3])
+    }
+}
+
+impl<T: Scalar> From<VecN<2, T>> for Vec2<T> {
+    fn from(v: VecN<2, T>) -> Self {
+        let [x, y] = v.0;
+        Vec2(x, y)
+    }
+}
+
+impl<T: Scalar> From<VecN<3, T>> for Vec3<T> {
+    fn from(v: VecN<3, T>) -> Self {
+        let [x, y, z] = v.0;
+        Vec3(x, y, z)
+    }
+}
+
+impl<T: Scalar> From<VecN<4, T>> for Vec4<T> {
+    fn from(v: VecN<4, T>) -> Self {
+        let [x, y, z, w] = v.0;
+        Vec4(x, y, z, w)
+    }
+}
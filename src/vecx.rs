@@ -1,49 +1,42 @@
 use std::{
     fmt::Display,
-    ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Rem, RemAssign, Sub, SubAssign},
+    ops::{
+        Add, AddAssign, Div, DivAssign, Index, IndexMut, Mul, MulAssign, Rem, RemAssign, Sub,
+        SubAssign,
+    },
 };
 
-use crate::{Vec2, Vec3, Vec4};
+use crate::{Scalar, Vec2, Vec3, Vec4, VecxError};
 
-pub fn vec_dot(a: Vec<f64>, b: Vec<f64>) -> f64 {
-        if a.len() != b.len() {
-            panic!("Invalid dot product: length of a is {} != length of b {}", a.len(), b.len());
-        }
-
-        let mut result = 0.0;
-        for i in 0..a.len() {
-            result += a[i] * b[i]
-        }
-        result
-    }
-
-pub trait VecX: 
+/// Operations shared by vectors of `T` components, `f64` by default
+pub trait VecX<T: Scalar = f64>:
     Copy 
     + Display
     // operations
     + Add<Self, Output = Self>
     + Sub<Self, Output = Self>
     + Mul<Self, Output = Self>
-    + Mul<f64, Output = Self>
+    + Mul<T, Output = Self>
     + Div<Self, Output = Self>
-    + Div<f64, Output = Self>
+    + Div<T, Output = Self>
     + Rem<Self, Output = Self>
-    + Rem<f64, Output = Self>
+    + Rem<T, Output = Self>
     // assignments
     + AddAssign<Self>
     + SubAssign<Self>
     + MulAssign<Self>
-    + MulAssign<f64>
+    + MulAssign<T>
     + DivAssign<Self>
-    + DivAssign<f64>
+    + DivAssign<T>
     + RemAssign<Self>
-    + RemAssign<f64>
+    + RemAssign<T>
+    // components
+    + Index<usize, Output = T>
+    + IndexMut<usize>
     // from
-    + From<f64>
-    + From<Vec2>
-    + From<Vec3>
-    + From<Vec<f64>>
-    + FromIterator<f64>
+    + From<T>
+    + From<Vec<T>>
+    + FromIterator<T>
 {
     fn size() -> usize where Self: Sized;
 
@@ -62,8 +55,31 @@ pub trait VecX:
     }
     fn magnitude(&self) -> f64;
 
+    /// Angle between both vectors in radians, from 0.0 to PI
+    ///
+    /// Panics if either vector is zero, see `try_angle`
     fn angle(&self, other: &Self) -> f64 {
-        return f64::acos(self.dot(other));
+        self.try_angle(other)
+            .unwrap_or_else(|| panic!("{}, {}: {}", self, other, VecxError::ZeroMagnitude))
+    }
+
+    /// Angle between both vectors in radians from 0.0 to PI, or `None` if either vector is zero
+    ///
+    /// Unlike the `acos` of `dot` it never returns NaN and stays accurate for angles close to 0.0
+    /// and PI. Uses Kahan's `2 * atan2(|a * |b| - b * |a||, |a * |b| + b * |a||)`,
+    /// `Vec2` and `Vec3` use `atan2(|a x b|, a . b)`.
+    fn try_angle(&self, other: &Self) -> Option<f64> {
+        let (a, b) = (self.magnitude(), other.magnitude());
+        if a == 0.0 || b == 0.0 {
+            return None;
+        }
+        let (mut difference, mut sum) = (0.0, 0.0);
+        for i in 0..Self::size() {
+            let (x, y) = (self.at(i).to_f64() * b, other.at(i).to_f64() * a);
+            difference += (x - y) * (x - y);
+            sum += (x + y) * (x + y);
+        }
+        Some(2.0 * f64::atan2(difference.sqrt(), sum.sqrt()))
     }
 
     /// Returns the dot product of both **normalized** vectors.
@@ -78,21 +94,229 @@ pub trait VecX:
     /// 
     /// 1.0: Vectors are codirectional
     fn dot(&self, other: &Self) -> f64 {
-        return self.normalized().dot_product(&other.normalized());
+        for v in [self, other] {
+            if v.magnitude() == 0.0 {
+                panic!("{}: {}", v, VecxError::ZeroMagnitude);
+            }
+        }
+        self.dot_product(other).to_f64() / (self.magnitude() * other.magnitude())
+    }
+
+
+    fn dot_product(&self, other: &Self) -> T;
+
+    fn normalized(&self) -> Self {
+        self.try_normalized().unwrap_or_else(|e| panic!("{}: {}", self, e))
+    }
+
+    /// Returns the normalized vector, or `VecxError::ZeroMagnitude` for a zero vector
+    fn try_normalized(&self) -> Result<Self, VecxError>;
+
+    /// Component-wise division, `VecxError::ZeroByZeroDivision` if a component is 0.0 / 0.0
+    ///
+    /// Integer vectors also fail with `VecxError::IntegerDivisionByZero`
+    fn checked_div(&self, rhs: &Self) -> Result<Self, VecxError>;
+
+    /// Division by `rhs`, see `checked_div`
+    fn checked_div_scalar(&self, rhs: T) -> Result<Self, VecxError>;
+
+    /// Component-wise remainder, `VecxError::ZeroDivisor` if a component of `rhs` is 0
+    fn checked_rem(&self, rhs: &Self) -> Result<Self, VecxError>;
+
+    /// Remainder of the division by `rhs`, `VecxError::ZeroDivisor` if `rhs` is 0
+    fn checked_rem_scalar(&self, rhs: T) -> Result<Self, VecxError>;
+
+    /// Reflects the vector off a surface with the **unit** `normal`
+    /// # Examples
+    /// ```
+    /// use vecx::{Vec2, VecX};
+    ///
+    /// let v = Vec2(1.0, -1.0);
+    /// assert_eq!(v.reflect(&Vec2(0.0, 1.0)), Vec2(1.0, 1.0));
+    /// ```
+    fn reflect(&self, normal: &Self) -> Self {
+        *self - *normal * (self.dot_product(normal) * (T::ONE + T::ONE))
+    }
+
+    /// Refracts the **unit** vector through a surface with the **unit** `normal` facing against it,
+    /// `eta` being the ratio of the refractive indices from the incident side to the other side
+    ///
+    /// Returns `None` on total internal reflection
+    /// # Examples
+    /// ```
+    /// use vecx::{Vec2, VecX};
+    ///
+    /// let n = Vec2(0.0, 1.0);
+    /// assert_eq!(Vec2(0.0, -1.0).refract(&n, 1.5), Some(Vec2(0.0, -1.0)));
+    /// assert_eq!(Vec2(1.0, -1.0).normalized().refract(&n, 1.5), None);
+    /// ```
+    fn refract(&self, normal: &Self, eta: f64) -> Option<Self> {
+        // Snell's law, the tangential part is scaled by eta and the normal part keeps the vector unit
+        let cos_i = -self.dot_product(normal).to_f64();
+        let k = 1.0 - eta * eta * (1.0 - cos_i * cos_i);
+        if k < 0.0 {
+            return None;
+        }
+        let normal_scale = eta * cos_i - k.sqrt();
+        Some(
+            (0..Self::size())
+                .map(|i| T::from_f64(eta * self.at(i).to_f64() + normal_scale * normal.at(i).to_f64()))
+                .collect(),
+        )
     }
 
+    /// Component of the vector along `other`
+    ///
+    /// Panics if `other` is zero, see `try_project_onto`
+    fn project_onto(&self, other: &Self) -> Self {
+        self.try_project_onto(other).unwrap_or_else(|e| panic!("{}: {}", other, e))
+    }
 
-    fn dot_product(&self, other: &Self) -> f64;
+    /// Component of the vector along `other`, or `VecxError::ZeroMagnitude` if `other` is zero
+    /// # Examples
+    /// ```
+    /// use vecx::{Vec2, VecX, VecxError};
+    ///
+    /// let v = Vec2(2.0, 3.0);
+    /// assert_eq!(v.try_project_onto(&Vec2(4.0, 0.0)), Ok(Vec2(2.0, 0.0)));
+    /// assert_eq!(v.try_project_onto(&Vec2(0.0, 0.0)), Err(VecxError::ZeroMagnitude));
+    /// ```
+    fn try_project_onto(&self, other: &Self) -> Result<Self, VecxError> {
+        if other.magnitude() == 0.0 {
+            return Err(VecxError::ZeroMagnitude);
+        }
+        let scale = self.dot_product(other).to_f64() / other.dot_product(other).to_f64();
+        Ok((0..Self::size())
+            .map(|i| T::from_f64(other.at(i).to_f64() * scale))
+            .collect())
+    }
 
-    fn normalized(&self) -> Self;
+    /// Component of the vector orthogonal to `other`, so that
+    /// `v.project_onto(&o) + v.reject_from(&o) == v`
+    ///
+    /// Panics if `other` is zero, see `try_reject_from`
+    fn reject_from(&self, other: &Self) -> Self {
+        self.try_reject_from(other).unwrap_or_else(|e| panic!("{}: {}", other, e))
+    }
+
+    /// Component of the vector orthogonal to `other`, or `VecxError::ZeroMagnitude` if `other` is zero
+    fn try_reject_from(&self, other: &Self) -> Result<Self, VecxError> {
+        Ok(*self - self.try_project_onto(other)?)
+    }
+
+    /// Linear interpolation, `t = 0.0` gives the vector and `t = 1.0` gives `other`
+    ///
+    /// `t` isn't clamped, values outside of 0.0..=1.0 extrapolate
+    /// # Examples
+    /// ```
+    /// use vecx::{Vec3, VecX};
+    ///
+    /// let v = Vec3(0.0, 2.0, 4.0);
+    /// assert_eq!(v.lerp(&Vec3(2.0, 2.0, 0.0), 0.5), Vec3(1.0, 2.0, 2.0));
+    /// ```
+    fn lerp(&self, other: &Self, t: f64) -> Self {
+        (0..Self::size())
+            .map(|i| {
+                let (a, b) = (self.at(i).to_f64(), other.at(i).to_f64());
+                T::from_f64(a + (b - a) * t)
+            })
+            .collect()
+    }
+
+    /// Normalized linear interpolation, a cheaper `Vec3::slerp` that doesn't move at constant speed
+    ///
+    /// Panics if the interpolated vector is zero, see `try_nlerp`
+    fn nlerp(&self, other: &Self, t: f64) -> Self {
+        self.try_nlerp(other, t).unwrap_or_else(|e| panic!("{}, {}: {}", self, other, e))
+    }
+
+    /// Normalized linear interpolation, or `VecxError::ZeroMagnitude` if the interpolated vector
+    /// is zero, which happens halfway between antiparallel vectors of the same length
+    ///
+    /// `Vec3::slerp` picks a direction in that case
+    /// # Examples
+    /// ```
+    /// use vecx::{Vec2, VecX, VecxError};
+    ///
+    /// let x = Vec2(1.0, 0.0);
+    /// assert_eq!(x.try_nlerp(&Vec2(0.0, 2.0), 1.0), Ok(Vec2(0.0, 1.0)));
+    /// assert_eq!(x.try_nlerp(&-x, 0.5), Err(VecxError::ZeroMagnitude));
+    /// ```
+    fn try_nlerp(&self, other: &Self, t: f64) -> Result<Self, VecxError> {
+        self.lerp(other, t).try_normalized()
+    }
+
+    /*
+        Component-wise
+    */
+    /// Clamps every component between the matching components of `min` and `max`
+    /// # Examples
+    /// ```
+    /// use vecx::{Vec3, VecX};
+    ///
+    /// let v = Vec3(-1.0, 0.5, 2.0);
+    /// assert_eq!(v.clamp(&Vec3(0.0, 0.0, 0.0), &Vec3(1.0, 1.0, 1.0)), Vec3(0.0, 0.5, 1.0));
+    /// ```
+    fn clamp(&self, min: &Self, max: &Self) -> Self {
+        self.max(min).min(max)
+    }
+
+    /// Smallest of the two vectors' components
+    fn min(&self, other: &Self) -> Self {
+        (0..Self::size())
+            .map(|i| if other.at(i) < self.at(i) { other.at(i) } else { self.at(i) })
+            .collect()
+    }
+
+    /// Largest of the two vectors' components
+    fn max(&self, other: &Self) -> Self {
+        (0..Self::size())
+            .map(|i| if other.at(i) > self.at(i) { other.at(i) } else { self.at(i) })
+            .collect()
+    }
+
+    fn abs(&self) -> Self {
+        (0..Self::size()).map(|i| self.at(i).abs()).collect()
+    }
+
+    fn floor(&self) -> Self {
+        (0..Self::size()).map(|i| self.at(i).floor()).collect()
+    }
+
+    fn ceil(&self) -> Self {
+        (0..Self::size()).map(|i| self.at(i).ceil()).collect()
+    }
 
     /*
         Accessors
     */
-    fn comp(&self, component: char) -> f64;
-    fn at(&self, idx: usize) -> f64;
+    fn comp(&self, component: char) -> T {
+        self.try_comp(component).unwrap_or_else(|e| panic!("{} of {}", e, self))
+    }
+
+    /// Returns the component named `component`, or `VecxError::InvalidComponent`
+    ///
+    /// 'x', 'y', 'z' and 'w' name the components in order, as do 'r', 'g', 'b' and 'a'
+    fn try_comp(&self, component: char) -> Result<T, VecxError> {
+        Ok(self[component_index(component, Self::size())?])
+    }
+
+    /// Returns a mutable reference to the component named `component`, or `VecxError::InvalidComponent`
+    fn try_comp_mut(&mut self, component: char) -> Result<&mut T, VecxError> {
+        let idx = component_index(component, Self::size())?;
+        Ok(&mut self[idx])
+    }
+
+    /// Component at `idx`, or 0 past the last component
+    fn at(&self, idx: usize) -> T {
+        if idx < Self::size() {
+            self[idx]
+        } else {
+            T::ZERO
+        }
+    }
 
-    /// Returns the current vector's values as an array of `f64`
+    /// Returns the current vector's values as an array of `T`
     /// # Examples
     /// ```
     /// use vecx::{Vec2, Vec3, VecX};
@@ -100,12 +324,12 @@ pub trait VecX:
     /// let v = Vec2(1.0, 2.0);
     /// assert_eq!(v.as_values_of::<Vec3>(), vec![1.0_f64, 2.0_f64, 0.0_f64])
     /// ```
-    fn as_values_of<T: VecX>(&self) -> Vec<f64> {
-        let mut values = Vec::<f64>::new();
-        for i in 0..T::size() {
+    fn as_values_of<V: VecX<T>>(&self) -> Vec<T> {
+        let mut values = Vec::<T>::new();
+        for i in 0..V::size() {
             values.push(self.at(i))
         }
-        return values;
+        values
     }
 
     /// Returns a new Vec2 made of the components of the calling vector
@@ -123,8 +347,8 @@ pub trait VecX:
     /// assert_eq!(v3.s2("zx"), Vec2(2.0, 0.0));
     /// assert_eq!(v3.s2("rg"), Vec2(0.0, 1.0));
     /// ```
-    fn s2(&self, xy: &str) -> Vec2 {
-        self.s::<Vec2>(xy)
+    fn s2(&self, xy: &str) -> Vec2<T> {
+        self.s::<Vec2<T>>(xy)
     }
 
     /// Returns a new Vec3 made of the components of the calling vector
@@ -142,8 +366,8 @@ pub trait VecX:
     /// assert_eq!(v3.s3("zyx"), Vec3(2.0, 1.0, 0.0));
     /// assert_eq!(v3.s3("rgb"), Vec3(0.0, 1.0, 2.0));
     /// ```
-    fn s3(&self, xyz: &str) -> Vec3 {
-        self.s::<Vec3>(xyz)
+    fn s3(&self, xyz: &str) -> Vec3<T> {
+        self.s::<Vec3<T>>(xyz)
     }
 
     /// Returns a new Vec4 made of the components of the calling vector
@@ -162,14 +386,17 @@ pub trait VecX:
     /// assert_eq!(v3.s4("xzyx"), Vec4(0.0, 2.0, 1.0, 0.0));
     /// assert_eq!(v3.s4("rgbr"), Vec4(0.0, 1.0, 2.0, 0.0));
     /// 
-    /// assert_eq!(v4.s4("wwww"), Vec3(3.0, 3.0, 3.0, 3.0));
-    /// assert_eq!(v4.s4("aaaa"), Vec3(3.0, 3.0, 3.0, 3.0));
+    /// assert_eq!(v4.s4("wwww"), Vec4(3.0, 3.0, 3.0, 3.0));
+    /// assert_eq!(v4.s4("aaaa"), Vec4(3.0, 3.0, 3.0, 3.0));
     /// ```
-    fn s4(&self, xyzw: &str) -> Vec4 {
-        self.s::<Vec4>(xyzw)
+    fn s4(&self, xyzw: &str) -> Vec4<T> {
+        self.s::<Vec4<T>>(xyzw)
     }
 
-    /// Returns a new Vec `T` made of the components of the calling vector
+    /// Returns a new Vec `V` made of the components of the calling vector
+    ///
+    /// Vec2, Vec3 and Vec4 also have a generated method for every swizzle, e.g. `xzy()`,
+    /// which is checked at compile time and doesn't parse or allocate
     /// # Examples
     /// ```
     /// use vecx::{Vec2, Vec3, VecX};
@@ -179,33 +406,113 @@ pub trait VecX:
     ///
     /// assert_eq!(v3, Vec3(0.0, 0.0, 0.0));
     /// ```
-    fn s<T: VecX>(&self, swizzle: &str) -> T {
-        if !self.is_valid_swizzle::<T>(swizzle) {
-            panic!(
-                "Invalid swizzle size: expected length {} vs swizzle {} length {}",
-                T::size(),
-                swizzle,
-                swizzle.len(),
-            )
+    fn s<V: VecX<T>>(&self, swizzle: &str) -> V {
+        self.try_s(swizzle).unwrap_or_else(|e| panic!("{} of {}", e, self))
+    }
+
+    /// Returns a new Vec `V` made of the components of the calling vector,
+    /// or an error if the swizzle has the wrong length or an invalid component
+    /// # Examples
+    /// ```
+    /// use vecx::{Vec2, Vec3, VecX, VecxError};
+    ///
+    /// let v2 = Vec2(0.0, 1.0);
+    ///
+    /// assert_eq!(v2.try_s::<Vec3>("xyx"), Ok(Vec3(0.0, 1.0, 0.0)));
+    /// assert_eq!(v2.try_s::<Vec3>("xyq"), Err(VecxError::InvalidComponent('q')));
+    /// ```
+    fn try_s<V: VecX<T>>(&self, swizzle: &str) -> Result<V, VecxError> {
+        if !self.is_valid_swizzle::<V>(swizzle) {
+            return Err(VecxError::InvalidSwizzleLength {
+                swizzle: swizzle.to_string(),
+                expected: V::size(),
+            });
         }
 
-        let values = self.swizzle_as_vec64(swizzle);
+        swizzle.chars().map(|c| self.try_comp(c)).collect()
+    }
+
+    /// Writes the components of `value` into the components named by `swizzle`
+    ///
+    /// Panics if the swizzle is invalid, see `try_set_s`
+    /// # Examples
+    /// ```
+    /// use vecx::{Vec2, Vec3, VecX};
+    ///
+    /// let mut v = Vec3(0.0, 0.0, 0.0);
+    /// v.set_s("zx", Vec2(1.0, 2.0));
+    ///
+    /// assert_eq!(v, Vec3(2.0, 0.0, 1.0));
+    /// ```
+    fn set_s<V: VecX<T>>(&mut self, swizzle: &str, value: V) {
+        self.try_set_s(swizzle, value)
+            .unwrap_or_else(|e| panic!("{} of {}", e, self))
+    }
+
+    /// Writes the components of `value` into the components named by `swizzle`,
+    /// or returns an error without modifying the vector if the swizzle has the wrong length,
+    /// an invalid component or names a component twice
+    /// # Examples
+    /// ```
+    /// use vecx::{Vec2, Vec3, VecX, VecxError};
+    ///
+    /// let mut v = Vec3(0.0, 0.0, 0.0);
+    ///
+    /// assert_eq!(v.try_set_s("xx", Vec2(1.0, 2.0)), Err(VecxError::DuplicateComponent('x')));
+    /// assert_eq!(v, Vec3(0.0, 0.0, 0.0));
+    /// ```
+    fn try_set_s<V: VecX<T>>(&mut self, swizzle: &str, value: V) -> Result<(), VecxError> {
+        if !self.is_valid_swizzle::<V>(swizzle) {
+            return Err(VecxError::InvalidSwizzleLength {
+                swizzle: swizzle.to_string(),
+                expected: V::size(),
+            });
+        }
 
-        return T::from(values);
+        // Aliases like 'x' and 'r' name the same component, so duplicates are found by index
+        let mut target = *self;
+        let mut written = 0u8;
+        for (i, c) in swizzle.chars().enumerate() {
+            let idx = component_index(c, Self::size())?;
+            if written & (1 << idx) != 0 {
+                return Err(VecxError::DuplicateComponent(c));
+            }
+            written |= 1 << idx;
+            *target.try_comp_mut(c)? = value.at(i);
+        }
+        *self = target;
+        Ok(())
     }
 
-    fn is_valid_swizzle<T: VecX>(&self, swizzle: &str) -> bool {
-        swizzle.len() == T::size()
+    fn is_valid_swizzle<V: VecX<T>>(&self, swizzle: &str) -> bool {
+        swizzle.len() == V::size()
     }
 
     fn swizzle_as_vec64(&self, swizzle: &str) -> Vec<f64> {
         let values: Vec<f64> = swizzle
             .chars()
             .map(|c: char| -> f64 {
-                return self.comp(c);
+                self.comp(c).to_f64()
             })
             .collect();
 
-        return values;
+        values
+    }
+}
+
+/// Index of the component named `component` in a vector of `size` components
+///
+/// Names only go up to 'w', so the index is always below 4
+pub(crate) fn component_index(component: char, size: usize) -> Result<usize, VecxError> {
+    let idx = match component {
+        'x' | 'r' => 0,
+        'y' | 'g' => 1,
+        'z' | 'b' => 2,
+        'w' | 'a' => 3,
+        _ => return Err(VecxError::InvalidComponent(component)),
+    };
+    if idx >= size {
+        return Err(VecxError::InvalidComponent(component));
     }
+    Ok(idx)
 }